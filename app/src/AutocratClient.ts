@@ -11,8 +11,8 @@ import { Autocrat as AutocratIDLType, IDL as AutocratIDL } from './types/autocra
 import * as ixs from "./instructions/autocrat";
 import BN from "bn.js";
 import { AMM_PROGRAM_ID, AUTOCRAT_LUTS, AUTOCRAT_PROGRAM_ID } from "./constants";
-import { ProposalInstruction, UpdateDaoParams, Dao, DaoTreasury, Proposal, ProposalWrapper, ProposalVault, ProposalInstructions } from "./types";
-import { getDaoAddr, getDaoTreasuryAddr, getProposalAddr, getProposalVaultAddr } from "./utils";
+import { ProposalInstruction, UpdateDaoParams, Dao, DaoTreasury, Proposal, ProposalWrapper, ProposalInstructions } from "./types";
+import { getDaoAddr, getDaoTreasuryAddr, getProposalAddr } from "./utils";
 
 export type CreateAutocratClientParams = {
     provider: AnchorProvider,
@@ -56,48 +56,59 @@ export class AutocratClient {
 
     async initializeDao(
         metaMint: PublicKey,
-        usdcMint: PublicKey
+        usdcMint: PublicKey,
+        metaTicker: string,
+        usdcTicker: string
     ) {
         return ixs.initializeDaoHandler(
             this,
             metaMint,
-            usdcMint
+            usdcMint,
+            metaTicker,
+            usdcTicker
         )
     }
 
     // this won't ever be called directly (must be called via a proposal), but is here anyway for completeness / testing
     async updateDao(
+        dao: PublicKey,
         updateDaoParams: UpdateDaoParams
     ) {
         return ixs.updateDaoHandler(
             this,
+            dao,
             updateDaoParams
         )
     }
 
     async createProposalInstructions(
+        dao: PublicKey,
         proposalNumber: number,
         instructions: ProposalInstruction[],
     ) {
         return ixs.createProposalInstructionsHandler(
             this,
+            dao,
             proposalNumber,
             instructions,
         )
     }
 
     async addProposalInstructions(
+        dao: PublicKey,
         proposalNumber: number,
         instructions: ProposalInstruction[],
     ) {
         return ixs.addProposalInstructionsHandler(
             this,
+            dao,
             proposalNumber,
             instructions,
         )
     }
 
     async createProposal(
+        dao: PublicKey,
         proposalNumber: number,
         descriptionUrl: string,
         condMetaToMint: BN,
@@ -105,6 +116,7 @@ export class AutocratClient {
     ) {
         return ixs.createProposalHandler(
             this,
+            dao,
             proposalNumber,
             descriptionUrl,
             condMetaToMint,
@@ -113,6 +125,7 @@ export class AutocratClient {
     }
 
     async createProposalMarketSide(
+        dao: PublicKey,
         proposalNumber: number,
         isPassMarket: boolean,
         ammBaseAmountDeposit: BN,
@@ -121,6 +134,7 @@ export class AutocratClient {
     ) {
         return ixs.createProposalMarketSideHandler(
             this,
+            dao,
             proposalNumber,
             isPassMarket,
             ammBaseAmountDeposit,
@@ -130,22 +144,26 @@ export class AutocratClient {
     }
 
     async submitProposal(
+        dao: PublicKey,
         proposalNumber: number,
         ammProgram = AMM_PROGRAM_ID,
     ) {
         return ixs.submitProposalHandler(
             this,
+            dao,
             proposalNumber,
             ammProgram
         )
     }
 
     async finalizeProposal(
+        dao: PublicKey,
         proposalNumber: number,
         accounts: AccountMeta[]
     ) {
         return ixs.finalizeProposalHandler(
             this,
+            dao,
             proposalNumber,
             accounts
         )
@@ -153,36 +171,45 @@ export class AutocratClient {
 
     async mintConditionalTokens(
         proposalAddr: PublicKey,
-        metaAmount: BN,
-        usdcAmount: BN,
+        underlyingMint: PublicKey,
+        amount: BN,
     ) {
         return ixs.mintConditionalTokensHandler(
             this,
             proposalAddr,
-            metaAmount,
-            usdcAmount,
+            underlyingMint,
+            amount,
         )
     }
 
     async mergeConditionalTokens(
-        proposalAddr: PublicKey,
-        metaAmount: BN,
-        usdcAmount: BN,
+        vaultAddr: PublicKey,
+        amount: BN,
     ) {
         return ixs.mergeConditionalTokensHandler(
             this,
-            proposalAddr,
-            metaAmount,
-            usdcAmount,
+            vaultAddr,
+            amount,
+        )
+    }
+
+    async settleConditionalVault(
+        vaultAddr: PublicKey,
+    ) {
+        return ixs.settleConditionalVaultHandler(
+            this,
+            vaultAddr,
         )
     }
 
     async redeemConditionalTokens(
-        proposalAddr: PublicKey
+        vaultAddr: PublicKey,
+        amount: BN,
     ) {
         return ixs.redeemConditionalTokensHandler(
             this,
-            proposalAddr,
+            vaultAddr,
+            amount,
         )
     }
 
@@ -258,24 +285,24 @@ export class AutocratClient {
 
     // getter functions
 
-    async getDao(): Promise<Dao> {
-        return await this.program.account.dao.fetch(getDaoAddr(this.program.programId)[0]);
+    async getDao(creator: PublicKey, metaMint: PublicKey): Promise<Dao> {
+        return await this.program.account.dao.fetch(getDaoAddr(this.program.programId, creator, metaMint)[0]);
     }
 
-    async getDaoTreasury(): Promise<DaoTreasury> {
-        return await this.program.account.daoTreasury.fetch(getDaoTreasuryAddr(this.program.programId)[0]);
+    async getDaoTreasury(dao: PublicKey): Promise<DaoTreasury> {
+        return await this.program.account.daoTreasury.fetch(getDaoTreasuryAddr(this.program.programId, dao)[0]);
     }
 
     async getAllProposals(): Promise<ProposalWrapper[]> {
         return await this.program.account.proposal.all();
     }
 
-    async getProposalByNumber(proposalNumber: number): Promise<Proposal> {
-        return await this.program.account.proposal.fetch(getProposalAddr(this.program.programId, proposalNumber)[0]);
+    async getProposalByNumber(dao: PublicKey, proposalNumber: number): Promise<Proposal> {
+        return await this.program.account.proposal.fetch(getProposalAddr(this.program.programId, dao, proposalNumber)[0]);
     }
 
-    async getProposalInstructionsByNumber(proposalNumber: number): Promise<ProposalInstructions> {
-        const proposal = await this.getProposalByNumber(proposalNumber)
+    async getProposalInstructionsByNumber(dao: PublicKey, proposalNumber: number): Promise<ProposalInstructions> {
+        const proposal = await this.getProposalByNumber(dao, proposalNumber)
         return await this.program.account.proposalInstructions.fetch(proposal.instructions);
     }
 
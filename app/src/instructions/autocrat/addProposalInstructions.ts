@@ -6,11 +6,12 @@ import { getProposalAddr, getProposalInstructionsAddr } from "../../utils";
 
 export const addProposalInstructionsHandler = async (
     client: AutocratClient,
+    dao: PublicKey,
     proposalNumber: number,
     instructions: ProposalInstruction[],
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
 
-    let proposalAddr = getProposalAddr(client.program.programId, proposalNumber)[0]
+    let proposalAddr = getProposalAddr(client.program.programId, dao, proposalNumber)[0]
 
     let proposalInstructionsAddr = getProposalInstructionsAddr(client.program.programId, proposalAddr)[0]
 
@@ -1,21 +1,21 @@
 import { createAssociatedTokenAccountInstruction } from "@solana/spl-token";
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
-import { getATA, getDaoAddr, getProposalAddr, getProposalVaultAddr } from '../../utils';
+import { getATA, getProposalAddr, getProposalVaultAddr } from '../../utils';
 import { Keypair, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
 import BN from "bn.js";
 
 export const createProposalHandler = async (
     client: AutocratClient,
+    daoAddr: PublicKey,
     proposalNumber: number,
     descriptionUrl: string,
     condMetaToMint: BN,
     condUsdcToMint: BN,
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
-    let daoAddr = getDaoAddr(client.program.programId)[0]
     let dao = await client.program.account.dao.fetch(daoAddr)
 
-    let proposalAddr = getProposalAddr(client.program.programId, proposalNumber)[0]
+    let proposalAddr = getProposalAddr(client.program.programId, daoAddr, proposalNumber)[0]
     let proposalVaultAddr = getProposalVaultAddr(client.program.programId, proposalAddr)[0]
 
     let ix = await client.program.methods
@@ -1,16 +1,17 @@
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
 import { ProposalInstruction } from '../../types';
-import { Keypair } from "@solana/web3.js";
+import { PublicKey } from "@solana/web3.js";
 import { getProposalAddr, getProposalInstructionsAddr } from "../../utils";
 
 export const createProposalInstructionsHandler = async (
     client: AutocratClient,
+    dao: PublicKey,
     proposalNumber: number,
     instructions: ProposalInstruction[],
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
 
-    let proposalAddr = getProposalAddr(client.program.programId, proposalNumber)[0]
+    let proposalAddr = getProposalAddr(client.program.programId, dao, proposalNumber)[0]
 
     let proposalInstructionsAddr = getProposalInstructionsAddr(client.program.programId, proposalAddr)[0]
 
@@ -1,21 +1,21 @@
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
-import { getATA, getAmmAddr, getAmmAuthAddr, getAmmPositionAddr, getDaoAddr, getProposalAddr, getProposalVaultAddr } from '../../utils';
+import { getATA, getAmmAddr, getAmmAuthAddr, getAmmPositionAddr, getProposalAddr, getProposalVaultAddr } from '../../utils';
 import BN from "bn.js";
 import { Keypair, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
 
 export const createProposalMarketSideHandler = async (
     client: AutocratClient,
+    daoAddr: PublicKey,
     proposalNumber: number,
     isPassMarket: boolean,
     ammBaseAmountDeposit: BN,
     ammQuoteAmountDeposit: BN,
     ammProgram: PublicKey,
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
-    let daoAddr = getDaoAddr(client.program.programId)[0]
     let dao = await client.program.account.dao.fetch(daoAddr)
 
-    let proposalAddr = getProposalAddr(client.program.programId, proposalNumber)[0]
+    let proposalAddr = getProposalAddr(client.program.programId, daoAddr, proposalNumber)[0]
     let proposalVaultAddr = getProposalVaultAddr(client.program.programId, proposalAddr)[0]
 
     let conditionalMetaMintKeypair = Keypair.generate()
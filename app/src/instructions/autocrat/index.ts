@@ -11,6 +11,7 @@ export * from './mergeConditionalTokens'
 export * from './mintConditionalTokens'
 export * from './redeemConditionalTokens'
 export * from './ammCpi/removeLiquidityCpi'
+export * from './settleConditionalVault'
 export * from './ammCpi/swapCpi'
 export * from './updateDao'
 
@@ -7,15 +7,35 @@ import { META_MINT, USDC_MINT } from '../../constants';
 export const initializeDaoHandler = async (
     client: AutocratClient,
     metaMint: PublicKey,
-    usdcMint: PublicKey
+    usdcMint: PublicKey,
+    metaTicker: string,
+    usdcTicker: string
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
-    let daoTreasuryAddr = getDaoTreasuryAddr(client.program.programId)[0]
+    let daoAddr = getDaoAddr(client.program.programId, client.provider.wallet.publicKey, metaMint)[0]
+    let daoTreasuryAddr = getDaoTreasuryAddr(client.program.programId, daoAddr)[0]
 
     let ix = await client.program.methods
-        .initializeDao()
+        .initializeDao({
+            metaTicker,
+            usdcTicker,
+            passThresholdBps: null,
+            proposalDurationSlots: null,
+            maxActiveProposals: null,
+            finalizeWindowSlots: null,
+            executionWindowSlots: null,
+            executionDelaySlots: null,
+            proposalFeeUsdc: null,
+            proposalFeeCurve: null,
+            finalizerBountyUsdc: null,
+            proposalFeeRefundBps: null,
+            ammInitialQuoteLiquidityAmount: null,
+            ammSwapFeeBps: null,
+            ammLtwapDecimals: null,
+            guardian: null,
+        })
         .accounts({
             payer: client.provider.wallet.publicKey,
-            dao: getDaoAddr(client.program.programId)[0],
+            dao: daoAddr,
             daoTreasury: daoTreasuryAddr,
             metaMint: metaMint,
             usdcMint: usdcMint,
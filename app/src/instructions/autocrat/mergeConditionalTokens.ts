@@ -1,40 +1,30 @@
 import { PublicKey } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
-import { getATA, getProposalVaultAddr } from '../../utils';
+import { getATA } from '../../utils';
+import { getConditionalTokenAccounts } from './mintConditionalTokens';
 import BN from 'bn.js';
 
+// burns `amount` conditional tokens of every outcome for as many underlying tokens
 export const mergeConditionalTokensHandler = async (
     client: AutocratClient,
-    proposalAddr: PublicKey,
-    metaAmount: BN,
-    usdcAmount: BN,
+    vaultAddr: PublicKey,
+    amount: BN,
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
-    const proposal = await client.program.account.proposal.fetch(proposalAddr);
-
-    let proposalVaultAddr = getProposalVaultAddr(client.program.programId, proposalAddr)[0]
+    const vault = await client.program.account.conditionalVault.fetch(vaultAddr);
 
     let ix = await client.program.methods
-        .mergeConditionalTokens(metaAmount, usdcAmount)
+        .mergeConditionalTokens(amount)
         .accounts({
             user: client.provider.publicKey,
-            proposal: proposalAddr,
-            proposalVault: proposalVaultAddr,
-            metaMint: proposal.metaMint,
-            usdcMint: proposal.usdcMint,
-            conditionalOnPassMetaMint: proposal.conditionalOnPassMetaMint,
-            conditionalOnPassUsdcMint: proposal.conditionalOnPassUsdcMint,
-            conditionalOnFailMetaMint: proposal.conditionalOnFailMetaMint,
-            conditionalOnFailUsdcMint: proposal.conditionalOnFailUsdcMint,
-            metaUserAta: getATA(proposal.metaMint, client.provider.publicKey)[0],
-            usdcUserAta: getATA(proposal.usdcMint, client.provider.publicKey)[0],
-            conditionalOnPassMetaUserAta: getATA(proposal.conditionalOnPassMetaMint, client.provider.publicKey)[0],
-            conditionalOnPassUsdcUserAta: getATA(proposal.conditionalOnPassUsdcMint, client.provider.publicKey)[0],
-            conditionalOnFailMetaUserAta: getATA(proposal.conditionalOnFailMetaMint, client.provider.publicKey)[0],
-            conditionalOnFailUsdcUserAta: getATA(proposal.conditionalOnFailUsdcMint, client.provider.publicKey)[0],
-            metaVaultAta: getATA(proposal.metaMint, proposalVaultAddr)[0],
-            usdcVaultAta: getATA(proposal.usdcMint, proposalVaultAddr)[0],
+            vault: vaultAddr,
+            underlyingMint: vault.underlyingMint,
+            vaultUnderlyingTokenAccount: vault.underlyingTokenAccount,
+            userUnderlyingTokenAccount: getATA(vault.underlyingMint, client.provider.publicKey)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .remainingAccounts(getConditionalTokenAccounts(vault.conditionalMints, client.provider.publicKey))
         .instruction()
 
     return new InstructionHandler([ix], [], client)
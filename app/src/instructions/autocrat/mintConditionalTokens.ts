@@ -1,41 +1,45 @@
-import { PublicKey } from '@solana/web3.js';
+import { AccountMeta, PublicKey } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
-import { getATA, getDaoAddr, getProposalVaultAddr } from '../../utils';
+import { getATA, getConditionalVaultAddr } from '../../utils';
 import BN from 'bn.js';
 
+// deposits `amount` of `underlyingMint` into the proposal's vault for it, and
+// mints as many conditional tokens of every outcome
 export const mintConditionalTokensHandler = async (
     client: AutocratClient,
     proposalAddr: PublicKey,
-    metaAmount: BN,
-    usdcAmount: BN,
+    underlyingMint: PublicKey,
+    amount: BN,
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
-    const proposal = await client.program.account.proposal.fetch(proposalAddr);
-
-    let proposalVaultAddr = getProposalVaultAddr(client.program.programId, proposalAddr)[0]
+    const vaultAddr = getConditionalVaultAddr(client.program.programId, proposalAddr, underlyingMint)[0]
+    const vault = await client.program.account.conditionalVault.fetch(vaultAddr);
 
     let ix = await client.program.methods
-        .mintConditionalTokens(metaAmount, usdcAmount)
+        .mintConditionalTokens(amount)
         .accounts({
             user: client.provider.publicKey,
             proposal: proposalAddr,
-            proposalVault: proposalVaultAddr,
-            metaMint: proposal.metaMint,
-            usdcMint: proposal.usdcMint,
-            conditionalOnPassMetaMint: proposal.conditionalOnPassMetaMint,
-            conditionalOnPassUsdcMint: proposal.conditionalOnPassUsdcMint,
-            conditionalOnFailMetaMint: proposal.conditionalOnFailMetaMint,
-            conditionalOnFailUsdcMint: proposal.conditionalOnFailUsdcMint,
-            metaUserAta: getATA(proposal.metaMint, client.provider.publicKey)[0],
-            usdcUserAta: getATA(proposal.usdcMint, client.provider.publicKey)[0],
-            conditionalOnPassMetaUserAta: getATA(proposal.conditionalOnPassMetaMint, client.provider.publicKey)[0],
-            conditionalOnPassUsdcUserAta: getATA(proposal.conditionalOnPassUsdcMint, client.provider.publicKey)[0],
-            conditionalOnFailMetaUserAta: getATA(proposal.conditionalOnFailMetaMint, client.provider.publicKey)[0],
-            conditionalOnFailUsdcUserAta: getATA(proposal.conditionalOnFailUsdcMint, client.provider.publicKey)[0],
-            metaVaultAta: getATA(proposal.metaMint, proposalVaultAddr)[0],
-            usdcVaultAta: getATA(proposal.usdcMint, proposalVaultAddr)[0],
+            vault: vaultAddr,
+            underlyingMint,
+            vaultUnderlyingTokenAccount: vault.underlyingTokenAccount,
+            userUnderlyingTokenAccount: getATA(underlyingMint, client.provider.publicKey)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .remainingAccounts(getConditionalTokenAccounts(vault.conditionalMints, client.provider.publicKey))
         .instruction()
 
     return new InstructionHandler([ix], [], client)
 };
+
+// every outcome's conditional mint followed by the user's account for it, in outcome order
+export const getConditionalTokenAccounts = (
+    conditionalMints: PublicKey[],
+    user: PublicKey,
+): AccountMeta[] => {
+    return conditionalMints.flatMap(conditionalMint => [
+        { pubkey: conditionalMint, isSigner: false, isWritable: true },
+        { pubkey: getATA(conditionalMint, user)[0], isSigner: false, isWritable: true },
+    ])
+};
@@ -1,36 +1,36 @@
 import { PublicKey } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
-import { getATA, getProposalVaultAddr } from '../../utils';
+import { getATA } from '../../utils';
+import BN from 'bn.js';
 
+// burns `amount` of the winning outcome's conditional tokens for as many
+// underlying tokens, once the vault has been settled
 export const redeemConditionalTokensHandler = async (
     client: AutocratClient,
-    proposalAddr: PublicKey,
+    vaultAddr: PublicKey,
+    amount: BN,
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
-    const proposal = await client.program.account.proposal.fetch(proposalAddr);
+    const vault = await client.program.account.conditionalVault.fetch(vaultAddr);
 
-    let proposalVaultAddr = getProposalVaultAddr(client.program.programId, proposalAddr)[0]
+    if (!vault.status.finalized) {
+        throw new Error("only a vault that's been settled as finalized can be redeemed from")
+    }
+
+    const conditionalMint = vault.conditionalMints[vault.status.finalized.winningOutcomeIndex]
 
     let ix = await client.program.methods
-        .redeemConditionalTokens()
+        .redeemConditionalTokens(amount)
         .accounts({
             user: client.provider.publicKey,
-            proposal: proposalAddr,
-            proposalVault: proposalVaultAddr,
-            metaMint: proposal.metaMint,
-            usdcMint: proposal.usdcMint,
-            conditionalOnPassMetaMint: proposal.conditionalOnPassMetaMint,
-            conditionalOnPassUsdcMint: proposal.conditionalOnPassUsdcMint,
-            conditionalOnFailMetaMint: proposal.conditionalOnFailMetaMint,
-            conditionalOnFailUsdcMint: proposal.conditionalOnFailUsdcMint,
-            metaUserAta: getATA(proposal.metaMint, client.provider.publicKey)[0],
-            usdcUserAta: getATA(proposal.usdcMint, client.provider.publicKey)[0],
-            conditionalOnPassMetaUserAta: getATA(proposal.conditionalOnPassMetaMint, client.provider.publicKey)[0],
-            conditionalOnPassUsdcUserAta: getATA(proposal.conditionalOnPassUsdcMint, client.provider.publicKey)[0],
-            conditionalOnFailMetaUserAta: getATA(proposal.conditionalOnFailMetaMint, client.provider.publicKey)[0],
-            conditionalOnFailUsdcUserAta: getATA(proposal.conditionalOnFailUsdcMint, client.provider.publicKey)[0],
-            metaVaultAta: getATA(proposal.metaMint, proposalVaultAddr)[0],
-            usdcVaultAta: getATA(proposal.usdcMint, proposalVaultAddr)[0],
+            vault: vaultAddr,
+            underlyingMint: vault.underlyingMint,
+            vaultUnderlyingTokenAccount: vault.underlyingTokenAccount,
+            userUnderlyingTokenAccount: getATA(vault.underlyingMint, client.provider.publicKey)[0],
+            conditionalMint,
+            userConditionalTokenAccount: getATA(conditionalMint, client.provider.publicKey)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
         })
         .instruction()
 
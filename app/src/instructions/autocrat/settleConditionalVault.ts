@@ -0,0 +1,20 @@
+import { PublicKey } from '@solana/web3.js';
+import { AutocratClient } from "../../AutocratClient";
+import { InstructionHandler } from "../../InstructionHandler";
+
+export const settleConditionalVaultHandler = async (
+    client: AutocratClient,
+    vaultAddr: PublicKey,
+): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
+    const vault = await client.program.account.conditionalVault.fetch(vaultAddr);
+
+    let ix = await client.program.methods
+        .settleConditionalVault()
+        .accounts({
+            proposal: vault.settlementAuthority,
+            vault: vaultAddr,
+        })
+        .instruction()
+
+    return new InstructionHandler([ix], [], client)
+};
@@ -1,18 +1,18 @@
 import { createAssociatedTokenAccountInstruction } from "@solana/spl-token";
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
-import { getATA, getAmmAuthAddr, getDaoAddr, getDaoTreasuryAddr, getProposalAddr, getProposalInstructionsAddr, getProposalVaultAddr } from '../../utils';
+import { getATA, getAmmAuthAddr, getDaoTreasuryAddr, getProposalAddr, getProposalInstructionsAddr, getProposalVaultAddr } from '../../utils';
 import { Keypair, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
 
 export const submitProposalHandler = async (
     client: AutocratClient,
+    daoAddr: PublicKey,
     proposalNumber: number,
     ammProgram: PublicKey,
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
-    let daoAddr = getDaoAddr(client.program.programId)[0]
-    let daoTreasuryAddr = getDaoTreasuryAddr(client.program.programId)[0]
+    let daoTreasuryAddr = getDaoTreasuryAddr(client.program.programId, daoAddr)[0]
 
-    let proposalAddr = getProposalAddr(client.program.programId, proposalNumber)[0]
+    let proposalAddr = getProposalAddr(client.program.programId, daoAddr, proposalNumber)[0]
     let proposal = await client.program.account.proposal.fetch(proposalAddr)
 
     let proposalInstructionsAddr = getProposalInstructionsAddr(client.program.programId, proposalAddr)[0]
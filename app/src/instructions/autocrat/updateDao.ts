@@ -1,17 +1,19 @@
 import { AutocratClient } from "../../AutocratClient";
 import { InstructionHandler } from "../../InstructionHandler";
-import { getDaoAddr, getDaoTreasuryAddr } from '../../utils';
+import { PublicKey } from '@solana/web3.js';
+import { getDaoTreasuryAddr } from '../../utils';
 import { UpdateDaoParams } from '../../types';
 
 export const updateDaoHandler = async (
     client: AutocratClient,
+    dao: PublicKey,
     updateDaoParams: UpdateDaoParams
 ): Promise<InstructionHandler<typeof client.program, AutocratClient>> => {
     let ix = await client.program.methods
         .updateDao(updateDaoParams)
         .accounts({
-            dao: getDaoAddr(client.program.programId)[0],
-            daoTreasury: getDaoTreasuryAddr(client.program.programId)[0],
+            dao,
+            daoTreasury: getDaoTreasuryAddr(client.program.programId, dao)[0],
         })
         .instruction()
 
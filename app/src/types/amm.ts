@@ -41,7 +41,12 @@ export type Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -152,7 +157,12 @@ export type Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -241,7 +251,12 @@ export type Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -313,7 +328,12 @@ export type Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -377,6 +397,112 @@ export type Amm = {
           }
         }
       ]
+    },
+    {
+      "name": "resetLtwap",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authPda",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closePosition",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authPda",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeAmm",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "quoteMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAtaBase",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAtaQuote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authPda",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
@@ -538,6 +664,26 @@ export type Amm = {
       "code": 6001,
       "name": "DecimalScaleError",
       "msg": "Error in decimal scale conversion"
+    },
+    {
+      "code": 6002,
+      "name": "AmmNotPermissioned",
+      "msg": "Only permissioned AMMs can be closed"
+    },
+    {
+      "code": 6003,
+      "name": "AmmNotEmpty",
+      "msg": "The AMM still holds liquidity"
+    },
+    {
+      "code": 6004,
+      "name": "AmmPositionNotEmpty",
+      "msg": "The AMM position still has ownership"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidAuthPda",
+      "msg": "The auth PDA doesn't match the AMM's permissioned caller"
     }
   ]
 };
@@ -585,7 +731,12 @@ export const IDL: Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -696,7 +847,12 @@ export const IDL: Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -785,7 +941,12 @@ export const IDL: Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -857,7 +1018,12 @@ export const IDL: Amm = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -921,6 +1087,112 @@ export const IDL: Amm = {
           }
         }
       ]
+    },
+    {
+      "name": "resetLtwap",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authPda",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closePosition",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authPda",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeAmm",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "quoteMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAtaBase",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAtaQuote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authPda",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
@@ -1082,6 +1354,26 @@ export const IDL: Amm = {
       "code": 6001,
       "name": "DecimalScaleError",
       "msg": "Error in decimal scale conversion"
+    },
+    {
+      "code": 6002,
+      "name": "AmmNotPermissioned",
+      "msg": "Only permissioned AMMs can be closed"
+    },
+    {
+      "code": 6003,
+      "name": "AmmNotEmpty",
+      "msg": "The AMM still holds liquidity"
+    },
+    {
+      "code": 6004,
+      "name": "AmmPositionNotEmpty",
+      "msg": "The AMM position still has ownership"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidAuthPda",
+      "msg": "The auth PDA doesn't match the AMM's permissioned caller"
     }
   ]
 };
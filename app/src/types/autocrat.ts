@@ -20,6 +20,11 @@ export type Autocrat = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "instructionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "metaMint",
           "isMut": false,
@@ -46,7 +51,14 @@ export type Autocrat = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "daoParams",
+          "type": {
+            "defined": "InitializeDaoParams"
+          }
+        }
+      ]
     },
     {
       "name": "updateDao",
@@ -71,6 +83,40 @@ export type Autocrat = {
         }
       ]
     },
+    {
+      "name": "updateInstructionPolicy",
+      "accounts": [
+        {
+          "name": "dao",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructionPolicy",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "isEnabled",
+          "type": "bool"
+        },
+        {
+          "name": "rules",
+          "type": {
+            "vec": {
+              "defined": "InstructionPolicyRule"
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "createProposalInstructions",
       "accounts": [
@@ -89,6 +135,11 @@ export type Autocrat = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "instructionPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
@@ -101,6 +152,10 @@ export type Autocrat = {
         }
       ],
       "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        },
         {
           "name": "instructions",
           "type": {
@@ -108,6 +163,12 @@ export type Autocrat = {
               "defined": "ProposalInstruction"
             }
           }
+        },
+        {
+          "name": "lookupTables",
+          "type": {
+            "vec": "publicKey"
+          }
         }
       ]
     },
@@ -129,6 +190,11 @@ export type Autocrat = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "instructionPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
@@ -151,6 +217,32 @@ export type Autocrat = {
         }
       ]
     },
+    {
+      "name": "closeProposalInstructions",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposalInstructions",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "createProposal",
       "accounts": [
@@ -170,7 +262,12 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "metaVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
           "isMut": true,
           "isSigner": false
         },
@@ -210,7 +307,12 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -232,6 +334,24 @@ export type Autocrat = {
         {
           "name": "mintCondUsdc",
           "type": "u64"
+        },
+        {
+          "name": "outcomeCount",
+          "type": "u8"
+        },
+        {
+          "name": "scalar",
+          "type": {
+            "option": {
+              "defined": "ScalarRange"
+            }
+          }
+        },
+        {
+          "name": "prerequisites",
+          "type": {
+            "vec": "u64"
+          }
         }
       ]
     },
@@ -249,7 +369,12 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "metaVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
           "isMut": true,
           "isSigner": false
         },
@@ -289,22 +414,44 @@ export type Autocrat = {
         {
           "name": "conditionalMetaMint",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "conditionalUsdcMint",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaMetadata",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "mint is a token-2022 mint"
+          ]
+        },
+        {
+          "name": "conditionalUsdcMetadata",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "mint is a token-2022 mint"
+          ]
         },
         {
           "name": "conditionalMetaProposerAta",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "associated token program"
+          ]
         },
         {
           "name": "conditionalUsdcProposerAta",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "associated token program"
+          ]
         },
         {
           "name": "conditionalMetaAmmVaultAta",
@@ -321,13 +468,23 @@ export type Autocrat = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -335,12 +492,17 @@ export type Autocrat = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "isPassMarket",
-          "type": "bool"
+          "name": "outcomeIndex",
+          "type": "u8"
         },
         {
           "name": "ammCondMetaDeposit",
@@ -365,26 +527,11 @@ export type Autocrat = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "daoTreasury",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "proposalVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "proposalInstructions",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "usdcMint",
           "isMut": false,
@@ -396,17 +543,7 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "usdcTreasuryVaultAta",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "passMarketAmm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "failMarketAmm",
+          "name": "usdcFeeEscrow",
           "isMut": true,
           "isSigner": false
         },
@@ -442,10 +579,10 @@ export type Autocrat = {
       "args": []
     },
     {
-      "name": "finalizeProposal",
+      "name": "cancelProposalMarketSide",
       "accounts": [
         {
-          "name": "user",
+          "name": "proposer",
           "isMut": true,
           "isSigner": true
         },
@@ -455,126 +592,178 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalInstructions",
-          "isMut": true,
+          "name": "metaVault",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "dao",
+          "name": "usdcVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "daoTreasury",
+          "name": "ammPosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "passMarketAmm",
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "conditionalMetaMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "failMarketAmm",
+          "name": "conditionalUsdcMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "conditionalMetaProposerAta",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "mintConditionalTokens",
-      "accounts": [
+        },
         {
-          "name": "user",
+          "name": "conditionalUsdcProposerAta",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "proposal",
-          "isMut": false,
+          "name": "conditionalMetaAmmVaultAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "conditionalUsdcAmmVaultAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaMint",
+          "name": "ammProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcMint",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassMetaMint",
-          "isMut": true,
+          "name": "metaTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcMint",
-          "isMut": true,
+          "name": "usdcTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaMint",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelProposal",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "conditionalOnFailUsdcMint",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeProposal",
+      "accounts": [
         {
-          "name": "metaUserAta",
+          "name": "user",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "usdcUserAta",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassMetaUserAta",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcUserAta",
+          "name": "usdcFeeEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaUserAta",
+          "name": "dao",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcUserAta",
-          "isMut": true,
+          "name": "daoTreasury",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metaVaultAta",
+          "name": "usdcMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcVaultAta",
+          "name": "usdcTreasuryVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "usdcProposerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "usdcUserAta",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "associatedTokenProgram",
           "isMut": false,
@@ -591,217 +780,239 @@ export type Autocrat = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "metaAmount",
-          "type": "u64"
-        },
-        {
-          "name": "usdcAmount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "mergeConditionalTokens",
+      "name": "vetoProposal",
       "accounts": [
         {
-          "name": "user",
-          "isMut": true,
+          "name": "guardian",
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "proposal",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "proposalVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaMint",
+          "name": "dao",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "accounts": [
         {
-          "name": "usdcMint",
+          "name": "user",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "conditionalOnPassMetaMint",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcMint",
+          "name": "proposalInstructions",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "conditionalOnFailMetaMint",
-          "isMut": true,
+          "name": "dao",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcMint",
+          "name": "daoTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaUserAta",
-          "isMut": true,
+          "name": "instructionPolicy",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "usdcUserAta",
-          "isMut": true,
-          "isSigner": false
+          "name": "instructionIndex",
+          "type": "u64"
         },
         {
-          "name": "conditionalOnPassMetaUserAta",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "instructionCount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeProposalAmmPosition",
+      "accounts": [
         {
-          "name": "conditionalOnPassUsdcUserAta",
-          "isMut": true,
-          "isSigner": false
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "conditionalOnFailMetaUserAta",
-          "isMut": true,
+          "name": "proposal",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcUserAta",
-          "isMut": true,
+          "name": "amm",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metaVaultAta",
+          "name": "ammPosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcVaultAta",
+          "name": "positionOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "ammAuthPda",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
         },
         {
-          "name": "systemProgram",
+          "name": "ammProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "metaAmount",
-          "type": "u64"
-        },
-        {
-          "name": "usdcAmount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "redeemConditionalTokens",
+      "name": "closeProposalMarketSide",
       "accounts": [
         {
           "name": "user",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "proposal",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaMint",
-          "isMut": false,
+          "name": "amm",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcMint",
+          "name": "ammAuthPda",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
         },
         {
-          "name": "conditionalOnPassMetaMint",
+          "name": "conditionalMetaMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcMint",
+          "name": "conditionalUsdcMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaMint",
+          "name": "conditionalMetaAmmVaultAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcMint",
+          "name": "conditionalUsdcAmmVaultAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaUserAta",
-          "isMut": true,
+          "name": "ammProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcUserAta",
-          "isMut": true,
+          "name": "metaTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassMetaUserAta",
-          "isMut": true,
+          "name": "usdcTokenProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeProposal",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "conditionalOnPassUsdcUserAta",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaUserAta",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeConditionalVault",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "conditionalOnFailUsdcUserAta",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaVaultAta",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcVaultAta",
+          "name": "underlyingMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -824,10 +1035,10 @@ export type Autocrat = {
       "args": []
     },
     {
-      "name": "createPosition",
+      "name": "createConditionalVaultMint",
       "accounts": [
         {
-          "name": "user",
+          "name": "proposer",
           "isMut": true,
           "isSigner": true
         },
@@ -837,28 +1048,22 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "amm",
-          "isMut": false,
+          "name": "vault",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ammPosition",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "CHECK"
-          ]
+          "name": "underlyingMint",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "ammAuthPda",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "CHECK"
-          ]
+          "name": "conditionalMint",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "ammProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -868,10 +1073,15 @@ export type Autocrat = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "addLiquidity",
+      "name": "mintConditionalTokens",
       "accounts": [
         {
           "name": "user",
@@ -884,76 +1094,184 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "vault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "amm",
-          "isMut": true,
+          "name": "underlyingMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ammPosition",
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "CHECK"
-          ]
+          "isSigner": false
         },
         {
-          "name": "ammAuthPda",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "CHECK"
-          ]
+          "name": "userUnderlyingTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "metaMint",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "usdcMint",
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mergeConditionalTokens",
+      "accounts": [
+        {
+          "name": "user",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "conditionalMetaMint",
+          "name": "vault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalUsdcMint",
+          "name": "underlyingMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalMetaUserAta",
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalUsdcUserAta",
+          "name": "userUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalMetaVaultAta",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "conditionalUsdcVaultAta",
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemConditionalTokens",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "underlyingMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ammProgram",
+          "name": "userUnderlyingTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userConditionalTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleConditionalVault",
+      "accounts": [
+        {
+          "name": "proposal",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeConditionalVault",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "underlyingMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultUnderlyingTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposerUnderlyingTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "associatedTokenProgram",
           "isMut": false,
@@ -970,27 +1288,57 @@ export type Autocrat = {
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "createPosition",
+      "accounts": [
         {
-          "name": "maxBaseAmount",
-          "type": "u64"
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "maxQuoteAmount",
-          "type": "u64"
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "minBaseAmount",
-          "type": "u64"
+          "name": "amm",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "minQuoteAmount",
-          "type": "u64"
+          "name": "ammPosition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "removeLiquidity",
+      "name": "addLiquidity",
       "accounts": [
         {
           "name": "user",
@@ -1003,8 +1351,13 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
-          "isMut": true,
+          "name": "metaVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1079,7 +1432,12 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1091,13 +1449,25 @@ export type Autocrat = {
       ],
       "args": [
         {
-          "name": "removeBps",
+          "name": "maxBaseAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxQuoteAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minBaseAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minQuoteAmount",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "swap",
+      "name": "removeLiquidity",
       "accounts": [
         {
           "name": "user",
@@ -1110,8 +1480,13 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
-          "isMut": true,
+          "name": "metaVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1119,6 +1494,14 @@ export type Autocrat = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "ammPosition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
         {
           "name": "ammAuthPda",
           "isMut": false,
@@ -1178,7 +1561,12 @@ export type Autocrat = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1190,200 +1578,502 @@ export type Autocrat = {
       ],
       "args": [
         {
-          "name": "isQuoteToBase",
-          "type": "bool"
-        },
-        {
-          "name": "inputAmount",
-          "type": "u64"
-        },
-        {
-          "name": "outputAmountMin",
+          "name": "removeBps",
           "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "daoTreasury",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "dao",
-            "type": "publicKey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
     },
     {
-      "name": "dao",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "treasuryPdaBump",
-            "type": "u8"
-          },
-          {
-            "name": "treasuryPda",
-            "type": "publicKey"
-          },
-          {
-            "name": "metaMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "usdcMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "proposalCount",
-            "type": "u64"
-          },
-          {
-            "name": "proposalsActive",
-            "type": "u32"
-          },
-          {
-            "name": "proposalFeeUsdc",
-            "type": "u64"
-          },
-          {
-            "name": "passThresholdBps",
-            "type": "u64"
-          },
+      "name": "swap",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metaVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "metaMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaUserAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcUserAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "isQuoteToBase",
+          "type": "bool"
+        },
+        {
+          "name": "inputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "outputAmountMin",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintAndSwap",
+      "accounts": [
+        {
+          "name": "mintConditionalTokens",
+          "accounts": [
+            {
+              "name": "user",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "proposal",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vault",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "underlyingMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vaultUnderlyingTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userUnderlyingTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "conditionalMetaMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaUserAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcUserAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "outputAmountMin",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "conditionalVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "proposalDurationSlots",
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "finalizeWindowSlots",
-            "type": "u64"
+            "name": "settlementAuthority",
+            "type": "publicKey"
           },
           {
-            "name": "ammInitialQuoteLiquidityAmount",
-            "type": "u64"
+            "name": "underlyingMint",
+            "type": "publicKey"
           },
           {
-            "name": "ammSwapFeeBps",
-            "type": "u64"
+            "name": "underlyingTokenAccount",
+            "type": "publicKey"
           },
           {
-            "name": "ammLtwapDecimals",
-            "type": "u8"
+            "name": "status",
+            "type": {
+              "defined": "VaultStatus"
+            }
+          },
+          {
+            "name": "conditionalMints",
+            "type": {
+              "vec": "publicKey"
+            }
           }
         ]
       }
     },
     {
-      "name": "proposalVault",
+      "name": "daoTreasury",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "proposal",
-            "type": "publicKey"
-          },
-          {
-            "name": "metaVaultAta",
+            "name": "dao",
             "type": "publicKey"
           },
           {
-            "name": "usdcVaultAta",
-            "type": "publicKey"
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "proposal",
+      "name": "dao",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "number",
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "proposer",
+            "name": "treasuryPdaBump",
+            "type": "u8"
+          },
+          {
+            "name": "treasuryPda",
             "type": "publicKey"
           },
           {
-            "name": "descriptionUrl",
-            "type": "string"
+            "name": "creator",
+            "type": "publicKey"
           },
           {
-            "name": "slotEnqueued",
-            "type": "u64"
+            "name": "metaMint",
+            "type": "publicKey"
           },
           {
-            "name": "slotsDuration",
-            "type": "u64"
+            "name": "usdcMint",
+            "type": "publicKey"
           },
           {
-            "name": "state",
+            "name": "metaMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "usdcMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "metaTicker",
             "type": {
-              "defined": "ProposalState"
+              "array": [
+                "u8",
+                8
+              ]
             }
           },
           {
-            "name": "instructions",
-            "type": "publicKey"
+            "name": "usdcTicker",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
           },
           {
-            "name": "proposalVault",
-            "type": "publicKey"
+            "name": "proposalCount",
+            "type": "u64"
           },
           {
-            "name": "isPassMarketCreated",
-            "type": "bool"
+            "name": "proposalsActive",
+            "type": "u32"
           },
           {
-            "name": "isFailMarketCreated",
-            "type": "bool"
+            "name": "lastProposalSlot",
+            "type": "u64"
           },
           {
-            "name": "metaMint",
-            "type": "publicKey"
+            "name": "queueHead",
+            "type": "u64"
           },
           {
-            "name": "usdcMint",
-            "type": "publicKey"
+            "name": "queueTail",
+            "type": "u64"
           },
           {
-            "name": "passMarketAmm",
+            "name": "guardian",
             "type": "publicKey"
           },
           {
-            "name": "failMarketAmm",
+            "name": "config",
+            "type": {
+              "defined": "DaoConfig"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "instructionPolicy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "dao",
             "type": "publicKey"
           },
           {
-            "name": "conditionalOnPassMetaMint",
+            "name": "isEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "rules",
+            "type": {
+              "vec": {
+                "defined": "InstructionPolicyRule"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dao",
             "type": "publicKey"
           },
           {
-            "name": "conditionalOnPassUsdcMint",
+            "name": "number",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
             "type": "publicKey"
           },
           {
-            "name": "conditionalOnFailMetaMint",
+            "name": "descriptionUrl",
+            "type": "string"
+          },
+          {
+            "name": "slotEnqueued",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "ProposalState"
+            }
+          },
+          {
+            "name": "queuePosition",
+            "type": "u64"
+          },
+          {
+            "name": "conditionalVaultCount",
+            "type": "u8"
+          },
+          {
+            "name": "metaMint",
             "type": "publicKey"
           },
           {
-            "name": "conditionalOnFailUsdcMint",
+            "name": "usdcMint",
             "type": "publicKey"
           },
+          {
+            "name": "outcomes",
+            "type": {
+              "vec": {
+                "defined": "ProposalOutcome"
+              }
+            }
+          },
+          {
+            "name": "winningOutcomeIndex",
+            "type": "u8"
+          },
+          {
+            "name": "scalar",
+            "type": {
+              "option": {
+                "defined": "ScalarRange"
+              }
+            }
+          },
+          {
+            "name": "prerequisites",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "dependentCount",
+            "type": "u32"
+          },
           {
             "name": "proposerInititialConditionalMetaMinted",
             "type": "u64"
@@ -1391,6 +2081,46 @@ export type Autocrat = {
           {
             "name": "proposerInititialConditionalUsdcMinted",
             "type": "u64"
+          },
+          {
+            "name": "slotsDuration",
+            "type": "u64"
+          },
+          {
+            "name": "finalizeWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "executionWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "executionDelaySlots",
+            "type": "u64"
+          },
+          {
+            "name": "passThresholdBps",
+            "type": "u64"
+          },
+          {
+            "name": "finalizerBountyUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "proposalFeeRefundBps",
+            "type": "u64"
+          },
+          {
+            "name": "slotFinalized",
+            "type": "u64"
+          },
+          {
+            "name": "ammSwapFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "ammLtwapDecimals",
+            "type": "u8"
           }
         ]
       }
@@ -1409,8 +2139,18 @@ export type Autocrat = {
             "type": "publicKey"
           },
           {
-            "name": "proposalInstructionsFrozen",
-            "type": "bool"
+            "name": "outcomeIndex",
+            "type": "u8"
+          },
+          {
+            "name": "executionCursor",
+            "type": "u64"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
           },
           {
             "name": "instructions",
@@ -1426,10 +2166,18 @@ export type Autocrat = {
   ],
   "types": [
     {
-      "name": "UpdateDaoParams",
+      "name": "InitializeDaoParams",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "metaTicker",
+            "type": "string"
+          },
+          {
+            "name": "usdcTicker",
+            "type": "string"
+          },
           {
             "name": "passThresholdBps",
             "type": {
@@ -1442,18 +2190,56 @@ export type Autocrat = {
               "option": "u64"
             }
           },
+          {
+            "name": "maxActiveProposals",
+            "type": {
+              "option": "u32"
+            }
+          },
           {
             "name": "finalizeWindowSlots",
             "type": {
               "option": "u64"
             }
           },
+          {
+            "name": "executionWindowSlots",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "executionDelaySlots",
+            "type": {
+              "option": "u64"
+            }
+          },
           {
             "name": "proposalFeeUsdc",
             "type": {
               "option": "u64"
             }
           },
+          {
+            "name": "proposalFeeCurve",
+            "type": {
+              "option": {
+                "defined": "ProposalFeeCurve"
+              }
+            }
+          },
+          {
+            "name": "finalizerBountyUsdc",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "proposalFeeRefundBps",
+            "type": {
+              "option": "u64"
+            }
+          },
           {
             "name": "ammInitialQuoteLiquidityAmount",
             "type": {
@@ -1471,264 +2257,1235 @@ export type Autocrat = {
             "type": {
               "option": "u8"
             }
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
           }
         ]
       }
     },
     {
-      "name": "ProposalAccount",
+      "name": "UpdateDaoParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pubkey",
-            "type": "publicKey"
+            "name": "passThresholdBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "isSigner",
-            "type": "bool"
+            "name": "proposalDurationSlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "isWritable",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ProposalInstruction",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
+            "name": "maxActiveProposals",
+            "type": {
+              "option": "u32"
+            }
           },
           {
-            "name": "accounts",
+            "name": "finalizeWindowSlots",
             "type": {
-              "vec": {
-                "defined": "ProposalAccount"
-              }
+              "option": "u64"
             }
           },
           {
-            "name": "data",
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ProposalState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Initialize"
+            "name": "executionWindowSlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "Pending"
+            "name": "executionDelaySlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "Passed"
+            "name": "proposalFeeUsdc",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "proposalFeeCurve",
+            "type": {
+              "option": {
+                "defined": "ProposalFeeCurve"
+              }
+            }
+          },
+          {
+            "name": "finalizerBountyUsdc",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "proposalFeeRefundBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "ammInitialQuoteLiquidityAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "ammSwapFeeBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "ammLtwapDecimals",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DaoConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalFeeUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "proposalFeeCurve",
+            "type": {
+              "defined": "ProposalFeeCurve"
+            }
+          },
+          {
+            "name": "finalizerBountyUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "proposalFeeRefundBps",
+            "type": "u64"
+          },
+          {
+            "name": "passThresholdBps",
+            "type": "u64"
+          },
+          {
+            "name": "proposalDurationSlots",
+            "type": "u64"
+          },
+          {
+            "name": "maxActiveProposals",
+            "type": "u32"
+          },
+          {
+            "name": "finalizeWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "executionWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "executionDelaySlots",
+            "type": "u64"
+          },
+          {
+            "name": "ammInitialQuoteLiquidityAmount",
+            "type": "u64"
+          },
+          {
+            "name": "ammSwapFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "ammLtwapDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InstructionPolicyRule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "discriminators",
+            "type": {
+              "vec": "bytes"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ScalarRange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minValue",
+            "type": "u64"
+          },
+          {
+            "name": "maxValue",
+            "type": "u64"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "dataOffset",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalOutcome",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isMarketCreated",
+            "type": "bool"
+          },
+          {
+            "name": "amm",
+            "type": "publicKey"
+          },
+          {
+            "name": "conditionalMetaMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "conditionalUsdcMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "instructions",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": {
+              "defined": "ProposalAccountKey"
+            }
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalInstruction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "ProposalAccount"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Finalized",
+            "fields": [
+              {
+                "name": "winningOutcomeIndex",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "Reverted"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalFeeCurve",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear",
+            "fields": [
+              {
+                "name": "incrementUsdc",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Exponential",
+            "fields": [
+              {
+                "name": "maxFeeUsdc",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "TimeDecaying",
+            "fields": [
+              {
+                "name": "peakFeeUsdc",
+                "type": "u64"
+              },
+              {
+                "name": "decayPerSlotUsdc",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initialize"
+          },
+          {
+            "name": "Queued"
+          },
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Passed"
           },
           {
             "name": "Failed"
+          },
+          {
+            "name": "Cancelled"
+          },
+          {
+            "name": "Executed"
+          },
+          {
+            "name": "ExecutionFailed"
+          },
+          {
+            "name": "Vetoed"
           }
         ]
       }
+    },
+    {
+      "name": "ProposalAccountKey",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pubkey",
+            "fields": [
+              "publicKey"
+            ]
+          },
+          {
+            "name": "LookupTable",
+            "fields": [
+              {
+                "name": "tableIndex",
+                "type": "u8"
+              },
+              {
+                "name": "addressIndex",
+                "type": "u8"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "ProposalExecutionQueued",
+      "fields": [
+        {
+          "name": "dao",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "winningOutcomeIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "scalarValue",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "executableSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalVetoed",
+      "fields": [
+        {
+          "name": "dao",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalExecuted",
+      "fields": [
+        {
+          "name": "dao",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
     {
-      "code": 6000,
-      "name": "ProposalIsNoLongerPending",
-      "msg": "The proposal is no longer pending"
+      "code": 6000,
+      "name": "ProposalIsNoLongerPending",
+      "msg": "The proposal is no longer pending"
+    },
+    {
+      "code": 6001,
+      "name": "AmmProposalMismatch",
+      "msg": "The provided amm does not match the pass or fail market for this proposal"
+    },
+    {
+      "code": 6002,
+      "name": "RemoveLiquidityBpsOutOfRange",
+      "msg": "Remove liquidity BPS is out of range"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidMarket",
+      "msg": "Either the `pass_market` or the `fail_market`'s tokens doesn't match the vaults supplied"
+    },
+    {
+      "code": 6004,
+      "name": "TWAPMarketTooOld",
+      "msg": "`TWAPMarket` must have an `initial_slot` within 50 slots of the proposal's `slot_enqueued`"
+    },
+    {
+      "code": 6005,
+      "name": "TWAPMarketInvalidExpectedValue",
+      "msg": "`TWAPMarket` has the wrong `expected_value`"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidSettlementAuthority",
+      "msg": "The vault's `settlement_authority` is not this proposal"
+    },
+    {
+      "code": 6007,
+      "name": "ProposalTooYoung",
+      "msg": "Proposal is too young to be executed or rejected"
+    },
+    {
+      "code": 6008,
+      "name": "ProposalStillPending",
+      "msg": "Proposal is still pending"
+    },
+    {
+      "code": 6009,
+      "name": "MarketsTooYoung",
+      "msg": "Markets too young for proposal to be finalized"
+    },
+    {
+      "code": 6010,
+      "name": "ProposalCannotPass",
+      "msg": "The market dictates that this proposal cannot pass"
+    },
+    {
+      "code": 6011,
+      "name": "ProposalAlreadyFinalized",
+      "msg": "This proposal has already been finalized"
+    },
+    {
+      "code": 6012,
+      "name": "InsufficientUnderlyingTokens",
+      "msg": "Insufficient underlying token balance to mint this amount of conditional tokens"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidVaultUnderlyingTokenAccount",
+      "msg": "This `vault_underlying_token_account` is not this vault's `underlying_token_account`"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidConditionalTokenMint",
+      "msg": "This conditional token mint is not this vault's conditional token mint"
+    },
+    {
+      "code": 6015,
+      "name": "CantRedeemConditionalTokens",
+      "msg": "Vault needs to be settled as finalized before users can redeem conditional tokens for underlying tokens"
+    },
+    {
+      "code": 6016,
+      "name": "VaultAlreadySettled",
+      "msg": "Once a vault has been settled, its status as either finalized or reverted cannot be changed"
+    },
+    {
+      "code": 6017,
+      "name": "ProposerCannotPullLiquidityWhileMarketIsPending",
+      "msg": "Proposer cannot remove intitial liquidity while the proposal is pending"
+    },
+    {
+      "code": 6018,
+      "name": "NonConsecutiveProposalNumber",
+      "msg": "Proposal numbers must be consecutive"
+    },
+    {
+      "code": 6019,
+      "name": "AddLiquidityCalculationError",
+      "msg": "Add liquidity calculation error"
+    },
+    {
+      "code": 6020,
+      "name": "DecimalScaleError",
+      "msg": "Error in decimal scale conversion"
+    },
+    {
+      "code": 6021,
+      "name": "IdenticalDaoMints",
+      "msg": "The governance mint and the quote mint must be different"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidMintDecimals",
+      "msg": "Mint decimals are out of range"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidTicker",
+      "msg": "Tickers must be 1 to 8 ASCII letters or digits"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidPassThresholdBps",
+      "msg": "Pass threshold BPS is out of range"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidProposalDurationSlots",
+      "msg": "Proposal duration slots is out of range"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidFinalizeWindowSlots",
+      "msg": "Finalize window slots is out of range"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidExecutionWindowSlots",
+      "msg": "Execution window slots is out of range"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidExecutionDelaySlots",
+      "msg": "Execution delay slots is out of range"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidAmmInitialQuoteLiquidityAmount",
+      "msg": "AMM initial quote liquidity amount is out of range"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidAmmSwapFeeBps",
+      "msg": "AMM swap fee BPS is out of range"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidAmmLtwapDecimals",
+      "msg": "AMM LTWAP decimals is out of range"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidFinalizerBountyUsdc",
+      "msg": "The finalizer bounty can't be more than the proposal fee"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidProposalFeeRefundBps",
+      "msg": "The proposal fee refund can't be more than 100%"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidProposalFeeCurve",
+      "msg": "The proposal fee curve can't charge less than the proposal fee"
+    },
+    {
+      "code": 6035,
+      "name": "ProposalFeeOverflow",
+      "msg": "The proposal fee overflowed"
     },
     {
-      "code": 6001,
-      "name": "AmmProposalMismatch",
-      "msg": "The provided amm does not match the pass or fail market for this proposal"
+      "code": 6036,
+      "name": "InvalidMaxActiveProposals",
+      "msg": "At least one proposal has to be able to trade at a time"
     },
     {
-      "code": 6002,
-      "name": "RemoveLiquidityBpsOutOfRange",
-      "msg": "Remove liquidity BPS is out of range"
+      "code": 6037,
+      "name": "InvalidQueuedProposal",
+      "msg": "The next queued proposal has to be promoted when a proposal is finalized"
     },
     {
-      "code": 6003,
-      "name": "InvalidMarket",
-      "msg": "Either the `pass_market` or the `fail_market`'s tokens doesn't match the vaults supplied"
+      "code": 6038,
+      "name": "ArithmeticOverflow",
+      "msg": "Arithmetic overflow"
     },
     {
-      "code": 6004,
-      "name": "TWAPMarketTooOld",
-      "msg": "`TWAPMarket` must have an `initial_slot` within 50 slots of the proposal's `slot_enqueued`"
+      "code": 6039,
+      "name": "ProposalNotPending",
+      "msg": "The proposal is not pending"
     },
     {
-      "code": 6005,
-      "name": "TWAPMarketInvalidExpectedValue",
-      "msg": "`TWAPMarket` has the wrong `expected_value`"
+      "code": 6040,
+      "name": "ProposalCannotBeCancelled",
+      "msg": "Only proposals that haven't been submitted can be cancelled"
     },
     {
-      "code": 6006,
-      "name": "InvalidSettlementAuthority",
-      "msg": "One of the vaults has an invalid `settlement_authority`"
+      "code": 6041,
+      "name": "MarketSideNotCreated",
+      "msg": "This outcome's market hasn't been created"
     },
     {
-      "code": 6007,
-      "name": "ProposalTooYoung",
-      "msg": "Proposal is too young to be executed or rejected"
+      "code": 6042,
+      "name": "MarketSidesStillOpen",
+      "msg": "Every outcome's market needs to be closed before the proposal can be"
     },
     {
-      "code": 6008,
-      "name": "ProposalStillPending",
-      "msg": "Proposal is still pending"
+      "code": 6043,
+      "name": "ConditionalTokensOutstanding",
+      "msg": "Conditional tokens are still held outside of the proposer's accounts"
     },
     {
-      "code": 6009,
-      "name": "MarketsTooYoung",
-      "msg": "Markets too young for proposal to be finalized"
+      "code": 6044,
+      "name": "InvalidProposalInstructions",
+      "msg": "The proposal instructions account doesn't belong to this proposal"
     },
     {
-      "code": 6010,
-      "name": "ProposalCannotPass",
-      "msg": "The market dictates that this proposal cannot pass"
+      "code": 6045,
+      "name": "ProposalNotFinalized",
+      "msg": "The proposal hasn't been finalized"
     },
     {
-      "code": 6011,
-      "name": "ProposalAlreadyFinalized",
-      "msg": "This proposal has already been finalized"
+      "code": 6046,
+      "name": "ConditionalTokensNotRedeemed",
+      "msg": "Every winning conditional token needs to be redeemed before the proposal can be closed"
     },
     {
-      "code": 6012,
-      "name": "InvalidVaultNonce",
-      "msg": "A conditional vault has an invalid nonce. A nonce should encode pass = 0 / fail = 1 in its most significant bit, base = 0 / quote = 1 in its second most significant bit, and the proposal number in least significant 32 bits"
+      "code": 6047,
+      "name": "ProposalNotPassed",
+      "msg": "The proposal hasn't passed, or has already been executed"
     },
     {
-      "code": 6013,
-      "name": "InsufficientUnderlyingTokens",
-      "msg": "Insufficient underlying token balance to mint this amount of conditional tokens"
+      "code": 6048,
+      "name": "ProposalAwaitingExecution",
+      "msg": "The proposal passed but hasn't been executed yet"
     },
     {
-      "code": 6014,
-      "name": "InvalidVaultUnderlyingTokenAccount",
-      "msg": "This `vault_underlying_token_account` is not this vault's `underlying_token_account`"
+      "code": 6049,
+      "name": "InstructionExecutedOutOfOrder",
+      "msg": "Proposal instructions have to be executed in order, starting at the execution cursor"
     },
     {
-      "code": 6015,
-      "name": "InvalidConditionalTokenMint",
-      "msg": "This conditional token mint is not this vault's conditional token mint"
+      "code": 6050,
+      "name": "InstructionIndexOutOfRange",
+      "msg": "The instruction range is past the end of the proposal's instructions"
     },
     {
-      "code": 6016,
-      "name": "CantRedeemConditionalTokens",
-      "msg": "Vault needs to be settled as finalized before users can redeem conditional tokens for underlying tokens"
+      "code": 6051,
+      "name": "ProposalExecutionDelayed",
+      "msg": "The proposal is still in its execution delay"
     },
     {
-      "code": 6017,
-      "name": "VaultAlreadySettled",
-      "msg": "Once a vault has been settled, its status as either finalized or reverted cannot be changed"
+      "code": 6052,
+      "name": "InvalidGuardian",
+      "msg": "The DAO has no guardian, or this isn't it"
     },
     {
-      "code": 6018,
-      "name": "ProposerCannotPullLiquidityWhileMarketIsPending",
-      "msg": "Proposer cannot remove intitial liquidity while the proposal is pending"
+      "code": 6053,
+      "name": "VetoWindowClosed",
+      "msg": "Proposals can only be vetoed during their execution delay"
     },
     {
-      "code": 6019,
-      "name": "NonConsecutiveProposalNumber",
-      "msg": "Proposal numbers must be consecutive"
+      "code": 6054,
+      "name": "InstructionNotAllowed",
+      "msg": "The DAO's instruction policy doesn't allow this instruction"
     },
     {
-      "code": 6020,
-      "name": "AddLiquidityCalculationError",
-      "msg": "Add liquidity calculation error"
+      "code": 6055,
+      "name": "InvalidInstructionPolicy",
+      "msg": "The instruction policy has too many rules or discriminators, or an empty or oversized discriminator"
+    },
+    {
+      "code": 6056,
+      "name": "InstructionAccountsMismatch",
+      "msg": "The remaining accounts have to be each instruction's accounts, in order and with matching privileges, followed by its program"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidLookupTables",
+      "msg": "The lookup tables don't match the ones recorded on the proposal instructions"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidLookupTableIndex",
+      "msg": "An instruction account references a lookup table or address that doesn't exist"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidOutcomeIndex",
+      "msg": "The outcome index is out of range for this proposal"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidOutcomeCount",
+      "msg": "Proposals need between 2 and 8 outcomes"
+    },
+    {
+      "code": 6061,
+      "name": "StatusQuoOutcomeInstructions",
+      "msg": "The status quo outcome can't have instructions"
+    },
+    {
+      "code": 6062,
+      "name": "OutcomeMarketsNotCreated",
+      "msg": "Every outcome's market has to be created before the proposal can be submitted"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidOutcomeAccounts",
+      "msg": "The remaining accounts have to be the accounts of every outcome, in outcome order"
+    },
+    {
+      "code": 6064,
+      "name": "ProposalInstructionsStillNeeded",
+      "msg": "The proposal instructions can't be closed while the proposal is trading or awaiting execution"
+    },
+    {
+      "code": 6065,
+      "name": "ProposalInstructionsStillOpen",
+      "msg": "Every outcome's instructions need to be closed before the proposal can be"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidScalarRange",
+      "msg": "Scalar proposals need a non-empty range and at least 2 value buckets"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidScalarTemplate",
+      "msg": "The scalar instruction template has no room for the value at its data offset"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidPrerequisites",
+      "msg": "Prerequisites have to be distinct earlier proposals, listed in increasing order, that haven't failed"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPrerequisiteAccounts",
+      "msg": "The remaining accounts have to be the proposal's prerequisites, in the order they're listed"
+    },
+    {
+      "code": 6070,
+      "name": "PrerequisitesNotExecuted",
+      "msg": "Every prerequisite has to be executed before this proposal can be"
+    },
+    {
+      "code": 6071,
+      "name": "ProposalHasDependents",
+      "msg": "The proposal can't be closed while proposals that depend on it are open"
+    },
+    {
+      "code": 6072,
+      "name": "InsufficientConditionalTokens",
+      "msg": "Can't redeem more conditional tokens than the user holds"
+    },
+    {
+      "code": 6073,
+      "name": "ConditionalVaultNotSettled",
+      "msg": "The conditional vault has to be settled first"
+    },
+    {
+      "code": 6074,
+      "name": "ConditionalVaultsStillOpen",
+      "msg": "Every conditional vault of the proposal needs to be closed before the proposal can be"
+    },
+    {
+      "code": 6075,
+      "name": "ProposalAlreadySubmitted",
+      "msg": "Conditional vaults can only be set up before the proposal is submitted"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidMarketVault",
+      "msg": "Only the governance and quote tokens are traded on the proposal's markets"
+    },
+    {
+      "code": 6077,
+      "name": "MarketsClosed",
+      "msg": "The proposal's markets have stopped trading"
+    }
+  ]
+};
+
+export const IDL: Autocrat = {
+  "version": "0.1.0",
+  "name": "autocrat",
+  "instructions": [
+    {
+      "name": "initializeDao",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "dao",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metaMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "daoParams",
+          "type": {
+            "defined": "InitializeDaoParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateDao",
+      "accounts": [
+        {
+          "name": "dao",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "daoParams",
+          "type": {
+            "defined": "UpdateDaoParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateInstructionPolicy",
+      "accounts": [
+        {
+          "name": "dao",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructionPolicy",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "isEnabled",
+          "type": "bool"
+        },
+        {
+          "name": "rules",
+          "type": {
+            "vec": {
+              "defined": "InstructionPolicyRule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createProposalInstructions",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposalInstructions",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        },
+        {
+          "name": "instructions",
+          "type": {
+            "vec": {
+              "defined": "ProposalInstruction"
+            }
+          }
+        },
+        {
+          "name": "lookupTables",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
     },
     {
-      "code": 6021,
-      "name": "DecimalScaleError",
-      "msg": "Error in decimal scale conversion"
-    }
-  ]
-};
-
-export const IDL: Autocrat = {
-  "version": "0.1.0",
-  "name": "autocrat",
-  "instructions": [
-    {
-      "name": "initializeDao",
+      "name": "addProposalInstructions",
       "accounts": [
         {
-          "name": "payer",
+          "name": "proposer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "dao",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "daoTreasury",
+          "name": "proposalInstructions",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaMint",
+          "name": "instructionPolicy",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcMint",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "tokenProgram",
+          "name": "instructions",
+          "type": {
+            "vec": {
+              "defined": "ProposalInstruction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeProposalInstructions",
+      "accounts": [
+        {
+          "name": "user",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposalInstructions",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "updateDao",
+      "name": "createProposal",
       "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "dao",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "daoTreasury",
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metaVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metaMint",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "usdcMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metaProposerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "usdcProposerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metaVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "daoParams",
+          "name": "descriptionUrl",
+          "type": "string"
+        },
+        {
+          "name": "mintCondMeta",
+          "type": "u64"
+        },
+        {
+          "name": "mintCondUsdc",
+          "type": "u64"
+        },
+        {
+          "name": "outcomeCount",
+          "type": "u8"
+        },
+        {
+          "name": "scalar",
           "type": {
-            "defined": "UpdateDaoParams"
+            "option": {
+              "defined": "ScalarRange"
+            }
+          }
+        },
+        {
+          "name": "prerequisites",
+          "type": {
+            "vec": "u64"
           }
         }
       ]
     },
     {
-      "name": "createProposalInstructions",
+      "name": "createProposalMarketSide",
       "accounts": [
         {
           "name": "proposer",
@@ -1741,52 +3498,122 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalInstructions",
+          "name": "metaVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "usdcVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "dao",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "metaMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "usdcMint",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "instructions",
-          "type": {
-            "vec": {
-              "defined": "ProposalInstruction"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "addProposalInstructions",
-      "accounts": [
+          "name": "conditionalMetaMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaMetadata",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "mint is a token-2022 mint"
+          ]
+        },
+        {
+          "name": "conditionalUsdcMetadata",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "mint is a token-2022 mint"
+          ]
+        },
+        {
+          "name": "conditionalMetaProposerAta",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "associated token program"
+          ]
+        },
+        {
+          "name": "conditionalUsdcProposerAta",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "associated token program"
+          ]
+        },
+        {
+          "name": "conditionalMetaAmmVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcAmmVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
-          "name": "proposer",
-          "isMut": true,
-          "isSigner": true
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "proposal",
-          "isMut": true,
+          "name": "associatedTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposalInstructions",
-          "isMut": true,
+          "name": "metaTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1794,21 +3621,30 @@ export const IDL: Autocrat = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "instructions",
-          "type": {
-            "vec": {
-              "defined": "ProposalInstruction"
-            }
-          }
+          "name": "outcomeIndex",
+          "type": "u8"
+        },
+        {
+          "name": "ammCondMetaDeposit",
+          "type": "u64"
+        },
+        {
+          "name": "ammCondUsdcDeposit",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "createProposal",
+      "name": "submitProposal",
       "accounts": [
         {
           "name": "proposer",
@@ -1825,39 +3661,32 @@ export const IDL: Autocrat = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "proposalVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "metaMint",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "usdcMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metaProposerAta",
+          "name": "usdcProposerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcProposerAta",
+          "name": "usdcFeeEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaVaultAta",
-          "isMut": true,
-          "isSigner": false
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
         },
         {
-          "name": "usdcVaultAta",
-          "isMut": true,
+          "name": "ammProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1876,23 +3705,10 @@ export const IDL: Autocrat = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "descriptionUrl",
-          "type": "string"
-        },
-        {
-          "name": "mintCondMeta",
-          "type": "u64"
-        },
-        {
-          "name": "mintCondUsdc",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createProposalMarketSide",
+      "name": "cancelProposalMarketSide",
       "accounts": [
         {
           "name": "proposer",
@@ -1905,13 +3721,13 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
-          "isMut": true,
+          "name": "metaVault",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "dao",
-          "isMut": true,
+          "name": "usdcVault",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1932,25 +3748,15 @@ export const IDL: Autocrat = {
             "CHECK"
           ]
         },
-        {
-          "name": "metaMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "usdcMint",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "conditionalMetaMint",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "conditionalUsdcMint",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "conditionalMetaProposerAta",
@@ -1983,7 +3789,12 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1995,59 +3806,62 @@ export const IDL: Autocrat = {
       ],
       "args": [
         {
-          "name": "isPassMarket",
-          "type": "bool"
-        },
-        {
-          "name": "ammCondMetaDeposit",
-          "type": "u64"
-        },
-        {
-          "name": "ammCondUsdcDeposit",
-          "type": "u64"
+          "name": "outcomeIndex",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "submitProposal",
+      "name": "cancelProposal",
       "accounts": [
         {
           "name": "proposer",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "dao",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeProposal",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "daoTreasury",
-          "isMut": false,
+          "name": "proposal",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposal",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "usdcFeeEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalInstructions",
+          "name": "dao",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcMint",
+          "name": "daoTreasury",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcProposerAta",
+          "name": "usdcMint",
           "isMut": true,
           "isSigner": false
         },
@@ -2057,12 +3871,12 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "passMarketAmm",
+          "name": "usdcProposerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "failMarketAmm",
+          "name": "usdcUserAta",
           "isMut": true,
           "isSigner": false
         },
@@ -2098,11 +3912,32 @@ export const IDL: Autocrat = {
       "args": []
     },
     {
-      "name": "finalizeProposal",
+      "name": "vetoProposal",
       "accounts": [
         {
-          "name": "user",
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "dao",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -2113,11 +3948,12 @@ export const IDL: Autocrat = {
         {
           "name": "proposalInstructions",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "dao",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2126,17 +3962,60 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "passMarketAmm",
+          "name": "instructionPolicy",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "instructionIndex",
+          "type": "u64"
+        },
+        {
+          "name": "instructionCount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeProposalAmmPosition",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "amm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "failMarketAmm",
+          "name": "positionOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "ammProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -2144,90 +4023,125 @@ export const IDL: Autocrat = {
       "args": []
     },
     {
-      "name": "mintConditionalTokens",
+      "name": "closeProposalMarketSide",
       "accounts": [
         {
           "name": "user",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "proposal",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaMint",
-          "isMut": false,
+          "name": "amm",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcMint",
+          "name": "ammAuthPda",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
         },
         {
-          "name": "conditionalOnPassMetaMint",
+          "name": "conditionalMetaMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcMint",
+          "name": "conditionalUsdcMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaMint",
+          "name": "conditionalMetaAmmVaultAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcMint",
+          "name": "conditionalUsdcAmmVaultAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaUserAta",
-          "isMut": true,
+          "name": "ammProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcUserAta",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeProposal",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassMetaUserAta",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeConditionalVault",
+      "accounts": [
         {
-          "name": "conditionalOnPassUsdcUserAta",
+          "name": "proposer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "conditionalOnFailMetaUserAta",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcUserAta",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaVaultAta",
-          "isMut": true,
+          "name": "underlyingMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcVaultAta",
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -2247,22 +4161,13 @@ export const IDL: Autocrat = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "metaAmount",
-          "type": "u64"
-        },
-        {
-          "name": "usdcAmount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "mergeConditionalTokens",
+      "name": "createConditionalVaultMint",
       "accounts": [
         {
-          "name": "user",
+          "name": "proposer",
           "isMut": true,
           "isSigner": true
         },
@@ -2272,103 +4177,121 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaMint",
+          "name": "underlyingMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcMint",
-          "isMut": false,
+          "name": "conditionalMint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassMetaMint",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcMint",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
+        {
+          "name": "outcomeIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "mintConditionalTokens",
+      "accounts": [
         {
-          "name": "conditionalOnFailMetaMint",
+          "name": "user",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "conditionalOnFailUsdcMint",
-          "isMut": true,
+          "name": "proposal",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metaUserAta",
-          "isMut": true,
+          "name": "vault",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcUserAta",
-          "isMut": true,
+          "name": "underlyingMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassMetaUserAta",
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcUserAta",
+          "name": "userUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaUserAta",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mergeConditionalTokens",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "conditionalOnFailUsdcUserAta",
-          "isMut": true,
+          "name": "vault",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metaVaultAta",
-          "isMut": true,
+          "name": "underlyingMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcVaultAta",
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "userUnderlyingTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "metaAmount",
-          "type": "u64"
-        },
-        {
-          "name": "usdcAmount",
+          "name": "amount",
           "type": "u64"
         }
       ]
@@ -2378,86 +4301,103 @@ export const IDL: Autocrat = {
       "accounts": [
         {
           "name": "user",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "proposal",
+          "name": "vault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposalVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "metaMint",
+          "name": "underlyingMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcMint",
-          "isMut": false,
+          "name": "vaultUnderlyingTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassMetaMint",
+          "name": "userUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcMint",
+          "name": "conditionalMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaMint",
+          "name": "userConditionalTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcMint",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "metaUserAta",
-          "isMut": true,
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleConditionalVault",
+      "accounts": [
+        {
+          "name": "proposal",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "usdcUserAta",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeConditionalVault",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "conditionalOnPassMetaUserAta",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnPassUsdcUserAta",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailMetaUserAta",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "conditionalOnFailUsdcUserAta",
+          "name": "underlyingMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metaVaultAta",
+          "name": "vaultUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "usdcVaultAta",
+          "name": "proposerUnderlyingTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -2540,7 +4480,12 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
+          "name": "metaVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
           "isMut": false,
           "isSigner": false
         },
@@ -2616,7 +4561,12 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -2659,8 +4609,13 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
-          "isMut": true,
+          "name": "metaVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2735,7 +4690,12 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -2766,8 +4726,13 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "proposalVault",
-          "isMut": true,
+          "name": "metaVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcVault",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2834,7 +4799,139 @@ export const IDL: Autocrat = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "isQuoteToBase",
+          "type": "bool"
+        },
+        {
+          "name": "inputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "outputAmountMin",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintAndSwap",
+      "accounts": [
+        {
+          "name": "mintConditionalTokens",
+          "accounts": [
+            {
+              "name": "user",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "proposal",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vault",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "underlyingMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vaultUnderlyingTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userUnderlyingTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "amm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammAuthPda",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK"
+          ]
+        },
+        {
+          "name": "conditionalMetaMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaUserAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcUserAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalMetaVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalUsdcVaultAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metaTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "usdcTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -2846,11 +4943,11 @@ export const IDL: Autocrat = {
       ],
       "args": [
         {
-          "name": "isQuoteToBase",
-          "type": "bool"
+          "name": "outcomeIndex",
+          "type": "u8"
         },
         {
-          "name": "inputAmount",
+          "name": "amount",
           "type": "u64"
         },
         {
@@ -2861,6 +4958,42 @@ export const IDL: Autocrat = {
     }
   ],
   "accounts": [
+    {
+      "name": "conditionalVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "settlementAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "underlyingMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "underlyingTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "VaultStatus"
+            }
+          },
+          {
+            "name": "conditionalMints",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "daoTreasury",
       "type": {
@@ -2871,262 +5004,620 @@ export const IDL: Autocrat = {
             "type": "publicKey"
           },
           {
-            "name": "bump",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dao",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "treasuryPdaBump",
+            "type": "u8"
+          },
+          {
+            "name": "treasuryPda",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "metaMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "usdcMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "metaMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "usdcMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "metaTicker",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "usdcTicker",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "proposalCount",
+            "type": "u64"
+          },
+          {
+            "name": "proposalsActive",
+            "type": "u32"
+          },
+          {
+            "name": "lastProposalSlot",
+            "type": "u64"
+          },
+          {
+            "name": "queueHead",
+            "type": "u64"
+          },
+          {
+            "name": "queueTail",
+            "type": "u64"
+          },
+          {
+            "name": "guardian",
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "DaoConfig"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "instructionPolicy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "dao",
+            "type": "publicKey"
+          },
+          {
+            "name": "isEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "rules",
+            "type": {
+              "vec": {
+                "defined": "InstructionPolicyRule"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dao",
+            "type": "publicKey"
+          },
+          {
+            "name": "number",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "descriptionUrl",
+            "type": "string"
+          },
+          {
+            "name": "slotEnqueued",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "ProposalState"
+            }
+          },
+          {
+            "name": "queuePosition",
+            "type": "u64"
+          },
+          {
+            "name": "conditionalVaultCount",
+            "type": "u8"
+          },
+          {
+            "name": "metaMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "usdcMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "outcomes",
+            "type": {
+              "vec": {
+                "defined": "ProposalOutcome"
+              }
+            }
+          },
+          {
+            "name": "winningOutcomeIndex",
+            "type": "u8"
+          },
+          {
+            "name": "scalar",
+            "type": {
+              "option": {
+                "defined": "ScalarRange"
+              }
+            }
+          },
+          {
+            "name": "prerequisites",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "dependentCount",
+            "type": "u32"
+          },
+          {
+            "name": "proposerInititialConditionalMetaMinted",
+            "type": "u64"
+          },
+          {
+            "name": "proposerInititialConditionalUsdcMinted",
+            "type": "u64"
+          },
+          {
+            "name": "slotsDuration",
+            "type": "u64"
+          },
+          {
+            "name": "finalizeWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "executionWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "executionDelaySlots",
+            "type": "u64"
+          },
+          {
+            "name": "passThresholdBps",
+            "type": "u64"
+          },
+          {
+            "name": "finalizerBountyUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "proposalFeeRefundBps",
+            "type": "u64"
+          },
+          {
+            "name": "slotFinalized",
+            "type": "u64"
+          },
+          {
+            "name": "ammSwapFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "ammLtwapDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalInstructions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposal",
+            "type": "publicKey"
+          },
+          {
+            "name": "outcomeIndex",
             "type": "u8"
+          },
+          {
+            "name": "executionCursor",
+            "type": "u64"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "instructions",
+            "type": {
+              "vec": {
+                "defined": "ProposalInstruction"
+              }
+            }
           }
         ]
       }
-    },
+    }
+  ],
+  "types": [
     {
-      "name": "dao",
+      "name": "InitializeDaoParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "treasuryPdaBump",
-            "type": "u8"
+            "name": "metaTicker",
+            "type": "string"
           },
           {
-            "name": "treasuryPda",
-            "type": "publicKey"
+            "name": "usdcTicker",
+            "type": "string"
           },
           {
-            "name": "metaMint",
-            "type": "publicKey"
+            "name": "passThresholdBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "usdcMint",
-            "type": "publicKey"
+            "name": "proposalDurationSlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "proposalCount",
-            "type": "u64"
+            "name": "maxActiveProposals",
+            "type": {
+              "option": "u32"
+            }
           },
           {
-            "name": "proposalsActive",
-            "type": "u32"
+            "name": "finalizeWindowSlots",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "executionWindowSlots",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "executionDelaySlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "proposalFeeUsdc",
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "passThresholdBps",
-            "type": "u64"
+            "name": "proposalFeeCurve",
+            "type": {
+              "option": {
+                "defined": "ProposalFeeCurve"
+              }
+            }
           },
           {
-            "name": "proposalDurationSlots",
-            "type": "u64"
+            "name": "finalizerBountyUsdc",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "finalizeWindowSlots",
-            "type": "u64"
+            "name": "proposalFeeRefundBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "ammInitialQuoteLiquidityAmount",
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "ammSwapFeeBps",
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "ammLtwapDecimals",
-            "type": "u8"
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
           }
         ]
       }
     },
     {
-      "name": "proposalVault",
+      "name": "UpdateDaoParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "passThresholdBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "proposal",
-            "type": "publicKey"
+            "name": "proposalDurationSlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "metaVaultAta",
-            "type": "publicKey"
+            "name": "maxActiveProposals",
+            "type": {
+              "option": "u32"
+            }
           },
           {
-            "name": "usdcVaultAta",
-            "type": "publicKey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "proposal",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "finalizeWindowSlots",
+            "type": {
+              "option": "u64"
+            }
+          },
           {
-            "name": "number",
-            "type": "u64"
+            "name": "executionWindowSlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "proposer",
-            "type": "publicKey"
+            "name": "executionDelaySlots",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "descriptionUrl",
-            "type": "string"
+            "name": "proposalFeeUsdc",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "slotEnqueued",
-            "type": "u64"
+            "name": "proposalFeeCurve",
+            "type": {
+              "option": {
+                "defined": "ProposalFeeCurve"
+              }
+            }
           },
           {
-            "name": "slotsDuration",
-            "type": "u64"
+            "name": "finalizerBountyUsdc",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "state",
+            "name": "proposalFeeRefundBps",
             "type": {
-              "defined": "ProposalState"
+              "option": "u64"
             }
           },
           {
-            "name": "instructions",
-            "type": "publicKey"
+            "name": "ammInitialQuoteLiquidityAmount",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "proposalVault",
-            "type": "publicKey"
+            "name": "ammSwapFeeBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "isPassMarketCreated",
-            "type": "bool"
+            "name": "ammLtwapDecimals",
+            "type": {
+              "option": "u8"
+            }
           },
           {
-            "name": "isFailMarketCreated",
-            "type": "bool"
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DaoConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalFeeUsdc",
+            "type": "u64"
           },
           {
-            "name": "metaMint",
-            "type": "publicKey"
+            "name": "proposalFeeCurve",
+            "type": {
+              "defined": "ProposalFeeCurve"
+            }
           },
           {
-            "name": "usdcMint",
-            "type": "publicKey"
+            "name": "finalizerBountyUsdc",
+            "type": "u64"
           },
           {
-            "name": "passMarketAmm",
-            "type": "publicKey"
+            "name": "proposalFeeRefundBps",
+            "type": "u64"
           },
           {
-            "name": "failMarketAmm",
-            "type": "publicKey"
+            "name": "passThresholdBps",
+            "type": "u64"
           },
           {
-            "name": "conditionalOnPassMetaMint",
-            "type": "publicKey"
+            "name": "proposalDurationSlots",
+            "type": "u64"
           },
           {
-            "name": "conditionalOnPassUsdcMint",
-            "type": "publicKey"
+            "name": "maxActiveProposals",
+            "type": "u32"
           },
           {
-            "name": "conditionalOnFailMetaMint",
-            "type": "publicKey"
+            "name": "finalizeWindowSlots",
+            "type": "u64"
           },
           {
-            "name": "conditionalOnFailUsdcMint",
-            "type": "publicKey"
+            "name": "executionWindowSlots",
+            "type": "u64"
           },
           {
-            "name": "proposerInititialConditionalMetaMinted",
+            "name": "executionDelaySlots",
             "type": "u64"
           },
           {
-            "name": "proposerInititialConditionalUsdcMinted",
+            "name": "ammInitialQuoteLiquidityAmount",
+            "type": "u64"
+          },
+          {
+            "name": "ammSwapFeeBps",
             "type": "u64"
+          },
+          {
+            "name": "ammLtwapDecimals",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "proposalInstructions",
+      "name": "InstructionPolicyRule",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "proposer",
-            "type": "publicKey"
-          },
-          {
-            "name": "proposal",
+            "name": "programId",
             "type": "publicKey"
           },
           {
-            "name": "proposalInstructionsFrozen",
-            "type": "bool"
-          },
-          {
-            "name": "instructions",
+            "name": "discriminators",
             "type": {
-              "vec": {
-                "defined": "ProposalInstruction"
-              }
+              "vec": "bytes"
             }
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "UpdateDaoParams",
+      "name": "ScalarRange",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "passThresholdBps",
-            "type": {
-              "option": "u64"
-            }
+            "name": "minValue",
+            "type": "u64"
           },
           {
-            "name": "proposalDurationSlots",
-            "type": {
-              "option": "u64"
-            }
+            "name": "maxValue",
+            "type": "u64"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u32"
           },
           {
-            "name": "finalizeWindowSlots",
-            "type": {
-              "option": "u64"
-            }
+            "name": "dataOffset",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalOutcome",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isMarketCreated",
+            "type": "bool"
           },
           {
-            "name": "proposalFeeUsdc",
-            "type": {
-              "option": "u64"
-            }
+            "name": "amm",
+            "type": "publicKey"
           },
           {
-            "name": "ammInitialQuoteLiquidityAmount",
-            "type": {
-              "option": "u64"
-            }
+            "name": "conditionalMetaMint",
+            "type": "publicKey"
           },
           {
-            "name": "ammSwapFeeBps",
-            "type": {
-              "option": "u64"
-            }
+            "name": "conditionalUsdcMint",
+            "type": "publicKey"
           },
           {
-            "name": "ammLtwapDecimals",
-            "type": {
-              "option": "u8"
-            }
+            "name": "instructions",
+            "type": "publicKey"
           }
         ]
       }
@@ -3137,8 +5628,10 @@ export const IDL: Autocrat = {
         "kind": "struct",
         "fields": [
           {
-            "name": "pubkey",
-            "type": "publicKey"
+            "name": "key",
+            "type": {
+              "defined": "ProposalAccountKey"
+            }
           },
           {
             "name": "isSigner",
@@ -3175,6 +5668,68 @@ export const IDL: Autocrat = {
         ]
       }
     },
+    {
+      "name": "VaultStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Finalized",
+            "fields": [
+              {
+                "name": "winningOutcomeIndex",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "Reverted"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalFeeCurve",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear",
+            "fields": [
+              {
+                "name": "incrementUsdc",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Exponential",
+            "fields": [
+              {
+                "name": "maxFeeUsdc",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "TimeDecaying",
+            "fields": [
+              {
+                "name": "peakFeeUsdc",
+                "type": "u64"
+              },
+              {
+                "name": "decayPerSlotUsdc",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
     {
       "name": "ProposalState",
       "type": {
@@ -3183,6 +5738,9 @@ export const IDL: Autocrat = {
           {
             "name": "Initialize"
           },
+          {
+            "name": "Queued"
+          },
           {
             "name": "Pending"
           },
@@ -3191,11 +5749,119 @@ export const IDL: Autocrat = {
           },
           {
             "name": "Failed"
+          },
+          {
+            "name": "Cancelled"
+          },
+          {
+            "name": "Executed"
+          },
+          {
+            "name": "ExecutionFailed"
+          },
+          {
+            "name": "Vetoed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalAccountKey",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pubkey",
+            "fields": [
+              "publicKey"
+            ]
+          },
+          {
+            "name": "LookupTable",
+            "fields": [
+              {
+                "name": "tableIndex",
+                "type": "u8"
+              },
+              {
+                "name": "addressIndex",
+                "type": "u8"
+              }
+            ]
           }
         ]
       }
     }
   ],
+  "events": [
+    {
+      "name": "ProposalExecutionQueued",
+      "fields": [
+        {
+          "name": "dao",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "winningOutcomeIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "scalarValue",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "executableSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalVetoed",
+      "fields": [
+        {
+          "name": "dao",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalExecuted",
+      "fields": [
+        {
+          "name": "dao",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -3230,7 +5896,7 @@ export const IDL: Autocrat = {
     {
       "code": 6006,
       "name": "InvalidSettlementAuthority",
-      "msg": "One of the vaults has an invalid `settlement_authority`"
+      "msg": "The vault's `settlement_authority` is not this proposal"
     },
     {
       "code": 6007,
@@ -3259,53 +5925,333 @@ export const IDL: Autocrat = {
     },
     {
       "code": 6012,
-      "name": "InvalidVaultNonce",
-      "msg": "A conditional vault has an invalid nonce. A nonce should encode pass = 0 / fail = 1 in its most significant bit, base = 0 / quote = 1 in its second most significant bit, and the proposal number in least significant 32 bits"
-    },
-    {
-      "code": 6013,
       "name": "InsufficientUnderlyingTokens",
       "msg": "Insufficient underlying token balance to mint this amount of conditional tokens"
     },
     {
-      "code": 6014,
+      "code": 6013,
       "name": "InvalidVaultUnderlyingTokenAccount",
       "msg": "This `vault_underlying_token_account` is not this vault's `underlying_token_account`"
     },
     {
-      "code": 6015,
+      "code": 6014,
       "name": "InvalidConditionalTokenMint",
       "msg": "This conditional token mint is not this vault's conditional token mint"
     },
     {
-      "code": 6016,
+      "code": 6015,
       "name": "CantRedeemConditionalTokens",
       "msg": "Vault needs to be settled as finalized before users can redeem conditional tokens for underlying tokens"
     },
     {
-      "code": 6017,
+      "code": 6016,
       "name": "VaultAlreadySettled",
       "msg": "Once a vault has been settled, its status as either finalized or reverted cannot be changed"
     },
     {
-      "code": 6018,
+      "code": 6017,
       "name": "ProposerCannotPullLiquidityWhileMarketIsPending",
       "msg": "Proposer cannot remove intitial liquidity while the proposal is pending"
     },
     {
-      "code": 6019,
+      "code": 6018,
       "name": "NonConsecutiveProposalNumber",
       "msg": "Proposal numbers must be consecutive"
     },
     {
-      "code": 6020,
+      "code": 6019,
       "name": "AddLiquidityCalculationError",
       "msg": "Add liquidity calculation error"
     },
     {
-      "code": 6021,
+      "code": 6020,
       "name": "DecimalScaleError",
       "msg": "Error in decimal scale conversion"
+    },
+    {
+      "code": 6021,
+      "name": "IdenticalDaoMints",
+      "msg": "The governance mint and the quote mint must be different"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidMintDecimals",
+      "msg": "Mint decimals are out of range"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidTicker",
+      "msg": "Tickers must be 1 to 8 ASCII letters or digits"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidPassThresholdBps",
+      "msg": "Pass threshold BPS is out of range"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidProposalDurationSlots",
+      "msg": "Proposal duration slots is out of range"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidFinalizeWindowSlots",
+      "msg": "Finalize window slots is out of range"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidExecutionWindowSlots",
+      "msg": "Execution window slots is out of range"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidExecutionDelaySlots",
+      "msg": "Execution delay slots is out of range"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidAmmInitialQuoteLiquidityAmount",
+      "msg": "AMM initial quote liquidity amount is out of range"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidAmmSwapFeeBps",
+      "msg": "AMM swap fee BPS is out of range"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidAmmLtwapDecimals",
+      "msg": "AMM LTWAP decimals is out of range"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidFinalizerBountyUsdc",
+      "msg": "The finalizer bounty can't be more than the proposal fee"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidProposalFeeRefundBps",
+      "msg": "The proposal fee refund can't be more than 100%"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidProposalFeeCurve",
+      "msg": "The proposal fee curve can't charge less than the proposal fee"
+    },
+    {
+      "code": 6035,
+      "name": "ProposalFeeOverflow",
+      "msg": "The proposal fee overflowed"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidMaxActiveProposals",
+      "msg": "At least one proposal has to be able to trade at a time"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidQueuedProposal",
+      "msg": "The next queued proposal has to be promoted when a proposal is finalized"
+    },
+    {
+      "code": 6038,
+      "name": "ArithmeticOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6039,
+      "name": "ProposalNotPending",
+      "msg": "The proposal is not pending"
+    },
+    {
+      "code": 6040,
+      "name": "ProposalCannotBeCancelled",
+      "msg": "Only proposals that haven't been submitted can be cancelled"
+    },
+    {
+      "code": 6041,
+      "name": "MarketSideNotCreated",
+      "msg": "This outcome's market hasn't been created"
+    },
+    {
+      "code": 6042,
+      "name": "MarketSidesStillOpen",
+      "msg": "Every outcome's market needs to be closed before the proposal can be"
+    },
+    {
+      "code": 6043,
+      "name": "ConditionalTokensOutstanding",
+      "msg": "Conditional tokens are still held outside of the proposer's accounts"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidProposalInstructions",
+      "msg": "The proposal instructions account doesn't belong to this proposal"
+    },
+    {
+      "code": 6045,
+      "name": "ProposalNotFinalized",
+      "msg": "The proposal hasn't been finalized"
+    },
+    {
+      "code": 6046,
+      "name": "ConditionalTokensNotRedeemed",
+      "msg": "Every winning conditional token needs to be redeemed before the proposal can be closed"
+    },
+    {
+      "code": 6047,
+      "name": "ProposalNotPassed",
+      "msg": "The proposal hasn't passed, or has already been executed"
+    },
+    {
+      "code": 6048,
+      "name": "ProposalAwaitingExecution",
+      "msg": "The proposal passed but hasn't been executed yet"
+    },
+    {
+      "code": 6049,
+      "name": "InstructionExecutedOutOfOrder",
+      "msg": "Proposal instructions have to be executed in order, starting at the execution cursor"
+    },
+    {
+      "code": 6050,
+      "name": "InstructionIndexOutOfRange",
+      "msg": "The instruction range is past the end of the proposal's instructions"
+    },
+    {
+      "code": 6051,
+      "name": "ProposalExecutionDelayed",
+      "msg": "The proposal is still in its execution delay"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidGuardian",
+      "msg": "The DAO has no guardian, or this isn't it"
+    },
+    {
+      "code": 6053,
+      "name": "VetoWindowClosed",
+      "msg": "Proposals can only be vetoed during their execution delay"
+    },
+    {
+      "code": 6054,
+      "name": "InstructionNotAllowed",
+      "msg": "The DAO's instruction policy doesn't allow this instruction"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidInstructionPolicy",
+      "msg": "The instruction policy has too many rules or discriminators, or an empty or oversized discriminator"
+    },
+    {
+      "code": 6056,
+      "name": "InstructionAccountsMismatch",
+      "msg": "The remaining accounts have to be each instruction's accounts, in order and with matching privileges, followed by its program"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidLookupTables",
+      "msg": "The lookup tables don't match the ones recorded on the proposal instructions"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidLookupTableIndex",
+      "msg": "An instruction account references a lookup table or address that doesn't exist"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidOutcomeIndex",
+      "msg": "The outcome index is out of range for this proposal"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidOutcomeCount",
+      "msg": "Proposals need between 2 and 8 outcomes"
+    },
+    {
+      "code": 6061,
+      "name": "StatusQuoOutcomeInstructions",
+      "msg": "The status quo outcome can't have instructions"
+    },
+    {
+      "code": 6062,
+      "name": "OutcomeMarketsNotCreated",
+      "msg": "Every outcome's market has to be created before the proposal can be submitted"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidOutcomeAccounts",
+      "msg": "The remaining accounts have to be the accounts of every outcome, in outcome order"
+    },
+    {
+      "code": 6064,
+      "name": "ProposalInstructionsStillNeeded",
+      "msg": "The proposal instructions can't be closed while the proposal is trading or awaiting execution"
+    },
+    {
+      "code": 6065,
+      "name": "ProposalInstructionsStillOpen",
+      "msg": "Every outcome's instructions need to be closed before the proposal can be"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidScalarRange",
+      "msg": "Scalar proposals need a non-empty range and at least 2 value buckets"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidScalarTemplate",
+      "msg": "The scalar instruction template has no room for the value at its data offset"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidPrerequisites",
+      "msg": "Prerequisites have to be distinct earlier proposals, listed in increasing order, that haven't failed"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPrerequisiteAccounts",
+      "msg": "The remaining accounts have to be the proposal's prerequisites, in the order they're listed"
+    },
+    {
+      "code": 6070,
+      "name": "PrerequisitesNotExecuted",
+      "msg": "Every prerequisite has to be executed before this proposal can be"
+    },
+    {
+      "code": 6071,
+      "name": "ProposalHasDependents",
+      "msg": "The proposal can't be closed while proposals that depend on it are open"
+    },
+    {
+      "code": 6072,
+      "name": "InsufficientConditionalTokens",
+      "msg": "Can't redeem more conditional tokens than the user holds"
+    },
+    {
+      "code": 6073,
+      "name": "ConditionalVaultNotSettled",
+      "msg": "The conditional vault has to be settled first"
+    },
+    {
+      "code": 6074,
+      "name": "ConditionalVaultsStillOpen",
+      "msg": "Every conditional vault of the proposal needs to be closed before the proposal can be"
+    },
+    {
+      "code": 6075,
+      "name": "ProposalAlreadySubmitted",
+      "msg": "Conditional vaults can only be set up before the proposal is submitted"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidMarketVault",
+      "msg": "Only the governance and quote tokens are traded on the proposal's markets"
+    },
+    {
+      "code": 6077,
+      "name": "MarketsClosed",
+      "msg": "The proposal's markets have stopped trading"
     }
   ]
 };
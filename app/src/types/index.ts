@@ -13,7 +13,7 @@ export type ProposalWrapper = {
     publicKey: PublicKey
 }
 
-export type ProposalVault = IdlAccounts<AutocratIDLType>['proposalVault'];
+export type ConditionalVault = IdlAccounts<AutocratIDLType>['conditionalVault'];
 export type ProposalInstructions = IdlAccounts<AutocratIDLType>['proposalInstructions'];
 
 export type Dao = IdlAccounts<AutocratIDLType>['dao'];
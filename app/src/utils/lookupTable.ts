@@ -17,6 +17,7 @@ import { getDaoAddr, getDaoTreasuryAddr } from "./pda";
 
 export async function createLookupTable(
     client: AutocratClient,
+    daoCreator: PublicKey,
     print: boolean = false
 ): Promise<PublicKey> {
     const slot = await client.provider.connection.getSlot();
@@ -54,6 +55,8 @@ export async function createLookupTable(
         console.log("lookup table: ", lookupTableAddress.toBase58())
     }
 
+    const [dao] = getDaoAddr(AUTOCRAT_PROGRAM_ID, daoCreator, META_MINT)
+
     let addresses = new Set<PublicKey>([
         ComputeBudgetProgram.programId,
         SystemProgram.programId,
@@ -61,8 +64,8 @@ export async function createLookupTable(
         ASSOCIATED_TOKEN_PROGRAM_ID,
         SYSVAR_RENT_PUBKEY,
         AUTOCRAT_PROGRAM_ID,
-        getDaoAddr(AUTOCRAT_PROGRAM_ID)[0],
-        getDaoTreasuryAddr(AUTOCRAT_PROGRAM_ID)[0],
+        dao,
+        getDaoTreasuryAddr(AUTOCRAT_PROGRAM_ID, dao)[0],
         META_MINT,
         USDC_MINT
     ])
@@ -5,17 +5,19 @@ import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token
 
 export const getDaoAddr = (
     programId: PublicKey,
+    creator: PublicKey,
+    metaMint: PublicKey,
 ): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
-        [utils.bytes.utf8.encode("WWCACOTMICMIBMHAFTTWYGHMB")],
+        [utils.bytes.utf8.encode("WWCACOTMICMIBMHAFTTWYGHMB"), creator.toBuffer(), metaMint.toBuffer()],
         programId,
     );
 };
 
 export const getDaoTreasuryAddr = (
     programId: PublicKey,
+    dao: PublicKey,
 ): [PublicKey, number] => {
-    let [dao] = getDaoAddr(programId)
     return PublicKey.findProgramAddressSync(
         [utils.bytes.utf8.encode("dao_treasury"), dao.toBuffer()],
         programId,
@@ -24,10 +26,11 @@ export const getDaoTreasuryAddr = (
 
 export const getProposalAddr = (
     programId: PublicKey,
+    dao: PublicKey,
     proposalNumber: number,
 ): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
-        [utils.bytes.utf8.encode("proposal__"), numToBytes64LE(proposalNumber)],
+        [utils.bytes.utf8.encode("proposal__"), dao.toBuffer(), numToBytes64LE(proposalNumber)],
         programId,
     );
 };
@@ -52,6 +55,29 @@ export const getProposalVaultAddr = (
     );
 };
 
+export const getConditionalVaultAddr = (
+    programId: PublicKey,
+    proposal: PublicKey,
+    underlyingMint: PublicKey,
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [utils.bytes.utf8.encode("conditional_vault"), proposal.toBuffer(), underlyingMint.toBuffer()],
+        programId,
+    );
+};
+
+export const getConditionalMintAddr = (
+    programId: PublicKey,
+    proposal: PublicKey,
+    outcomeIndex: number,
+    underlyingMint: PublicKey,
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [utils.bytes.utf8.encode("conditional_mint"), proposal.toBuffer(), Buffer.from([outcomeIndex]), underlyingMint.toBuffer()],
+        programId,
+    );
+};
+
 export const getAmmAddr = (
     programId: PublicKey,
     baseMint: PublicKey,
@@ -6,6 +6,14 @@ pub enum ErrorCode {
     AddLiquidityCalculationError,
     #[msg("Error in decimal scale conversion")]
     DecimalScaleError,
+    #[msg("Only permissioned AMMs can be closed")]
+    AmmNotPermissioned,
+    #[msg("The AMM still holds liquidity")]
+    AmmNotEmpty,
+    #[msg("The AMM position still has ownership")]
+    AmmPositionNotEmpty,
+    #[msg("The auth PDA doesn't match the AMM's permissioned caller")]
+    InvalidAuthPda,
 }
 
 #[macro_export]
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 use num_traits::ToPrimitive;
 
 use crate::error::ErrorCode;
@@ -31,36 +32,42 @@ pub struct AddLiquidity<'info> {
         bump
     )]
     pub amm_position: Account<'info, AmmPosition>,
-    pub base_mint: Account<'info, Mint>,
-    pub quote_mint: Account<'info, Mint>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = user,
+        associated_token::token_program = base_token_program,
     )]
-    pub user_ata_base: Account<'info, TokenAccount>,
+    pub user_ata_base: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = quote_mint,
         associated_token::authority = user,
+        associated_token::token_program = quote_token_program,
     )]
-    pub user_ata_quote: Account<'info, TokenAccount>,
+    pub user_ata_quote: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
     )]
-    pub vault_ata_base: Account<'info, TokenAccount>,
+    pub vault_ata_base: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = quote_mint,
         associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
     )]
-    pub vault_ata_quote: Account<'info, TokenAccount>,
+    pub vault_ata_quote: InterfaceAccount<'info, TokenAccount>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     #[account(
         seeds = [AMM_AUTH_SEED_PREFIX],
@@ -81,14 +88,15 @@ pub fn handler(
         user,
         amm,
         amm_position,
-        base_mint: _,
-        quote_mint: _,
+        base_mint,
+        quote_mint,
         user_ata_base,
         user_ata_quote,
         vault_ata_base,
         vault_ata_quote,
         associated_token_program: _,
-        token_program,
+        base_token_program,
+        quote_token_program,
         system_program: _,
         auth_pda,
     } = ctx.accounts;
@@ -102,19 +110,15 @@ pub fn handler(
 
     amm.update_ltwap(None)?;
 
+    let is_initial_liquidity = amm.base_amount == 0 && amm.quote_amount == 0;
+
     let mut temp_base_amount: u128;
     let mut temp_quote_amount: u128;
 
-    // if there is no liquidity in the amm, then initialize with new ownership values
-    if amm.base_amount == 0 && amm.quote_amount == 0 {
+    // if there is no liquidity in the amm, then the deposit sets the price
+    if is_initial_liquidity {
         temp_base_amount = max_base_amount as u128;
         temp_quote_amount = max_quote_amount as u128;
-
-        // use the higher number for ownership, to reduce rounding errors
-        let max_base_or_quote_amount = std::cmp::max(temp_base_amount, temp_quote_amount);
-
-        amm_position.ownership = max_base_or_quote_amount.to_u64().unwrap();
-        amm.total_ownership = max_base_or_quote_amount.to_u64().unwrap();
     } else {
         temp_base_amount = max_base_amount as u128;
 
@@ -139,8 +143,48 @@ pub fn handler(
                 return err!(ErrorCode::AddLiquidityCalculationError);
             }
         }
+    }
+
+    // send user base tokens to vault
+    let vault_base_balance_before = vault_ata_base.amount;
+    token_transfer(
+        temp_base_amount as u64,
+        base_token_program,
+        user_ata_base,
+        base_mint,
+        vault_ata_base,
+        user,
+    )?;
+    let base_received = token_received(vault_ata_base, vault_base_balance_before)?;
+
+    // send user quote tokens to vault
+    let vault_quote_balance_before = vault_ata_quote.amount;
+    token_transfer(
+        temp_quote_amount as u64,
+        quote_token_program,
+        user_ata_quote,
+        quote_mint,
+        vault_ata_quote,
+        user,
+    )?;
+    let quote_received = token_received(vault_ata_quote, vault_quote_balance_before)?;
+
+    // the position is credited with what reached the vaults, which is less than
+    // what was sent if either mint charges a transfer fee
+    assert!(base_received >= min_base_amount);
+    assert!(quote_received >= min_quote_amount);
 
-        let additional_ownership_base = temp_base_amount
+    if is_initial_liquidity {
+        assert!(base_received > 0);
+        assert!(quote_received > 0);
+
+        // use the higher number for ownership, to reduce rounding errors
+        let max_base_or_quote_amount = std::cmp::max(base_received, quote_received);
+
+        amm_position.ownership = max_base_or_quote_amount;
+        amm.total_ownership = max_base_or_quote_amount;
+    } else {
+        let additional_ownership_base = (base_received as u128)
             .checked_mul(amm.total_ownership as u128)
             .unwrap()
             .checked_div(amm.base_amount as u128)
@@ -148,7 +192,7 @@ pub fn handler(
             .to_u64()
             .unwrap();
 
-        let additional_ownership_quote = temp_quote_amount
+        let additional_ownership_quote = (quote_received as u128)
             .checked_mul(amm.total_ownership as u128)
             .unwrap()
             .checked_div(amm.quote_amount as u128)
@@ -169,36 +213,8 @@ pub fn handler(
             .unwrap();
     }
 
-    assert!(temp_base_amount >= min_base_amount as u128);
-    assert!(temp_quote_amount >= min_quote_amount as u128);
-
-    amm.base_amount = amm
-        .base_amount
-        .checked_add(temp_base_amount.to_u64().unwrap())
-        .unwrap();
-
-    amm.quote_amount = amm
-        .quote_amount
-        .checked_add(temp_quote_amount.to_u64().unwrap())
-        .unwrap();
-
-    // send user base tokens to vault
-    token_transfer(
-        temp_base_amount as u64,
-        &token_program,
-        user_ata_base,
-        vault_ata_base,
-        user,
-    )?;
-
-    // send user quote tokens to vault
-    token_transfer(
-        temp_quote_amount as u64,
-        token_program,
-        user_ata_quote,
-        vault_ata_quote,
-        user,
-    )?;
+    amm.base_amount = amm.base_amount.checked_add(base_received).unwrap();
+    amm.quote_amount = amm.quote_amount.checked_add(quote_received).unwrap();
 
     Ok(())
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::generate_vault_seeds;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct CloseAmm<'info> {
+    pub user: Signer<'info>,
+    /// CHECK: receives the rent, chosen by the permissioned caller
+    #[account(mut)]
+    pub rent_destination: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = base_mint,
+        has_one = quote_mint,
+        close = rent_destination,
+    )]
+    pub amm: Account<'info, Amm>,
+    #[account(mut, mint::token_program = base_token_program)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, mint::token_program = quote_token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: InterfaceAccount<'info, TokenAccount>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    #[account(
+        seeds = [AMM_AUTH_SEED_PREFIX],
+        bump = amm.auth_pda_bump,
+        seeds::program = amm.auth_program
+    )]
+    pub auth_pda: Option<Signer<'info>>,
+}
+
+pub fn handler(ctx: Context<CloseAmm>) -> Result<()> {
+    let CloseAmm {
+        user: _,
+        rent_destination,
+        amm,
+        base_mint,
+        quote_mint,
+        vault_ata_base,
+        vault_ata_quote,
+        base_token_program,
+        quote_token_program,
+        auth_pda,
+    } = ctx.accounts;
+
+    // the AMM doesn't record who paid for it, so only a permissioned caller
+    // can decide where the rent goes
+    require!(amm.permissioned, ErrorCode::AmmNotPermissioned);
+    assert!(auth_pda.is_some());
+
+    require!(
+        amm.total_ownership == 0 && amm.base_amount == 0 && amm.quote_amount == 0,
+        ErrorCode::AmmNotEmpty
+    );
+
+    let base_mint_key = base_mint.key();
+    let quote_mint_key = quote_mint.key();
+    let swap_fee_bps_bytes = amm.swap_fee_bps.to_le_bytes();
+    let permissioned_caller = amm.auth_program;
+
+    let seeds = generate_vault_seeds!(
+        base_mint_key,
+        quote_mint_key,
+        swap_fee_bps_bytes,
+        permissioned_caller,
+        amm.bump
+    );
+
+    // with no ownership left, anything still in the vaults was donated to the
+    // AMM and belongs to no one, so burn it to allow the vaults to be closed
+    token_burn_signed(
+        vault_ata_base.amount,
+        base_token_program,
+        base_mint,
+        vault_ata_base,
+        amm,
+        seeds,
+    )?;
+
+    token_burn_signed(
+        vault_ata_quote.amount,
+        quote_token_program,
+        quote_mint,
+        vault_ata_quote,
+        amm,
+        seeds,
+    )?;
+
+    token_harvest_withheld_fees(base_token_program, base_mint, vault_ata_base)?;
+
+    token_harvest_withheld_fees(quote_token_program, quote_mint, vault_ata_quote)?;
+
+    token_close_account_signed(
+        base_token_program,
+        vault_ata_base,
+        rent_destination,
+        amm,
+        seeds,
+    )?;
+
+    token_close_account_signed(
+        quote_token_program,
+        vault_ata_quote,
+        rent_destination,
+        amm,
+        seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    /// CHECK: only receives the position's rent, and is checked against the position
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+    /// CHECK: may already have been closed, checked in the handler otherwise
+    pub amm: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = user,
+        has_one = amm,
+        close = user,
+        seeds = [
+            AMM_POSITION_SEED_PREFIX,
+            amm.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub amm_position: Account<'info, AmmPosition>,
+    pub auth_pda: Option<Signer<'info>>,
+}
+
+pub fn handler(ctx: Context<ClosePosition>) -> Result<()> {
+    let ClosePosition {
+        user: _,
+        amm,
+        amm_position,
+        auth_pda,
+    } = ctx.accounts;
+
+    // an AMM can only be closed once all of its ownership is gone, so the
+    // positions it leaves behind are empty and can be closed by anyone
+    if amm.owner == &crate::ID && !amm.data_is_empty() {
+        let amm = Amm::try_deserialize(&mut amm.data.borrow().as_ref())?;
+
+        if amm.permissioned {
+            let expected_auth_pda = Pubkey::create_program_address(
+                &[AMM_AUTH_SEED_PREFIX, &[amm.auth_pda_bump]],
+                &amm.auth_program,
+            )
+            .map_err(|_| ErrorCode::InvalidAuthPda)?;
+
+            require!(
+                auth_pda
+                    .as_ref()
+                    .is_some_and(|auth_pda| auth_pda.key() == expected_auth_pda),
+                ErrorCode::InvalidAuthPda
+            );
+        }
+    }
+
+    // an empty position holds nothing but rent, which always goes back to its owner
+    require!(amm_position.ownership == 0, ErrorCode::AmmPositionNotEmpty);
+
+    Ok(())
+}
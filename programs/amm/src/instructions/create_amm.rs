@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 
 use crate::error::ErrorCode;
 use crate::state::*;
@@ -27,26 +28,30 @@ pub struct CreateAmm<'info> {
         bump
     )]
     pub amm: Account<'info, Amm>,
-    pub base_mint: Account<'info, Mint>,
-    pub quote_mint: Account<'info, Mint>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
     #[account(
         init_if_needed,
         payer = user,
         associated_token::authority = amm,
-        associated_token::mint = base_mint
+        associated_token::mint = base_mint,
+        associated_token::token_program = base_token_program,
     )]
-    pub vault_ata_base: Account<'info, TokenAccount>,
+    pub vault_ata_base: InterfaceAccount<'info, TokenAccount>,
     #[account(
         init_if_needed,
         payer = user,
         associated_token::authority = amm,
-        associated_token::mint = quote_mint
+        associated_token::mint = quote_mint,
+        associated_token::token_program = quote_token_program,
     )]
-    pub vault_ata_quote: Account<'info, TokenAccount>,
+    pub vault_ata_quote: InterfaceAccount<'info, TokenAccount>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     #[account(
         seeds = [AMM_AUTH_SEED_PREFIX],
@@ -72,7 +77,8 @@ pub fn handler(ctx: Context<CreateAmm>, create_amm_params: CreateAmmParams) -> R
         vault_ata_base: _,
         vault_ata_quote: _,
         associated_token_program: _,
-        token_program: _,
+        base_token_program: _,
+        quote_token_program: _,
         system_program: _,
         auth_pda: _,
     } = ctx.accounts;
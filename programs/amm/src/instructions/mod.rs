@@ -1,13 +1,22 @@
+// every instruction module has its own `handler`, which is only ever called by its full path
+#![allow(ambiguous_glob_reexports)]
+
 pub use add_liquidity::*;
+pub use close_amm::*;
+pub use close_position::*;
 pub use create_amm::*;
 pub use create_position::*;
 pub use remove_liquidity::*;
+pub use reset_ltwap::*;
 pub use swap::*;
 pub use update_ltwap::*;
 
 pub mod add_liquidity;
+pub mod close_amm;
+pub mod close_position;
 pub mod create_amm;
 pub mod create_position;
 pub mod remove_liquidity;
+pub mod reset_ltwap;
 pub mod swap;
 pub mod update_ltwap;
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 use num_traits::ToPrimitive;
 
 use crate::error::ErrorCode;
@@ -32,36 +33,42 @@ pub struct RemoveLiquidity<'info> {
         bump
     )]
     pub amm_position: Account<'info, AmmPosition>,
-    pub base_mint: Account<'info, Mint>,
-    pub quote_mint: Account<'info, Mint>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = user,
+        associated_token::token_program = base_token_program,
     )]
-    pub user_ata_base: Account<'info, TokenAccount>,
+    pub user_ata_base: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = quote_mint,
         associated_token::authority = user,
+        associated_token::token_program = quote_token_program,
     )]
-    pub user_ata_quote: Account<'info, TokenAccount>,
+    pub user_ata_quote: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
     )]
-    pub vault_ata_base: Account<'info, TokenAccount>,
+    pub vault_ata_base: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = quote_mint,
         associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
     )]
-    pub vault_ata_quote: Account<'info, TokenAccount>,
+    pub vault_ata_quote: InterfaceAccount<'info, TokenAccount>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     #[account(
         seeds = [AMM_AUTH_SEED_PREFIX],
@@ -83,7 +90,8 @@ pub fn handler(ctx: Context<RemoveLiquidity>, withdraw_bps: u64) -> Result<()> {
         vault_ata_base,
         vault_ata_quote,
         associated_token_program: _,
-        token_program,
+        base_token_program,
+        quote_token_program,
         system_program: _,
         auth_pda,
     } = ctx.accounts;
@@ -155,8 +163,9 @@ pub fn handler(ctx: Context<RemoveLiquidity>, withdraw_bps: u64) -> Result<()> {
     // send vault base tokens to user
     token_transfer_signed(
         base_to_withdraw,
-        token_program,
+        base_token_program,
         vault_ata_base,
+        base_mint,
         user_ata_base,
         amm,
         seeds,
@@ -165,8 +174,9 @@ pub fn handler(ctx: Context<RemoveLiquidity>, withdraw_bps: u64) -> Result<()> {
     // send vault quote tokens to user
     token_transfer_signed(
         quote_to_withdraw,
-        token_program,
+        quote_token_program,
         vault_ata_quote,
+        quote_mint,
         user_ata_quote,
         amm,
         seeds,
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ResetLtwap<'info> {
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub amm: Account<'info, Amm>,
+    #[account(
+        seeds = [AMM_AUTH_SEED_PREFIX],
+        bump = amm.auth_pda_bump,
+        seeds::program = amm.auth_program
+    )]
+    pub auth_pda: Option<Signer<'info>>,
+}
+
+pub fn handler(ctx: Context<ResetLtwap>) -> Result<()> {
+    let ResetLtwap {
+        user: _,
+        amm,
+        auth_pda,
+    } = ctx.accounts;
+
+    // the LTWAP decides what the permissioned caller does, so only it can
+    // choose when to start measuring
+    require!(amm.permissioned, ErrorCode::AmmNotPermissioned);
+    assert!(auth_pda.is_some());
+
+    amm.reset_ltwap()?;
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 use num_traits::ToPrimitive;
 
 use crate::generate_vault_seeds;
@@ -19,36 +20,42 @@ pub struct Swap<'info> {
         has_one = quote_mint,
     )]
     pub amm: Account<'info, Amm>,
-    pub base_mint: Account<'info, Mint>,
-    pub quote_mint: Account<'info, Mint>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = user,
+        associated_token::token_program = base_token_program,
     )]
-    pub user_ata_base: Account<'info, TokenAccount>,
+    pub user_ata_base: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = quote_mint,
         associated_token::authority = user,
+        associated_token::token_program = quote_token_program,
     )]
-    pub user_ata_quote: Account<'info, TokenAccount>,
+    pub user_ata_quote: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
     )]
-    pub vault_ata_base: Account<'info, TokenAccount>,
+    pub vault_ata_base: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = quote_mint,
         associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
     )]
-    pub vault_ata_quote: Account<'info, TokenAccount>,
+    pub vault_ata_quote: InterfaceAccount<'info, TokenAccount>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     #[account(
         seeds = [AMM_AUTH_SEED_PREFIX],
@@ -74,7 +81,8 @@ pub fn handler(
         vault_ata_base,
         vault_ata_quote,
         associated_token_program: _,
-        token_program,
+        base_token_program,
+        quote_token_program,
         system_program: _,
         auth_pda,
     } = ctx.accounts;
@@ -93,11 +101,14 @@ pub fn handler(
 
     let k = base_amount_start.checked_mul(quote_amount_start).unwrap();
 
-    let input_amount_minus_fee = input_amount
-        .checked_mul(BPS_SCALE.checked_sub(amm.swap_fee_bps).unwrap())
-        .unwrap()
-        .checked_div(BPS_SCALE)
-        .unwrap() as u128;
+    let swap_fee_bps = amm.swap_fee_bps;
+    let amount_minus_fee = |amount: u64| {
+        amount
+            .checked_mul(BPS_SCALE.checked_sub(swap_fee_bps).unwrap())
+            .unwrap()
+            .checked_div(BPS_SCALE)
+            .unwrap() as u128
+    };
 
     let base_mint_key = base_mint.key();
     let quote_mint_key = quote_mint.key();
@@ -112,9 +123,23 @@ pub fn handler(
         amm.bump
     );
 
+    // the swap is priced on what reaches the vault, and `output_amount` is what
+    // reaches the user, so a transfer fee on either side is paid by the user
     let output_amount = if is_quote_to_base {
+        // send user quote tokens to vault
+        let vault_quote_balance_before = vault_ata_quote.amount;
+        token_transfer(
+            input_amount,
+            quote_token_program,
+            user_ata_quote,
+            quote_mint,
+            vault_ata_quote,
+            user,
+        )?;
+        let input_received = token_received(vault_ata_quote, vault_quote_balance_before)?;
+
         let temp_quote_amount = quote_amount_start
-            .checked_add(input_amount_minus_fee)
+            .checked_add(amount_minus_fee(input_received))
             .unwrap();
 
         // for rounding up, if we have, a = b / c, we use: a = (b + (c - 1)) / c
@@ -130,32 +155,37 @@ pub fn handler(
             .to_u64()
             .unwrap();
 
-        amm.quote_amount = amm.quote_amount.checked_add(input_amount).unwrap();
+        amm.quote_amount = amm.quote_amount.checked_add(input_received).unwrap();
         amm.base_amount = amm.base_amount.checked_sub(output_amount_base).unwrap();
 
-        // send user quote tokens to vault
-        token_transfer(
-            input_amount,
-            token_program,
-            user_ata_quote,
-            vault_ata_quote,
-            &user,
-        )?;
-
         // send vault base tokens to user
+        let user_base_balance_before = user_ata_base.amount;
         token_transfer_signed(
             output_amount_base,
-            token_program,
+            base_token_program,
             vault_ata_base,
+            base_mint,
             user_ata_base,
             amm,
             seeds,
         )?;
 
-        output_amount_base
+        token_received(user_ata_base, user_base_balance_before)?
     } else {
+        // send user base tokens to vault
+        let vault_base_balance_before = vault_ata_base.amount;
+        token_transfer(
+            input_amount,
+            base_token_program,
+            user_ata_base,
+            base_mint,
+            vault_ata_base,
+            user,
+        )?;
+        let input_received = token_received(vault_ata_base, vault_base_balance_before)?;
+
         let temp_base_amount = base_amount_start
-            .checked_add(input_amount_minus_fee)
+            .checked_add(amount_minus_fee(input_received))
             .unwrap();
 
         // for rounding up, if we have, a = b / c, we use: a = (b + (c - 1)) / c
@@ -171,29 +201,22 @@ pub fn handler(
             .to_u64()
             .unwrap();
 
-        amm.base_amount = amm.base_amount.checked_add(input_amount).unwrap();
+        amm.base_amount = amm.base_amount.checked_add(input_received).unwrap();
         amm.quote_amount = amm.quote_amount.checked_sub(output_amount_quote).unwrap();
 
-        // send user base tokens to vault
-        token_transfer(
-            input_amount,
-            token_program,
-            &user_ata_base,
-            &vault_ata_base,
-            &user,
-        )?;
-
         // send vault quote tokens to user
+        let user_quote_balance_before = user_ata_quote.amount;
         token_transfer_signed(
             output_amount_quote,
-            token_program,
+            quote_token_program,
             vault_ata_quote,
+            quote_mint,
             user_ata_quote,
             amm,
             seeds,
         )?;
 
-        output_amount_quote
+        token_received(user_ata_quote, user_quote_balance_before)?
     };
 
     let new_k = (amm.base_amount as u128)
@@ -70,4 +70,16 @@ pub mod amm {
     pub fn update_ltwap(ctx: Context<UpdateLtwap>, final_slot: Option<u64>) -> Result<()> {
         instructions::update_ltwap::handler(ctx, final_slot)
     }
+
+    pub fn reset_ltwap(ctx: Context<ResetLtwap>) -> Result<()> {
+        instructions::reset_ltwap::handler(ctx)
+    }
+
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        instructions::close_position::handler(ctx)
+    }
+
+    pub fn close_amm(ctx: Context<CloseAmm>) -> Result<()> {
+        instructions::close_amm::handler(ctx)
+    }
 }
@@ -58,6 +58,18 @@ impl Amm {
         .unwrap_or(u64::MAX))
     }
 
+    // discards everything measured so far, so that the LTWAP only covers
+    // trading from now on
+    pub fn reset_ltwap(&mut self) -> Result<()> {
+        self.ltwap_slot_updated = Clock::get()?.slot;
+        self.ltwap_denominator_agg = AnchorDecimal::ser(Decimal::ZERO);
+        self.ltwap_numerator_agg = AnchorDecimal::ser(Decimal::ZERO);
+        self.ltwap_latest = 0;
+        self.ltwap_frozen = false;
+
+        Ok(())
+    }
+
     pub fn update_ltwap(&mut self, final_slot: Option<u64>) -> Result<u64> {
         if self.ltwap_frozen {
             return Ok(self.ltwap_latest);
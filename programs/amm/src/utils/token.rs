@@ -1,7 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::BaseStateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_interface;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
 
-pub fn token_mint_signed<'info, P: ToAccountInfo<'info>, M: ToAccountInfo<'info>, T: ToAccountInfo<'info>, A: ToAccountInfo<'info>>(
+pub fn token_mint_signed<
+    'info,
+    P: ToAccountInfo<'info>,
+    M: ToAccountInfo<'info>,
+    T: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
     amount: u64,
     token_program: &P,
     mint: &M,
@@ -10,15 +24,15 @@ pub fn token_mint_signed<'info, P: ToAccountInfo<'info>, M: ToAccountInfo<'info>
     seeds: &[&[u8]],
 ) -> Result<()> {
     if amount > 0 {
-        token::mint_to(
+        token_interface::mint_to(
             CpiContext::new_with_signer(
                 token_program.to_account_info(),
-                token::MintTo {
+                token_interface::MintTo {
                     mint: mint.to_account_info(),
                     to: to.to_account_info(),
                     authority: authority.to_account_info(),
                 },
-                &[&seeds[..]],
+                &[seeds],
             ),
             amount,
         )?
@@ -27,7 +41,13 @@ pub fn token_mint_signed<'info, P: ToAccountInfo<'info>, M: ToAccountInfo<'info>
     Ok(())
 }
 
-pub fn token_burn<'info, P: ToAccountInfo<'info>, M: ToAccountInfo<'info>, F: ToAccountInfo<'info>, A: ToAccountInfo<'info>>(
+pub fn token_burn<
+    'info,
+    P: ToAccountInfo<'info>,
+    M: ToAccountInfo<'info>,
+    F: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
     amount: u64,
     token_program: &P,
     mint: &M,
@@ -35,10 +55,10 @@ pub fn token_burn<'info, P: ToAccountInfo<'info>, M: ToAccountInfo<'info>, F: To
     authority: &A,
 ) -> Result<()> {
     if amount > 0 {
-        token::burn(
+        token_interface::burn(
             CpiContext::new(
                 token_program.to_account_info(),
-                token::Burn {
+                token_interface::Burn {
                     mint: mint.to_account_info(),
                     from: from.to_account_info(),
                     authority: authority.to_account_info(),
@@ -51,48 +71,111 @@ pub fn token_burn<'info, P: ToAccountInfo<'info>, M: ToAccountInfo<'info>, F: To
     Ok(())
 }
 
-pub fn token_transfer<'info, P: ToAccountInfo<'info>, F: ToAccountInfo<'info>, T: ToAccountInfo<'info>, A: ToAccountInfo<'info>>(
+// with a transfer fee, `to` receives less than `amount`, see `token_received`
+pub fn token_transfer<
+    'info,
+    P: ToAccountInfo<'info>,
+    F: ToAccountInfo<'info>,
+    T: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
     amount: u64,
     token_program: &P,
     from: &F,
+    mint: &InterfaceAccount<'info, Mint>,
     to: &T,
     authority: &A,
 ) -> Result<()> {
     if amount > 0 {
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new(
                 token_program.to_account_info(),
-                token::Transfer {
+                token_interface::TransferChecked {
                     from: from.to_account_info(),
+                    mint: mint.to_account_info(),
                     to: to.to_account_info(),
                     authority: authority.to_account_info(),
                 },
             ),
             amount,
+            mint.decimals,
         )?
     }
 
     Ok(())
 }
 
-pub fn token_transfer_signed<'info, P: ToAccountInfo<'info>, F: ToAccountInfo<'info>, T: ToAccountInfo<'info>, A: ToAccountInfo<'info>>(
+pub fn token_transfer_signed<
+    'info,
+    P: ToAccountInfo<'info>,
+    F: ToAccountInfo<'info>,
+    T: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
     amount: u64,
     token_program: &P,
     from: &F,
+    mint: &InterfaceAccount<'info, Mint>,
     to: &T,
     authority: &A,
     seeds: &[&[u8]],
 ) -> Result<()> {
     if amount > 0 {
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 token_program.to_account_info(),
-                token::Transfer {
+                token_interface::TransferChecked {
                     from: from.to_account_info(),
+                    mint: mint.to_account_info(),
                     to: to.to_account_info(),
                     authority: authority.to_account_info(),
                 },
-                &[&seeds[..]],
+                &[seeds],
+            ),
+            amount,
+            mint.decimals,
+        )?
+    }
+
+    Ok(())
+}
+
+// what `account` has received since its balance was `balance_before`. Anything
+// credited against tokens that came in has to use this rather than the amount
+// sent, which includes the transfer fee of a token-2022 mint that charges one
+pub fn token_received<'info>(
+    account: &mut InterfaceAccount<'info, TokenAccount>,
+    balance_before: u64,
+) -> Result<u64> {
+    account.reload()?;
+
+    Ok(account.amount.checked_sub(balance_before).unwrap())
+}
+
+pub fn token_burn_signed<
+    'info,
+    P: ToAccountInfo<'info>,
+    M: ToAccountInfo<'info>,
+    F: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
+    amount: u64,
+    token_program: &P,
+    mint: &M,
+    from: &F,
+    authority: &A,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    if amount > 0 {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::Burn {
+                    mint: mint.to_account_info(),
+                    from: from.to_account_info(),
+                    authority: authority.to_account_info(),
+                },
+                &[seeds],
             ),
             amount,
         )?
@@ -100,3 +183,62 @@ pub fn token_transfer_signed<'info, P: ToAccountInfo<'info>, F: ToAccountInfo<'i
 
     Ok(())
 }
+
+// token-2022 won't close an account that still holds withheld transfer fees,
+// so they're moved to the mint first, where the mint's withdraw authority can
+// collect them. Does nothing for mints without a transfer fee.
+pub fn token_harvest_withheld_fees<'info, P: ToAccountInfo<'info>, C: ToAccountInfo<'info>>(
+    token_program: &P,
+    mint: &InterfaceAccount<'info, Mint>,
+    account: &C,
+) -> Result<()> {
+    let mint_info = mint.to_account_info();
+
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let has_transfer_fee = {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        mint_state.get_extension::<TransferFeeConfig>().is_ok()
+    };
+
+    if has_transfer_fee {
+        let account_info = account.to_account_info();
+        invoke(
+            &harvest_withheld_tokens_to_mint(
+                token_program.to_account_info().key,
+                mint_info.key,
+                &[account_info.key],
+            )?,
+            &[mint_info, account_info],
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn token_close_account_signed<
+    'info,
+    P: ToAccountInfo<'info>,
+    C: ToAccountInfo<'info>,
+    D: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
+    token_program: &P,
+    account: &C,
+    destination: &D,
+    authority: &A,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: account.to_account_info(),
+            destination: destination.to_account_info(),
+            authority: authority.to_account_info(),
+        },
+        &[seeds],
+    ))
+}
@@ -16,7 +16,7 @@ pub enum ErrorCode {
     TWAPMarketTooOld,
     #[msg("`TWAPMarket` has the wrong `expected_value`")]
     TWAPMarketInvalidExpectedValue,
-    #[msg("One of the vaults has an invalid `settlement_authority`")]
+    #[msg("The vault's `settlement_authority` is not this proposal")]
     InvalidSettlementAuthority,
     #[msg("Proposal is too young to be executed or rejected")]
     ProposalTooYoung,
@@ -28,8 +28,6 @@ pub enum ErrorCode {
     ProposalCannotPass,
     #[msg("This proposal has already been finalized")]
     ProposalAlreadyFinalized,
-    #[msg("A conditional vault has an invalid nonce. A nonce should encode pass = 0 / fail = 1 in its most significant bit, base = 0 / quote = 1 in its second most significant bit, and the proposal number in least significant 32 bits")]
-    InvalidVaultNonce,
     #[msg("Insufficient underlying token balance to mint this amount of conditional tokens")]
     InsufficientUnderlyingTokens,
     #[msg("This `vault_underlying_token_account` is not this vault's `underlying_token_account`")]
@@ -48,6 +46,122 @@ pub enum ErrorCode {
     AddLiquidityCalculationError,
     #[msg("Error in decimal scale conversion")]
     DecimalScaleError,
+    #[msg("The governance mint and the quote mint must be different")]
+    IdenticalDaoMints,
+    #[msg("Mint decimals are out of range")]
+    InvalidMintDecimals,
+    #[msg("Tickers must be 1 to 8 ASCII letters or digits")]
+    InvalidTicker,
+    #[msg("Pass threshold BPS is out of range")]
+    InvalidPassThresholdBps,
+    #[msg("Proposal duration slots is out of range")]
+    InvalidProposalDurationSlots,
+    #[msg("Finalize window slots is out of range")]
+    InvalidFinalizeWindowSlots,
+    #[msg("Execution window slots is out of range")]
+    InvalidExecutionWindowSlots,
+    #[msg("Execution delay slots is out of range")]
+    InvalidExecutionDelaySlots,
+    #[msg("AMM initial quote liquidity amount is out of range")]
+    InvalidAmmInitialQuoteLiquidityAmount,
+    #[msg("AMM swap fee BPS is out of range")]
+    InvalidAmmSwapFeeBps,
+    #[msg("AMM LTWAP decimals is out of range")]
+    InvalidAmmLtwapDecimals,
+    #[msg("The finalizer bounty can't be more than the proposal fee")]
+    InvalidFinalizerBountyUsdc,
+    #[msg("The proposal fee refund can't be more than 100%")]
+    InvalidProposalFeeRefundBps,
+    #[msg("The proposal fee curve can't charge less than the proposal fee")]
+    InvalidProposalFeeCurve,
+    #[msg("The proposal fee overflowed")]
+    ProposalFeeOverflow,
+    #[msg("At least one proposal has to be able to trade at a time")]
+    InvalidMaxActiveProposals,
+    #[msg("The next queued proposal has to be promoted when a proposal is finalized")]
+    InvalidQueuedProposal,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("The proposal is not pending")]
+    ProposalNotPending,
+    #[msg("Only proposals that haven't been submitted can be cancelled")]
+    ProposalCannotBeCancelled,
+    #[msg("This outcome's market hasn't been created")]
+    MarketSideNotCreated,
+    #[msg("Every outcome's market needs to be closed before the proposal can be")]
+    MarketSidesStillOpen,
+    #[msg("Conditional tokens are still held outside of the proposer's accounts")]
+    ConditionalTokensOutstanding,
+    #[msg("The proposal instructions account doesn't belong to this proposal")]
+    InvalidProposalInstructions,
+    #[msg("The proposal hasn't been finalized")]
+    ProposalNotFinalized,
+    #[msg(
+        "Every winning conditional token needs to be redeemed before the proposal can be closed"
+    )]
+    ConditionalTokensNotRedeemed,
+    #[msg("The proposal hasn't passed, or has already been executed")]
+    ProposalNotPassed,
+    #[msg("The proposal passed but hasn't been executed yet")]
+    ProposalAwaitingExecution,
+    #[msg("Proposal instructions have to be executed in order, starting at the execution cursor")]
+    InstructionExecutedOutOfOrder,
+    #[msg("The instruction range is past the end of the proposal's instructions")]
+    InstructionIndexOutOfRange,
+    #[msg("The proposal is still in its execution delay")]
+    ProposalExecutionDelayed,
+    #[msg("The DAO has no guardian, or this isn't it")]
+    InvalidGuardian,
+    #[msg("Proposals can only be vetoed during their execution delay")]
+    VetoWindowClosed,
+    #[msg("The DAO's instruction policy doesn't allow this instruction")]
+    InstructionNotAllowed,
+    #[msg("The instruction policy has too many rules or discriminators, or an empty or oversized discriminator")]
+    InvalidInstructionPolicy,
+    #[msg("The remaining accounts have to be each instruction's accounts, in order and with matching privileges, followed by its program")]
+    InstructionAccountsMismatch,
+    #[msg("The lookup tables don't match the ones recorded on the proposal instructions")]
+    InvalidLookupTables,
+    #[msg("An instruction account references a lookup table or address that doesn't exist")]
+    InvalidLookupTableIndex,
+    #[msg("The outcome index is out of range for this proposal")]
+    InvalidOutcomeIndex,
+    #[msg("Proposals need between 2 and 8 outcomes")]
+    InvalidOutcomeCount,
+    #[msg("The status quo outcome can't have instructions")]
+    StatusQuoOutcomeInstructions,
+    #[msg("Every outcome's market has to be created before the proposal can be submitted")]
+    OutcomeMarketsNotCreated,
+    #[msg("The remaining accounts have to be the accounts of every outcome, in outcome order")]
+    InvalidOutcomeAccounts,
+    #[msg("The proposal instructions can't be closed while the proposal is trading or awaiting execution")]
+    ProposalInstructionsStillNeeded,
+    #[msg("Every outcome's instructions need to be closed before the proposal can be")]
+    ProposalInstructionsStillOpen,
+    #[msg("Scalar proposals need a non-empty range and at least 2 value buckets")]
+    InvalidScalarRange,
+    #[msg("The scalar instruction template has no room for the value at its data offset")]
+    InvalidScalarTemplate,
+    #[msg("Prerequisites have to be distinct earlier proposals, listed in increasing order, that haven't failed")]
+    InvalidPrerequisites,
+    #[msg("The remaining accounts have to be the proposal's prerequisites, in the order they're listed")]
+    InvalidPrerequisiteAccounts,
+    #[msg("Every prerequisite has to be executed before this proposal can be")]
+    PrerequisitesNotExecuted,
+    #[msg("The proposal can't be closed while proposals that depend on it are open")]
+    ProposalHasDependents,
+    #[msg("Can't redeem more conditional tokens than the user holds")]
+    InsufficientConditionalTokens,
+    #[msg("The conditional vault has to be settled first")]
+    ConditionalVaultNotSettled,
+    #[msg("Every conditional vault of the proposal needs to be closed before the proposal can be")]
+    ConditionalVaultsStillOpen,
+    #[msg("Conditional vaults can only be set up before the proposal is submitted")]
+    ProposalAlreadySubmitted,
+    #[msg("Only the governance and quote tokens are traded on the proposal's markets")]
+    InvalidMarketVault,
+    #[msg("The proposal's markets have stopped trading")]
+    MarketsClosed,
 }
 
 #[macro_export]
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProposalExecutionQueued {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub winning_outcome_index: u8,
+    // the value the proposal will set, for scalar proposals
+    pub scalar_value: Option<u64>,
+    pub executable_slot: u64,
+}
+
+#[event]
+pub struct ProposalVetoed {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+}
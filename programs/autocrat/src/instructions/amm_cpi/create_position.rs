@@ -26,8 +26,7 @@ pub struct CreatePosition<'info> {
 
 pub fn handler(ctx: Context<CreatePosition>) -> Result<()> {
     require!(
-        ctx.accounts.proposal.pass_market_amm == ctx.accounts.amm.key()
-            || ctx.accounts.proposal.fail_market_amm == ctx.accounts.amm.key(),
+        ctx.accounts.proposal.has_amm(ctx.accounts.amm.key),
         ErrorCode::AmmProposalMismatch
     );
 
@@ -57,7 +56,7 @@ impl<'info> CreatePosition<'info> {
     fn into_create_amm_position_context<'a, 'b, 'c>(
         &'a self,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, AmmCreatePosition<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, AmmCreatePosition<'info>> {
         let cpi_accounts = AmmCreatePosition {
             user: self.user.to_account_info(),
             amm: self.amm.to_account_info(),
@@ -0,0 +1,322 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::program::Autocrat;
+use amm::cpi::accounts::Swap as AmmSwap;
+use amm::program::Amm;
+
+use crate::error::ErrorCode;
+use crate::instructions::conditional_vault::mint_conditional_tokens::*;
+use crate::state::*;
+
+// the user's conditional token accounts for every outcome of the traded-in
+// token are passed as remaining accounts, as for `mint_conditional_tokens`
+#[derive(Accounts)]
+pub struct MintAndSwap<'info> {
+    // the vault of the token being traded in, either META or USDC
+    pub mint_conditional_tokens: MintConditionalTokens<'info>,
+    #[account(mut)]
+    /// CHECK: checked against the proposal
+    pub amm: UncheckedAccount<'info>,
+    /// CHECK
+    pub amm_auth_pda: UncheckedAccount<'info>,
+    #[account(mint::token_program = meta_token_program)]
+    pub conditional_meta_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = usdc_token_program)]
+    pub conditional_usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_meta_mint,
+        associated_token::authority = mint_conditional_tokens.user,
+        associated_token::token_program = meta_token_program,
+    )]
+    pub conditional_meta_user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_usdc_mint,
+        associated_token::authority = mint_conditional_tokens.user,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub conditional_usdc_user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_meta_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = meta_token_program,
+    )]
+    pub conditional_meta_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_usdc_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub conditional_usdc_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = amm::ID)]
+    pub amm_program: Program<'info, Amm>,
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub meta_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// takes a view on one outcome in a single step: splits `amount` of META or USDC
+// into conditional tokens of every outcome, then sells the chosen outcome's
+// newly minted ones on its market. Trading in USDC buys the outcome's
+// conditional META, and trading in META sells it, leaving the user with the
+// other outcomes' conditional tokens of what they traded in.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintAndSwap<'info>>,
+    outcome_index: u8,
+    amount: u64,
+    output_amount_min: u64,
+) -> Result<()> {
+    let MintAndSwap {
+        mint_conditional_tokens,
+        amm,
+        amm_auth_pda: _,
+        conditional_meta_mint,
+        conditional_usdc_mint,
+        conditional_meta_user_ata: _,
+        conditional_usdc_user_ata: _,
+        conditional_meta_vault_ata: _,
+        conditional_usdc_vault_ata: _,
+        amm_program: _,
+        associated_token_program: _,
+        meta_token_program: _,
+        usdc_token_program: _,
+        system_program: _,
+    } = ctx.accounts;
+
+    let proposal = &mint_conditional_tokens.proposal;
+
+    let clock = Clock::get()?;
+    validate_trading(proposal, clock.slot)?;
+
+    let is_quote_to_base = validate_market(
+        proposal,
+        outcome_index,
+        &mint_conditional_tokens.vault.underlying_mint,
+        &amm.key(),
+        &conditional_meta_mint.key(),
+        &conditional_usdc_mint.key(),
+    )?;
+
+    assert!(amount > 0);
+    assert!(output_amount_min > 0);
+
+    let minted = mint_conditional_tokens.deposit_and_mint(amount, ctx.remaining_accounts)?;
+
+    // swap, the AMM checks `output_amount_min` against what the user receives
+    let (_auth_pda, auth_pda_bump) =
+        Pubkey::find_program_address(&[AMM_AUTH_SEED_PREFIX], &Autocrat::id());
+    let seeds = &[AMM_AUTH_SEED_PREFIX, &[auth_pda_bump]];
+    let signer = [&seeds[..]];
+
+    let swap_ctx = ctx.accounts.into_swap_context(&signer);
+    amm::cpi::swap(swap_ctx, is_quote_to_base, minted, output_amount_min)?;
+
+    Ok(())
+}
+
+// checks that the proposal's markets are still trading at `slot`
+fn validate_trading(proposal: &Proposal, slot: u64) -> Result<()> {
+    require!(
+        proposal.state == ProposalState::Pending,
+        ErrorCode::ProposalIsNoLongerPending
+    );
+    require!(
+        slot < proposal.slot_enqueued + proposal.slots_duration,
+        ErrorCode::MarketsClosed
+    );
+
+    Ok(())
+}
+
+// checks that the accounts are the outcome's market and conditional mints, and
+// returns whether trading in `underlying_mint` buys the outcome's conditional
+// META with conditional USDC
+fn validate_market(
+    proposal: &Proposal,
+    outcome_index: u8,
+    underlying_mint: &Pubkey,
+    amm: &Pubkey,
+    conditional_meta_mint: &Pubkey,
+    conditional_usdc_mint: &Pubkey,
+) -> Result<bool> {
+    require!(
+        underlying_mint == &proposal.meta_mint || underlying_mint == &proposal.usdc_mint,
+        ErrorCode::InvalidMarketVault
+    );
+
+    let outcome = proposal.outcome(outcome_index)?;
+
+    require_keys_eq!(*amm, outcome.amm, ErrorCode::AmmProposalMismatch);
+    require_keys_eq!(
+        *conditional_meta_mint,
+        outcome.conditional_meta_mint,
+        ErrorCode::InvalidConditionalTokenMint
+    );
+    require_keys_eq!(
+        *conditional_usdc_mint,
+        outcome.conditional_usdc_mint,
+        ErrorCode::InvalidConditionalTokenMint
+    );
+
+    Ok(underlying_mint == &proposal.usdc_mint)
+}
+
+impl<'info> MintAndSwap<'info> {
+    fn into_swap_context<'a, 'b, 'c>(
+        &'a self,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, AmmSwap<'info>> {
+        let cpi_accounts = AmmSwap {
+            user: self.mint_conditional_tokens.user.to_account_info(),
+            amm: self.amm.to_account_info(),
+            base_mint: self.conditional_meta_mint.to_account_info(),
+            quote_mint: self.conditional_usdc_mint.to_account_info(),
+            user_ata_base: self.conditional_meta_user_ata.to_account_info(),
+            user_ata_quote: self.conditional_usdc_user_ata.to_account_info(),
+            vault_ata_base: self.conditional_meta_vault_ata.to_account_info(),
+            vault_ata_quote: self.conditional_usdc_vault_ata.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            auth_pda: Some(self.amm_auth_pda.to_account_info()),
+        };
+        let cpi_program = self.amm_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    fn validate(proposal: &Proposal, outcome_index: u8, underlying_mint: &Pubkey) -> Result<bool> {
+        let outcome = &proposal.outcomes[outcome_index as usize];
+        validate_market(
+            proposal,
+            outcome_index,
+            underlying_mint,
+            &outcome.amm,
+            &outcome.conditional_meta_mint,
+            &outcome.conditional_usdc_mint,
+        )
+    }
+
+    #[test]
+    fn trading_in_usdc_buys_and_meta_sells() {
+        let proposal = test_proposal(Pubkey::new_unique(), 0, 3);
+
+        for outcome_index in 0..3 {
+            assert_eq!(
+                validate(&proposal, outcome_index, &proposal.usdc_mint),
+                Ok(true)
+            );
+            assert_eq!(
+                validate(&proposal, outcome_index, &proposal.meta_mint),
+                Ok(false)
+            );
+        }
+    }
+
+    #[test]
+    fn only_the_proposals_tokens_are_traded() {
+        let proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+
+        assert_eq!(
+            validate(&proposal, 1, &Pubkey::new_unique()),
+            err!(ErrorCode::InvalidMarketVault)
+        );
+        assert_eq!(
+            validate_market(
+                &proposal,
+                2,
+                &proposal.usdc_mint,
+                &proposal.outcomes[1].amm,
+                &proposal.outcomes[1].conditional_meta_mint,
+                &proposal.outcomes[1].conditional_usdc_mint,
+            ),
+            err!(ErrorCode::InvalidOutcomeIndex)
+        );
+    }
+
+    #[test]
+    fn market_accounts_must_be_the_outcomes() {
+        let proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+        let (status_quo, pass) = (&proposal.outcomes[0], &proposal.outcomes[1]);
+
+        // the other outcome's market
+        assert_eq!(
+            validate_market(
+                &proposal,
+                1,
+                &proposal.usdc_mint,
+                &status_quo.amm,
+                &pass.conditional_meta_mint,
+                &pass.conditional_usdc_mint,
+            ),
+            err!(ErrorCode::AmmProposalMismatch)
+        );
+        // the other outcome's conditional tokens
+        assert_eq!(
+            validate_market(
+                &proposal,
+                1,
+                &proposal.usdc_mint,
+                &pass.amm,
+                &status_quo.conditional_meta_mint,
+                &pass.conditional_usdc_mint,
+            ),
+            err!(ErrorCode::InvalidConditionalTokenMint)
+        );
+        // the conditional mints swapped
+        assert_eq!(
+            validate_market(
+                &proposal,
+                1,
+                &proposal.meta_mint,
+                &pass.amm,
+                &pass.conditional_usdc_mint,
+                &pass.conditional_meta_mint,
+            ),
+            err!(ErrorCode::InvalidConditionalTokenMint)
+        );
+    }
+
+    #[test]
+    fn only_trading_proposals_are_traded() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+        proposal.slot_enqueued = 100;
+        let closing_slot = proposal.slot_enqueued + proposal.slots_duration;
+
+        assert_eq!(validate_trading(&proposal, closing_slot - 1), Ok(()));
+        assert_eq!(
+            validate_trading(&proposal, closing_slot),
+            err!(ErrorCode::MarketsClosed)
+        );
+
+        // submitted, but waiting in the queue
+        proposal.state = ProposalState::Queued;
+        assert_eq!(
+            validate_trading(&proposal, closing_slot - 1),
+            err!(ErrorCode::ProposalIsNoLongerPending)
+        );
+
+        // not yet submitted
+        proposal.state = ProposalState::Initialize;
+        assert_eq!(
+            validate_trading(&proposal, closing_slot - 1),
+            err!(ErrorCode::ProposalIsNoLongerPending)
+        );
+    }
+}
@@ -1,9 +1,14 @@
+// every instruction module has its own `handler`, which is only ever called by its full path
+#![allow(ambiguous_glob_reexports)]
+
 pub use add_liquidity::*;
 pub use create_position::*;
+pub use mint_and_swap::*;
 pub use remove_liquidity::*;
 pub use swap::*;
 
 pub mod add_liquidity;
 pub mod create_position;
+pub mod mint_and_swap;
 pub mod remove_liquidity;
 pub mod swap;
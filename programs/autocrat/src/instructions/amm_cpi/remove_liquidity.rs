@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::Mint;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 
 use crate::program::Autocrat;
 use amm::cpi::accounts::RemoveLiquidity as AmmRemoveLiquidity;
@@ -24,14 +23,23 @@ pub struct RemoveLiquidity<'info> {
     )]
     pub proposal: Box<Account<'info, Proposal>>,
     #[account(
-        mut,
         seeds = [
-            PROPOSAL_VAULT_SEED_PREFIX,
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            meta_mint.key().as_ref(),
+        ],
+        bump = meta_vault.bump
+    )]
+    pub meta_vault: Box<Account<'info, ConditionalVault>>,
+    #[account(
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
             proposal.key().as_ref(),
+            usdc_mint.key().as_ref(),
         ],
-        bump
+        bump = usdc_vault.bump
     )]
-    pub proposal_vault: Box<Account<'info, ProposalVault>>,
+    pub usdc_vault: Box<Account<'info, ConditionalVault>>,
     #[account(mut)]
     /// CHECK:
     pub amm: UncheckedAccount<'info>,
@@ -40,55 +48,60 @@ pub struct RemoveLiquidity<'info> {
     pub amm_position: UncheckedAccount<'info>,
     /// CHECK
     pub amm_auth_pda: UncheckedAccount<'info>,
-    pub meta_mint: Box<Account<'info, Mint>>,
-    pub usdc_mint: Box<Account<'info, Mint>>,
+    pub meta_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
-        mint::authority = proposal_vault,
+        mint::authority = meta_vault,
         mint::decimals = meta_mint.decimals,
+        mint::token_program = meta_token_program,
     )]
-    pub conditional_meta_mint: Box<Account<'info, Mint>>,
+    pub conditional_meta_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
-        mint::authority = proposal_vault,
+        mint::authority = usdc_vault,
         mint::decimals = usdc_mint.decimals,
+        mint::token_program = usdc_token_program,
     )]
-    pub conditional_usdc_mint: Box<Account<'info, Mint>>,
+    pub conditional_usdc_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
         associated_token::mint = conditional_meta_mint,
         associated_token::authority = user,
+        associated_token::token_program = meta_token_program,
     )]
-    pub conditional_meta_user_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_meta_user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = conditional_usdc_mint,
         associated_token::authority = user,
+        associated_token::token_program = usdc_token_program,
     )]
-    pub conditional_usdc_user_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_usdc_user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = conditional_meta_mint,
         associated_token::authority = amm,
+        associated_token::token_program = meta_token_program,
     )]
-    pub conditional_meta_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_meta_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = conditional_usdc_mint,
         associated_token::authority = amm,
+        associated_token::token_program = usdc_token_program,
     )]
-    pub conditional_usdc_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_usdc_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(address = amm::ID)]
     pub amm_program: Program<'info, Amm>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub meta_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<RemoveLiquidity>, remove_bps: u64) -> Result<()> {
     require!(
-        ctx.accounts.proposal.pass_market_amm == ctx.accounts.amm.key()
-            || ctx.accounts.proposal.fail_market_amm == ctx.accounts.amm.key(),
+        ctx.accounts.proposal.has_amm(ctx.accounts.amm.key),
         ErrorCode::AmmProposalMismatch
     );
 
@@ -131,7 +144,7 @@ impl<'info> RemoveLiquidity<'info> {
     fn into_remove_liquidity_context<'a, 'b, 'c>(
         &'a self,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, AmmRemoveLiquidity<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, AmmRemoveLiquidity<'info>> {
         let cpi_accounts = AmmRemoveLiquidity {
             user: self.user.to_account_info(),
             amm: self.amm.to_account_info(),
@@ -143,7 +156,8 @@ impl<'info> RemoveLiquidity<'info> {
             vault_ata_base: self.conditional_meta_vault_ata.to_account_info(),
             vault_ata_quote: self.conditional_usdc_vault_ata.to_account_info(),
             associated_token_program: self.associated_token_program.to_account_info(),
-            token_program: self.token_program.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
             system_program: self.system_program.to_account_info(),
             auth_pda: Some(self.amm_auth_pda.to_account_info()),
         };
@@ -154,7 +168,7 @@ impl<'info> RemoveLiquidity<'info> {
     fn into_update_ltwap_context<'a, 'b, 'c>(
         &'a self,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, UpdateLtwap<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, UpdateLtwap<'info>> {
         let cpi_accounts = UpdateLtwap {
             user: self.user.to_account_info(),
             amm: self.amm.to_account_info(),
@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::Mint;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 
 use crate::program::Autocrat;
 use amm::cpi::accounts::Swap as AmmSwap;
@@ -23,61 +22,76 @@ pub struct Swap<'info> {
     )]
     pub proposal: Box<Account<'info, Proposal>>,
     #[account(
-        mut,
         seeds = [
-            PROPOSAL_VAULT_SEED_PREFIX,
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            meta_mint.key().as_ref(),
+        ],
+        bump = meta_vault.bump
+    )]
+    pub meta_vault: Box<Account<'info, ConditionalVault>>,
+    #[account(
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
             proposal.key().as_ref(),
+            usdc_mint.key().as_ref(),
         ],
-        bump
+        bump = usdc_vault.bump
     )]
-    pub proposal_vault: Box<Account<'info, ProposalVault>>,
+    pub usdc_vault: Box<Account<'info, ConditionalVault>>,
     #[account(mut)]
     /// CHECK:
     pub amm: UncheckedAccount<'info>,
     /// CHECK
     pub amm_auth_pda: UncheckedAccount<'info>,
-    pub meta_mint: Box<Account<'info, Mint>>,
-    pub usdc_mint: Box<Account<'info, Mint>>,
+    pub meta_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
-        mint::authority = proposal_vault,
+        mint::authority = meta_vault,
         mint::decimals = meta_mint.decimals,
+        mint::token_program = meta_token_program,
     )]
-    pub conditional_meta_mint: Box<Account<'info, Mint>>,
+    pub conditional_meta_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
-        mint::authority = proposal_vault,
+        mint::authority = usdc_vault,
         mint::decimals = usdc_mint.decimals,
+        mint::token_program = usdc_token_program,
     )]
-    pub conditional_usdc_mint: Box<Account<'info, Mint>>,
+    pub conditional_usdc_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
         associated_token::mint = conditional_meta_mint,
         associated_token::authority = user,
+        associated_token::token_program = meta_token_program,
     )]
-    pub conditional_meta_user_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_meta_user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = conditional_usdc_mint,
         associated_token::authority = user,
+        associated_token::token_program = usdc_token_program,
     )]
-    pub conditional_usdc_user_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_usdc_user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = conditional_meta_mint,
         associated_token::authority = amm,
+        associated_token::token_program = meta_token_program,
     )]
-    pub conditional_meta_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_meta_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = conditional_usdc_mint,
         associated_token::authority = amm,
+        associated_token::token_program = usdc_token_program,
     )]
-    pub conditional_usdc_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_usdc_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(address = amm::ID)]
     pub amm_program: Program<'info, Amm>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub meta_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -90,7 +104,8 @@ pub fn handler(
     let Swap {
         user: _,
         proposal,
-        proposal_vault: _,
+        meta_vault: _,
+        usdc_vault: _,
         amm,
         amm_auth_pda: _,
         meta_mint: _,
@@ -103,14 +118,12 @@ pub fn handler(
         conditional_usdc_vault_ata: _,
         amm_program: _,
         associated_token_program: _,
-        token_program: _,
+        meta_token_program: _,
+        usdc_token_program: _,
         system_program: _,
     } = ctx.accounts;
 
-    require!(
-        proposal.pass_market_amm == amm.key() || proposal.fail_market_amm == amm.key(),
-        ErrorCode::AmmProposalMismatch
-    );
+    require!(proposal.has_amm(amm.key), ErrorCode::AmmProposalMismatch);
 
     require!(
         proposal.state == ProposalState::Pending,
@@ -141,7 +154,7 @@ impl<'info> Swap<'info> {
     fn into_swap_context<'a, 'b, 'c>(
         &'a self,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, AmmSwap<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, AmmSwap<'info>> {
         let cpi_accounts = AmmSwap {
             user: self.user.to_account_info(),
             amm: self.amm.to_account_info(),
@@ -152,7 +165,8 @@ impl<'info> Swap<'info> {
             vault_ata_base: self.conditional_meta_vault_ata.to_account_info(),
             vault_ata_quote: self.conditional_usdc_vault_ata.to_account_info(),
             associated_token_program: self.associated_token_program.to_account_info(),
-            token_program: self.token_program.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
             system_program: self.system_program.to_account_info(),
             auth_pda: Some(self.amm_auth_pda.to_account_info()),
         };
@@ -13,6 +13,7 @@ pub struct AddProposalInstructions<'info> {
         has_one = proposer,
         seeds = [
             PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
             proposal.number.to_le_bytes().as_ref()
         ],
         bump
@@ -27,11 +28,17 @@ pub struct AddProposalInstructions<'info> {
         realloc::zero = false,
         seeds = [
             PROPOSAL_INSTRUCTIONS_SEED_PREFIX,
-            proposal.key().as_ref()
+            proposal.key().as_ref(),
+            &[proposal_instructions.outcome_index]
         ],
         bump
     )]
     pub proposal_instructions: Box<Account<'info, ProposalInstructions>>,
+    #[account(
+        seeds = [INSTRUCTION_POLICY_SEED_PREFIX, proposal.dao.as_ref()],
+        bump = instruction_policy.bump
+    )]
+    pub instruction_policy: Box<Account<'info, InstructionPolicy>>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
 }
@@ -44,18 +51,34 @@ pub fn handler(
         proposer: _,
         proposal,
         proposal_instructions,
+        instruction_policy,
         rent: _,
         system_program: _,
     } = ctx.accounts;
 
-    assert_eq!(proposal_instructions.key(), proposal.instructions);
+    assert_eq!(
+        proposal_instructions.key(),
+        proposal
+            .outcome(proposal_instructions.outcome_index)?
+            .instructions
+    );
 
-    assert!(!proposal_instructions.proposal_instructions_frozen);
     assert_eq!(proposal.state, ProposalState::Initialize);
 
-    proposal_instructions
-        .instructions
-        .extend(instructions.into_iter());
+    // the lookup tables are passed as remaining accounts, so that every lookup
+    // can be checked to resolve before the proposal goes to the market
+    proposal_instructions.validate_lookup_tables(ctx.remaining_accounts)?;
+
+    for ix in instructions.iter() {
+        instruction_policy.check_instruction(ix)?;
+        ix.to_instruction(ctx.remaining_accounts)?;
+    }
+
+    proposal_instructions.instructions.extend(instructions);
+
+    if let Some(scalar) = proposal.scalar {
+        scalar.validate_template(&proposal_instructions.instructions)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+// withdraws a proposal that was never submitted, once every outcome's market
+// has been unwound with `cancel_proposal_market_side` and its instructions
+// closed with `close_proposal_instructions`. Its conditional vaults can then be
+// settled as reverted and closed, which refunds the proposer's deposit, after
+// which the proposal itself can be closed with `close_proposal`.
+pub fn handler(ctx: Context<CancelProposal>) -> Result<()> {
+    let CancelProposal {
+        proposer: _,
+        proposal,
+    } = ctx.accounts;
+
+    require!(
+        proposal.state == ProposalState::Initialize || proposal.state == ProposalState::Cancelled,
+        ErrorCode::ProposalCannotBeCancelled
+    );
+
+    require!(
+        proposal
+            .outcomes
+            .iter()
+            .all(|outcome| !outcome.is_market_created),
+        ErrorCode::MarketSidesStillOpen
+    );
+
+    require!(
+        proposal
+            .outcomes
+            .iter()
+            .all(|outcome| outcome.instructions == Pubkey::default()),
+        ErrorCode::ProposalInstructionsStillOpen
+    );
+
+    proposal.state = ProposalState::Cancelled;
+
+    Ok(())
+}
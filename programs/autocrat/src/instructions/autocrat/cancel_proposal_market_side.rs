@@ -0,0 +1,440 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use amm::cpi::accounts::CloseAmm;
+use amm::cpi::accounts::ClosePosition;
+use amm::cpi::accounts::RemoveLiquidity;
+use amm::program::Amm;
+use amm::state::AmmPosition;
+
+use crate::error::ErrorCode;
+use crate::program::Autocrat;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct CancelProposalMarketSide<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            proposal.meta_mint.as_ref(),
+        ],
+        bump = meta_vault.bump
+    )]
+    pub meta_vault: Box<Account<'info, ConditionalVault>>,
+    #[account(
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            proposal.usdc_mint.as_ref(),
+        ],
+        bump = usdc_vault.bump
+    )]
+    pub usdc_vault: Box<Account<'info, ConditionalVault>>,
+    #[account(mut)]
+    /// CHECK: checked against the proposal, closed in the AMM program
+    pub amm: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub amm_position: Box<Account<'info, AmmPosition>>,
+    /// CHECK
+    pub amm_auth_pda: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        mint::authority = meta_vault,
+        mint::token_program = meta_token_program,
+    )]
+    pub conditional_meta_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        mint::authority = usdc_vault,
+        mint::token_program = usdc_token_program,
+    )]
+    pub conditional_usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_meta_mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = meta_token_program,
+    )]
+    pub conditional_meta_proposer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_usdc_mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub conditional_usdc_proposer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_meta_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = meta_token_program,
+    )]
+    pub conditional_meta_amm_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_usdc_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub conditional_usdc_amm_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = amm::ID)]
+    pub amm_program: Program<'info, Amm>,
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub meta_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// unwinds one outcome's market of a proposal that was never submitted: pulls the
+// proposer's liquidity, burns their conditional tokens and closes the AMM and
+// token accounts. The conditional mints themselves are created without a close
+// authority, so they're left behind with zero supply.
+pub fn handler(ctx: Context<CancelProposalMarketSide>, outcome_index: u8) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        proposal.state == ProposalState::Initialize || proposal.state == ProposalState::Cancelled,
+        ErrorCode::ProposalCannotBeCancelled
+    );
+
+    // once a side has been cancelled, the proposal can no longer be submitted
+    proposal.state = ProposalState::Cancelled;
+
+    let outcome = proposal.outcome_mut(outcome_index)?;
+
+    require!(outcome.is_market_created, ErrorCode::MarketSideNotCreated);
+
+    require_keys_eq!(
+        ctx.accounts.amm.key(),
+        outcome.amm,
+        ErrorCode::AmmProposalMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.conditional_meta_mint.key(),
+        outcome.conditional_meta_mint,
+        ErrorCode::InvalidConditionalTokenMint
+    );
+    require_keys_eq!(
+        ctx.accounts.conditional_usdc_mint.key(),
+        outcome.conditional_usdc_mint,
+        ErrorCode::InvalidConditionalTokenMint
+    );
+
+    // the outcome keeps its instructions, they're closed separately
+    outcome.is_market_created = false;
+    outcome.amm = Pubkey::default();
+    outcome.conditional_meta_mint = Pubkey::default();
+    outcome.conditional_usdc_mint = Pubkey::default();
+
+    let (_auth_pda, auth_pda_bump) =
+        Pubkey::find_program_address(&[AMM_AUTH_SEED_PREFIX], &Autocrat::id());
+    let amm_auth_seeds = &[AMM_AUTH_SEED_PREFIX, &[auth_pda_bump]];
+    let amm_auth_signer = [&amm_auth_seeds[..]];
+
+    // pull all of the proposer's liquidity, which is the only liquidity an
+    // unsubmitted market can have
+    if ctx.accounts.amm_position.ownership > 0 {
+        let remove_liquidity_ctx = ctx.accounts.into_remove_liquidity_context(&amm_auth_signer);
+        amm::cpi::remove_liquidity(remove_liquidity_ctx, BPS_SCALE)?;
+    }
+
+    let close_amm_position_ctx = ctx
+        .accounts
+        .into_close_amm_position_context(&amm_auth_signer);
+    amm::cpi::close_position(close_amm_position_ctx)?;
+
+    let close_amm_ctx = ctx.accounts.into_close_amm_context(&amm_auth_signer);
+    amm::cpi::close_amm(close_amm_ctx)?;
+
+    let CancelProposalMarketSide {
+        proposer,
+        conditional_meta_mint,
+        conditional_usdc_mint,
+        conditional_meta_proposer_ata,
+        conditional_usdc_proposer_ata,
+        meta_token_program,
+        usdc_token_program,
+        ..
+    } = ctx.accounts;
+
+    conditional_meta_proposer_ata.reload()?;
+    conditional_usdc_proposer_ata.reload()?;
+
+    token_burn(
+        conditional_meta_proposer_ata.amount,
+        meta_token_program,
+        conditional_meta_mint.as_ref(),
+        conditional_meta_proposer_ata.as_ref(),
+        proposer,
+    )?;
+
+    token_burn(
+        conditional_usdc_proposer_ata.amount,
+        usdc_token_program,
+        conditional_usdc_mint.as_ref(),
+        conditional_usdc_proposer_ata.as_ref(),
+        proposer,
+    )?;
+
+    // the underlying tokens can only be refunded once every conditional token
+    // backed by them has been burned
+    conditional_meta_mint.reload()?;
+    conditional_usdc_mint.reload()?;
+
+    require!(
+        conditional_meta_mint.supply == 0 && conditional_usdc_mint.supply == 0,
+        ErrorCode::ConditionalTokensOutstanding
+    );
+
+    token_close_account(
+        meta_token_program,
+        conditional_meta_proposer_ata.as_ref(),
+        proposer,
+        proposer,
+    )?;
+
+    token_close_account(
+        usdc_token_program,
+        conditional_usdc_proposer_ata.as_ref(),
+        proposer,
+        proposer,
+    )?;
+
+    Ok(())
+}
+
+impl<'info> CancelProposalMarketSide<'info> {
+    fn into_remove_liquidity_context<'a, 'b, 'c>(
+        &'a self,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, RemoveLiquidity<'info>> {
+        let cpi_accounts = RemoveLiquidity {
+            user: self.proposer.to_account_info(),
+            amm: self.amm.to_account_info(),
+            amm_position: self.amm_position.to_account_info(),
+            base_mint: self.conditional_meta_mint.to_account_info(),
+            quote_mint: self.conditional_usdc_mint.to_account_info(),
+            user_ata_base: self.conditional_meta_proposer_ata.to_account_info(),
+            user_ata_quote: self.conditional_usdc_proposer_ata.to_account_info(),
+            vault_ata_base: self.conditional_meta_amm_vault_ata.to_account_info(),
+            vault_ata_quote: self.conditional_usdc_amm_vault_ata.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            auth_pda: Some(self.amm_auth_pda.to_account_info()),
+        };
+        let cpi_program = self.amm_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+
+    fn into_close_amm_position_context<'a, 'b, 'c>(
+        &'a self,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, ClosePosition<'info>> {
+        let cpi_accounts = ClosePosition {
+            user: self.proposer.to_account_info(),
+            amm: self.amm.to_account_info(),
+            amm_position: self.amm_position.to_account_info(),
+            auth_pda: Some(self.amm_auth_pda.to_account_info()),
+        };
+        let cpi_program = self.amm_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+
+    fn into_close_amm_context<'a, 'b, 'c>(
+        &'a self,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, CloseAmm<'info>> {
+        let cpi_accounts = CloseAmm {
+            user: self.proposer.to_account_info(),
+            rent_destination: self.proposer.to_account_info(),
+            amm: self.amm.to_account_info(),
+            base_mint: self.conditional_meta_mint.to_account_info(),
+            quote_mint: self.conditional_usdc_mint.to_account_info(),
+            vault_ata_base: self.conditional_meta_amm_vault_ata.to_account_info(),
+            vault_ata_quote: self.conditional_usdc_amm_vault_ata.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
+            auth_pda: Some(self.amm_auth_pda.to_account_info()),
+        };
+        let cpi_program = self.amm_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_conditional_vault_seeds;
+    use crate::instructions::conditional_vault::mint_conditional_tokens;
+    use crate::instructions::conditional_vault::MintConditionalTokens;
+    use crate::utils::test_utils::*;
+    use anchor_lang::solana_program::system_program;
+
+    // mints the conditional tokens of `vault` for `outcome_index` as the vault
+    fn vault_mint_to(
+        proposal: &AccountInfo<'static>,
+        vault: &TestVault,
+        token_program: &AccountInfo<'static>,
+        outcome_index: usize,
+        to: &AccountInfo<'static>,
+        amount: u64,
+    ) -> Result<()> {
+        let vault_data: ConditionalVault = read(&vault.vault);
+
+        with_token_program(|| {
+            token_mint_signed(
+                amount,
+                token_program,
+                &vault.conditional_mints[outcome_index],
+                to,
+                &vault.vault,
+                generate_conditional_vault_seeds!(
+                    proposal.key,
+                    vault_data.underlying_mint,
+                    vault_data.bump
+                ),
+            )
+        })
+    }
+
+    #[test]
+    fn a_third_party_cant_block_cancelling_an_unsubmitted_proposal() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+        proposal.state = ProposalState::Initialize;
+        let proposer = proposal.proposer;
+        let accounts = test_proposal_accounts(proposal);
+        let TestProposalAccounts {
+            proposal,
+            meta_vault,
+            usdc_vault,
+            token_program,
+        } = &accounts;
+
+        // the proposer's initial conditional tokens, minted when the market was created
+        let proposer_atas: Vec<_> = [meta_vault, usdc_vault]
+            .into_iter()
+            .map(|vault| {
+                let ata = leak_info(
+                    TestAccount::token_account(vault.conditional_mints[1].key, &proposer, 0)
+                        .writable(),
+                );
+                vault_mint_to(proposal, vault, token_program, 1, &ata, 1_000).unwrap();
+                ata
+            })
+            .collect();
+
+        // someone else tries to mint before the proposal is submitted
+        let user = Pubkey::new_unique();
+        let mint_accounts = [
+            leak_info(
+                TestAccount::new(system_program::ID, Vec::new())
+                    .with_key(user)
+                    .signer()
+                    .writable(),
+            ),
+            proposal.clone(),
+            meta_vault.vault.clone(),
+            meta_vault.underlying_mint.clone(),
+            meta_vault.underlying_token_account.clone(),
+            leak_info(
+                TestAccount::token_account(meta_vault.underlying_mint.key, &user, 1_000).writable(),
+            ),
+            token_program.clone(),
+        ]
+        .into_iter()
+        .chain(meta_vault.conditional_mints.iter().flat_map(|mint| {
+            [
+                mint.clone(),
+                leak_info(TestAccount::token_account(mint.key, &user, 0).writable()),
+            ]
+        }))
+        .collect::<Vec<_>>();
+
+        let res = with_token_program(|| {
+            run_instruction::<MintConditionalTokens>(&mint_accounts, &[], |ctx| {
+                mint_conditional_tokens::handler(ctx, 1_000)
+            })
+        });
+        assert_eq!(res, err!(ErrorCode::ProposalNotPending));
+
+        let amm = read::<Proposal>(proposal).outcomes[1].amm;
+        let amm_position = AmmPosition {
+            user: proposer,
+            amm,
+            ownership: 0,
+        };
+        let cancel_accounts = [
+            leak_info(
+                TestAccount::new(system_program::ID, Vec::new())
+                    .with_key(proposer)
+                    .signer()
+                    .writable(),
+            ),
+            proposal.clone(),
+            meta_vault.vault.clone(),
+            usdc_vault.vault.clone(),
+            leak_info(
+                TestAccount::new(amm::ID, Vec::new())
+                    .with_key(amm)
+                    .writable(),
+            ),
+            leak_info(
+                TestAccount {
+                    owner: amm::ID,
+                    ..TestAccount::account(&amm_position)
+                }
+                .writable(),
+            ),
+            leak_info(TestAccount::new(system_program::ID, Vec::new())),
+            meta_vault.conditional_mints[1].clone(),
+            usdc_vault.conditional_mints[1].clone(),
+            proposer_atas[0].clone(),
+            proposer_atas[1].clone(),
+            leak_info(
+                TestAccount::token_account(meta_vault.conditional_mints[1].key, &amm, 0).writable(),
+            ),
+            leak_info(
+                TestAccount::token_account(usdc_vault.conditional_mints[1].key, &amm, 0).writable(),
+            ),
+            leak_info(TestAccount::program(amm::ID)),
+            leak_info(TestAccount::program(associated_token::ID)),
+            token_program.clone(),
+            token_program.clone(),
+            leak_info(TestAccount::program(system_program::ID)),
+        ];
+
+        with_token_program(|| {
+            run_instruction::<CancelProposalMarketSide>(&cancel_accounts, &[], |ctx| {
+                handler(ctx, 1)
+            })
+        })
+        .unwrap();
+
+        assert_eq!(read::<Proposal>(proposal).state, ProposalState::Cancelled);
+        for vault in [meta_vault, usdc_vault] {
+            assert_eq!(read::<Mint>(&vault.conditional_mints[1]).supply, 0);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// CHECK: paid for the proposal's accounts, so it receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+// permissionlessly closes a finalized or cancelled proposal once every outcome's
+// market and instructions and every conditional vault have been closed,
+// returning the rent to the proposer. The proposal's prerequisites are passed
+// as remaining accounts.
+pub fn handler(ctx: Context<CloseProposal>) -> Result<()> {
+    let CloseProposal {
+        user: _,
+        proposal,
+        proposer: _,
+    } = ctx.accounts;
+
+    require!(
+        proposal.state.is_finalized() || proposal.state == ProposalState::Cancelled,
+        ErrorCode::ProposalNotFinalized
+    );
+
+    // a proposal that can still be executed has to stay around
+    require!(
+        proposal.state != ProposalState::Passed,
+        ErrorCode::ProposalAwaitingExecution
+    );
+
+    require!(
+        proposal
+            .outcomes
+            .iter()
+            .all(|outcome| !outcome.is_market_created),
+        ErrorCode::MarketSidesStillOpen
+    );
+
+    require!(
+        proposal
+            .outcomes
+            .iter()
+            .all(|outcome| outcome.instructions == Pubkey::default()),
+        ErrorCode::ProposalInstructionsStillOpen
+    );
+
+    // the vaults are settled by the proposal, and every conditional token is
+    // redeemed through them, so they have to be closed first
+    require_eq!(
+        proposal.conditional_vault_count,
+        0,
+        ErrorCode::ConditionalVaultsStillOpen
+    );
+
+    require_eq!(
+        proposal.dependent_count,
+        0,
+        ErrorCode::ProposalHasDependents
+    );
+
+    // only submitted proposals were counted as dependents of their prerequisites
+    if proposal.state != ProposalState::Cancelled {
+        for (prerequisite_info, mut prerequisite) in
+            get_prerequisites(proposal, ctx.remaining_accounts)?
+        {
+            prerequisite.dependent_count = prerequisite.dependent_count.checked_sub(1).unwrap();
+            save_proposal(prerequisite_info, &prerequisite)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use amm::cpi::accounts::ClosePosition;
+use amm::program::Amm;
+use amm::state::AmmPosition;
+
+use crate::error::ErrorCode;
+use crate::program::Autocrat;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseProposalAmmPosition<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// CHECK: checked against the proposal
+    pub amm: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = amm_position.user == position_owner.key()
+    )]
+    pub amm_position: Box<Account<'info, AmmPosition>>,
+    /// CHECK: paid for the position, so it receives the rent
+    #[account(mut)]
+    pub position_owner: UncheckedAccount<'info>,
+    /// CHECK
+    pub amm_auth_pda: UncheckedAccount<'info>,
+    #[account(address = amm::ID)]
+    pub amm_program: Program<'info, Amm>,
+}
+
+// permissionlessly closes an empty LP position on one of a finalized
+// proposal's markets, returning the rent to the LP
+pub fn handler(ctx: Context<CloseProposalAmmPosition>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.state.is_finalized(),
+        ErrorCode::ProposalNotFinalized
+    );
+
+    require!(
+        proposal.has_amm(ctx.accounts.amm.key),
+        ErrorCode::AmmProposalMismatch
+    );
+
+    let (_auth_pda, auth_pda_bump) =
+        Pubkey::find_program_address(&[AMM_AUTH_SEED_PREFIX], &Autocrat::id());
+    let seeds = &[AMM_AUTH_SEED_PREFIX, &[auth_pda_bump]];
+    let signer = [&seeds[..]];
+
+    // the AMM program refuses to close a position that still has ownership
+    let close_position_ctx = ctx.accounts.into_close_position_context(&signer);
+    amm::cpi::close_position(close_position_ctx)?;
+
+    Ok(())
+}
+
+impl<'info> CloseProposalAmmPosition<'info> {
+    fn into_close_position_context<'a, 'b, 'c>(
+        &'a self,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, ClosePosition<'info>> {
+        let cpi_accounts = ClosePosition {
+            user: self.position_owner.to_account_info(),
+            amm: self.amm.to_account_info(),
+            amm_position: self.amm_position.to_account_info(),
+            auth_pda: Some(self.amm_auth_pda.to_account_info()),
+        };
+        let cpi_program = self.amm_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
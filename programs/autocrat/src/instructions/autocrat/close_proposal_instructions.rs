@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseProposalInstructions<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// CHECK: paid for the instructions, so it receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = proposal,
+        has_one = proposer,
+        close = proposer,
+        seeds = [
+            PROPOSAL_INSTRUCTIONS_SEED_PREFIX,
+            proposal.key().as_ref(),
+            &[proposal_instructions.outcome_index]
+        ],
+        bump
+    )]
+    pub proposal_instructions: Box<Account<'info, ProposalInstructions>>,
+}
+
+// closes one outcome's instructions, returning the rent to the proposer. Before
+// submission only the proposer can do this, afterwards anyone can once the
+// instructions can no longer be executed.
+pub fn handler(ctx: Context<CloseProposalInstructions>) -> Result<()> {
+    let CloseProposalInstructions {
+        user,
+        proposal,
+        proposer: _,
+        proposal_instructions,
+    } = ctx.accounts;
+
+    match proposal.state {
+        ProposalState::Initialize => require_keys_eq!(
+            user.key(),
+            proposal.proposer,
+            ErrorCode::ProposalInstructionsStillNeeded
+        ),
+        ProposalState::Cancelled
+        | ProposalState::Failed
+        | ProposalState::Executed
+        | ProposalState::ExecutionFailed
+        | ProposalState::Vetoed => {}
+        ProposalState::Queued | ProposalState::Pending | ProposalState::Passed => {
+            return err!(ErrorCode::ProposalInstructionsStillNeeded)
+        }
+    }
+
+    require_keys_eq!(
+        proposal
+            .outcome(proposal_instructions.outcome_index)?
+            .instructions,
+        proposal_instructions.key(),
+        ErrorCode::InvalidProposalInstructions
+    );
+
+    // the buckets of a scalar proposal share their instructions
+    for outcome in proposal.outcomes.iter_mut() {
+        if outcome.instructions == proposal_instructions.key() {
+            outcome.instructions = Pubkey::default();
+        }
+    }
+
+    Ok(())
+}
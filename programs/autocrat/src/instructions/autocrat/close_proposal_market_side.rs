@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use amm::cpi::accounts::CloseAmm;
+use amm::program::Amm;
+
+use crate::error::ErrorCode;
+use crate::program::Autocrat;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseProposalMarketSide<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// CHECK: paid for the market, so it receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: checked against the proposal, closed in the AMM program
+    pub amm: UncheckedAccount<'info>,
+    /// CHECK
+    pub amm_auth_pda: UncheckedAccount<'info>,
+    #[account(mut, mint::token_program = meta_token_program)]
+    pub conditional_meta_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, mint::token_program = usdc_token_program)]
+    pub conditional_usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_meta_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = meta_token_program,
+    )]
+    pub conditional_meta_amm_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = conditional_usdc_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub conditional_usdc_amm_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = amm::ID)]
+    pub amm_program: Program<'info, Amm>,
+    pub meta_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+}
+
+// permissionlessly closes one of a finalized proposal's markets once every LP
+// has withdrawn, returning the rent to the proposer who created it
+pub fn handler(ctx: Context<CloseProposalMarketSide>, outcome_index: u8) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        proposal.state.is_finalized(),
+        ErrorCode::ProposalNotFinalized
+    );
+
+    let outcome = proposal.outcome_mut(outcome_index)?;
+
+    require!(outcome.is_market_created, ErrorCode::MarketSideNotCreated);
+
+    require_keys_eq!(
+        ctx.accounts.amm.key(),
+        outcome.amm,
+        ErrorCode::AmmProposalMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.conditional_meta_mint.key(),
+        outcome.conditional_meta_mint,
+        ErrorCode::InvalidConditionalTokenMint
+    );
+    require_keys_eq!(
+        ctx.accounts.conditional_usdc_mint.key(),
+        outcome.conditional_usdc_mint,
+        ErrorCode::InvalidConditionalTokenMint
+    );
+
+    // the market's keys are kept around for reference, this only records that
+    // the market no longer exists
+    outcome.is_market_created = false;
+
+    let (_auth_pda, auth_pda_bump) =
+        Pubkey::find_program_address(&[AMM_AUTH_SEED_PREFIX], &Autocrat::id());
+    let seeds = &[AMM_AUTH_SEED_PREFIX, &[auth_pda_bump]];
+    let signer = [&seeds[..]];
+
+    // the AMM program refuses to close a market that still has liquidity
+    let close_amm_ctx = ctx.accounts.into_close_amm_context(&signer);
+    amm::cpi::close_amm(close_amm_ctx)?;
+
+    Ok(())
+}
+
+impl<'info> CloseProposalMarketSide<'info> {
+    fn into_close_amm_context<'a, 'b, 'c>(
+        &'a self,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, CloseAmm<'info>> {
+        let cpi_accounts = CloseAmm {
+            user: self.user.to_account_info(),
+            rent_destination: self.proposer.to_account_info(),
+            amm: self.amm.to_account_info(),
+            base_mint: self.conditional_meta_mint.to_account_info(),
+            quote_mint: self.conditional_usdc_mint.to_account_info(),
+            vault_ata_base: self.conditional_meta_amm_vault_ata.to_account_info(),
+            vault_ata_quote: self.conditional_usdc_amm_vault_ata.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
+            auth_pda: Some(self.amm_auth_pda.to_account_info()),
+        };
+        let cpi_program = self.amm_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
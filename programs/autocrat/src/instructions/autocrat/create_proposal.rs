@@ -1,11 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::Mint;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 
 use crate::error::ErrorCode;
 use crate::state::*;
@@ -19,8 +17,8 @@ pub struct CreateProposal<'info> {
         mut,
         has_one = meta_mint,
         has_one = usdc_mint,
-        seeds = [b"WWCACOTMICMIBMHAFTTWYGHMB"],
-        bump
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
     )]
     pub dao: Box<Account<'info, Dao>>,
     #[account(
@@ -29,54 +27,76 @@ pub struct CreateProposal<'info> {
         space = 8 + Proposal::INIT_SPACE,
         seeds = [
             PROPOSAL_SEED_PREFIX,
+            dao.key().as_ref(),
             dao.proposal_count.to_le_bytes().as_ref(),
         ],
         bump
     )]
     pub proposal: Box<Account<'info, Proposal>>,
+    // the conditional vaults that back the proposal's markets
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ConditionalVault::INIT_SPACE,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            meta_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub meta_vault: Box<Account<'info, ConditionalVault>>,
     #[account(
         init,
         payer = proposer,
-        space = 8 + std::mem::size_of::<ProposalVault>(),
+        space = 8 + ConditionalVault::INIT_SPACE,
         seeds = [
-            PROPOSAL_VAULT_SEED_PREFIX,
+            CONDITIONAL_VAULT_SEED_PREFIX,
             proposal.key().as_ref(),
+            usdc_mint.key().as_ref(),
         ],
         bump
     )]
-    pub proposal_vault: Box<Account<'info, ProposalVault>>,
-    pub meta_mint: Box<Account<'info, Mint>>,
-    pub usdc_mint: Box<Account<'info, Mint>>,
+    pub usdc_vault: Box<Account<'info, ConditionalVault>>,
+    #[account(mint::token_program = meta_token_program)]
+    pub meta_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = usdc_token_program)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
         associated_token::mint = meta_mint,
         associated_token::authority = proposer,
+        associated_token::token_program = meta_token_program,
     )]
-    pub meta_proposer_ata: Box<Account<'info, TokenAccount>>,
+    pub meta_proposer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = usdc_mint,
         associated_token::authority = proposer,
+        associated_token::token_program = usdc_token_program,
     )]
-    pub usdc_proposer_ata: Box<Account<'info, TokenAccount>>,
+    pub usdc_proposer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         init,
         payer = proposer,
         associated_token::mint = meta_mint,
-        associated_token::authority = proposal_vault,
+        associated_token::authority = meta_vault,
+        associated_token::token_program = meta_token_program,
     )]
-    pub meta_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub meta_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         init,
         payer = proposer,
         associated_token::mint = usdc_mint,
-        associated_token::authority = proposal_vault,
+        associated_token::authority = usdc_vault,
+        associated_token::token_program = usdc_token_program,
     )]
-    pub usdc_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub usdc_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    // the governance and quote tokens can each be under either token program
+    pub meta_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -85,63 +105,106 @@ pub fn handler(
     description_url: String,
     mint_cond_meta: u64,
     mint_cond_usdc: u64,
+    outcome_count: u8,
+    scalar: Option<ScalarRange>,
+    prerequisites: Vec<u64>,
 ) -> Result<()> {
     let CreateProposal {
         proposer,
         dao,
         proposal,
-        proposal_vault,
-        meta_mint: _,
-        usdc_mint: _,
+        meta_vault,
+        usdc_vault,
+        meta_mint,
+        usdc_mint,
         meta_proposer_ata,
         usdc_proposer_ata,
         meta_vault_ata,
         usdc_vault_ata,
         associated_token_program: _,
-        token_program,
+        meta_token_program,
+        usdc_token_program,
         system_program: _,
     } = ctx.accounts;
 
     assert!(description_url.len() <= 100);
 
-    assert!(mint_cond_meta > 0);
-    assert!(mint_cond_usdc >= dao.amm_initial_quote_liquidity_amount);
+    require!(
+        outcome_count >= MIN_PROPOSAL_OUTCOMES && outcome_count as usize <= MAX_PROPOSAL_OUTCOMES,
+        ErrorCode::InvalidOutcomeCount
+    );
+
+    if let Some(scalar) = scalar {
+        scalar.validate(outcome_count)?;
+    }
 
+    validate_prerequisites(&prerequisites, dao.proposal_count)?;
+
+    proposal.dao = dao.key();
     proposal.proposer = proposer.key();
     proposal.state = ProposalState::Initialize;
     proposal.description_url = description_url;
-    proposal.proposal_vault = proposal_vault.key();
+    proposal.conditional_vault_count = 2;
+    proposal.outcomes = vec![ProposalOutcome::default(); outcome_count as usize];
+    proposal.scalar = scalar;
+    proposal.prerequisites = prerequisites;
 
     proposal.meta_mint = dao.meta_mint;
     proposal.usdc_mint = dao.usdc_mint;
 
-    proposal.proposer_inititial_conditional_meta_minted = mint_cond_meta;
-    proposal.proposer_inititial_conditional_usdc_minted = mint_cond_usdc;
+    proposal.amm_swap_fee_bps = dao.config.amm_swap_fee_bps;
+    proposal.amm_ltwap_decimals = dao.config.amm_ltwap_decimals;
 
     proposal.number = dao.proposal_count;
     dao.proposal_count = dao.proposal_count.checked_add(1).unwrap();
 
-    proposal_vault.proposal = proposal.key();
-    proposal_vault.meta_vault_ata = meta_vault_ata.key();
-    proposal_vault.usdc_vault_ata = usdc_vault_ata.key();
+    meta_vault.initialize(
+        ctx.bumps.meta_vault,
+        proposal,
+        proposal.key(),
+        proposal.meta_mint,
+        meta_vault_ata.key(),
+    );
+
+    usdc_vault.initialize(
+        ctx.bumps.usdc_vault,
+        proposal,
+        proposal.key(),
+        proposal.usdc_mint,
+        usdc_vault_ata.key(),
+    );
 
     // transfer user meta to vault
     token_transfer(
-        proposal.proposer_inititial_conditional_meta_minted,
-        token_program,
+        mint_cond_meta,
+        meta_token_program,
         meta_proposer_ata.as_ref(),
+        meta_mint,
         meta_vault_ata.as_ref(),
         proposer,
     )?;
 
     // transfer user usdc to vault
     token_transfer(
-        proposal.proposer_inititial_conditional_usdc_minted,
-        token_program,
+        mint_cond_usdc,
+        usdc_token_program,
         usdc_proposer_ata.as_ref(),
+        usdc_mint,
         usdc_vault_ata.as_ref(),
         proposer,
     )?;
 
+    // the vaults were just created empty, and the proposer's conditional tokens
+    // are backed by what reached them, which is less than what was sent if
+    // either mint charges a transfer fee
+    proposal.proposer_inititial_conditional_meta_minted = token_received(meta_vault_ata, 0)?;
+    proposal.proposer_inititial_conditional_usdc_minted = token_received(usdc_vault_ata, 0)?;
+
+    assert!(proposal.proposer_inititial_conditional_meta_minted > 0);
+    assert!(
+        proposal.proposer_inititial_conditional_usdc_minted
+            >= dao.config.amm_initial_quote_liquidity_amount
+    );
+
     Ok(())
 }
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
 use crate::state::*;
 use crate::utils::get_instructions_size;
 
 #[derive(Accounts)]
-#[instruction(instructions: Vec<ProposalInstruction>)]
+#[instruction(outcome_index: u8, instructions: Vec<ProposalInstruction>, lookup_tables: Vec<Pubkey>)]
 pub struct CreateProposalInstructions<'info> {
     #[account(mut)]
     pub proposer: Signer<'info>,
@@ -13,6 +14,7 @@ pub struct CreateProposalInstructions<'info> {
         has_one = proposer,
         seeds = [
             PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
             proposal.number.to_le_bytes().as_ref()
         ],
         bump
@@ -21,38 +23,84 @@ pub struct CreateProposalInstructions<'info> {
     #[account(
         init,
         payer = proposer,
-        space = 8 + ProposalInstructions::SERIALIZED_LEN + get_instructions_size(&instructions),
+        space = 8 + ProposalInstructions::SERIALIZED_LEN + 32 * lookup_tables.len() + get_instructions_size(&instructions),
         seeds = [
             PROPOSAL_INSTRUCTIONS_SEED_PREFIX,
-            proposal.key().as_ref()
+            proposal.key().as_ref(),
+            &[outcome_index]
         ],
         bump
     )]
     pub proposal_instructions: Box<Account<'info, ProposalInstructions>>,
+    #[account(
+        seeds = [INSTRUCTION_POLICY_SEED_PREFIX, proposal.dao.as_ref()],
+        bump = instruction_policy.bump
+    )]
+    pub instruction_policy: Box<Account<'info, InstructionPolicy>>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
 }
 
+// each outcome other than the status quo can have its own instructions, which
+// are only executed if that outcome wins. The buckets of a scalar proposal all
+// share one instruction template, created under the first bucket.
 pub fn handler(
     ctx: Context<CreateProposalInstructions>,
+    outcome_index: u8,
     instructions: Vec<ProposalInstruction>,
+    lookup_tables: Vec<Pubkey>,
 ) -> Result<()> {
     let CreateProposalInstructions {
         proposer,
         proposal,
         proposal_instructions,
+        instruction_policy,
         rent: _,
         system_program: _,
     } = ctx.accounts;
 
-    assert!(!proposal_instructions.proposal_instructions_frozen);
     assert_eq!(proposal.state, ProposalState::Initialize);
 
+    require_neq!(
+        outcome_index,
+        STATUS_QUO_OUTCOME_INDEX,
+        ErrorCode::StatusQuoOutcomeInstructions
+    );
+
+    if let Some(scalar) = proposal.scalar {
+        require_eq!(
+            outcome_index,
+            STATUS_QUO_OUTCOME_INDEX + 1,
+            ErrorCode::InvalidOutcomeIndex
+        );
+
+        scalar.validate_template(&instructions)?;
+    }
+
+    proposal_instructions.lookup_tables = lookup_tables;
+
+    // the lookup tables are passed as remaining accounts, so that every lookup
+    // can be checked to resolve before the proposal goes to the market
+    proposal_instructions.validate_lookup_tables(ctx.remaining_accounts)?;
+
+    for ix in instructions.iter() {
+        instruction_policy.check_instruction(ix)?;
+        ix.to_instruction(ctx.remaining_accounts)?;
+    }
+
     proposal_instructions.proposer = proposer.key();
     proposal_instructions.proposal = proposal.key();
+    proposal_instructions.outcome_index = outcome_index;
+    proposal_instructions.execution_cursor = 0;
     proposal_instructions.instructions = instructions;
 
-    proposal.instructions = proposal_instructions.key();
+    if proposal.scalar.is_some() {
+        for outcome in proposal.outcomes.iter_mut().skip(outcome_index as usize) {
+            outcome.instructions = proposal_instructions.key();
+        }
+    } else {
+        proposal.outcome_mut(outcome_index)?.instructions = proposal_instructions.key();
+    }
 
     Ok(())
 }
@@ -1,10 +1,22 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::program::invoke_signed;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::create_metadata_accounts_v3;
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::CreateMetadataAccountsV3;
+use anchor_spl::metadata::Metadata;
 use anchor_spl::token;
-use anchor_spl::token::Mint;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_2022;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::metadata_pointer;
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_interface;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenInterface;
+use spl_token_metadata_interface::instruction as token_metadata_instruction;
+use spl_token_metadata_interface::state::TokenMetadata;
 
 use amm::cpi::accounts::AddLiquidity;
 use amm::cpi::accounts::CreateAmm;
@@ -12,21 +24,23 @@ use amm::cpi::accounts::CreatePosition;
 use amm::instructions::create_amm::CreateAmmParams;
 use amm::program::Amm;
 
-use crate::error::ErrorCode;
-use crate::generate_proposal_vault_seeds;
+use crate::generate_conditional_vault_seeds;
 use crate::program::Autocrat;
 use crate::state::*;
 use crate::utils::*;
 
 #[derive(Accounts)]
+#[instruction(outcome_index: u8)]
 pub struct CreateProposalMarketSide<'info> {
     #[account(mut)]
     pub proposer: Signer<'info>,
     #[account(
         mut,
         has_one = proposer,
+        has_one = dao,
         seeds = [
             PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
             proposal.number.to_le_bytes().as_ref(),
         ],
         bump
@@ -35,18 +49,29 @@ pub struct CreateProposalMarketSide<'info> {
     #[account(
         mut,
         seeds = [
-            PROPOSAL_VAULT_SEED_PREFIX,
+            CONDITIONAL_VAULT_SEED_PREFIX,
             proposal.key().as_ref(),
+            meta_mint.key().as_ref(),
         ],
-        bump
+        bump = meta_vault.bump
+    )]
+    pub meta_vault: Box<Account<'info, ConditionalVault>>,
+    #[account(
+        mut,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            usdc_mint.key().as_ref(),
+        ],
+        bump = usdc_vault.bump
     )]
-    pub proposal_vault: Box<Account<'info, ProposalVault>>,
+    pub usdc_vault: Box<Account<'info, ConditionalVault>>,
     #[account(
         mut,
         has_one = meta_mint,
         has_one = usdc_mint,
-        seeds = [b"WWCACOTMICMIBMHAFTTWYGHMB"],
-        bump
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
     )]
     pub dao: Box<Account<'info, Dao>>,
     #[account(mut)]
@@ -57,69 +82,97 @@ pub struct CreateProposalMarketSide<'info> {
     pub amm_position: UncheckedAccount<'info>,
     /// CHECK
     pub amm_auth_pda: UncheckedAccount<'info>,
-    pub meta_mint: Box<Account<'info, Mint>>,
-    pub usdc_mint: Box<Account<'info, Mint>>,
-    #[account(
-        init,
-        payer = proposer,
-        mint::authority = proposal_vault,
-        mint::decimals = meta_mint.decimals,
-    )]
-    pub conditional_meta_mint: Box<Account<'info, Mint>>,
-    #[account(
-        init,
-        payer = proposer,
-        mint::authority = proposal_vault,
-        mint::decimals = usdc_mint.decimals,
-    )]
-    pub conditional_usdc_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = meta_token_program)]
+    pub meta_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = usdc_token_program)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: created in the handler, see `create_conditional_mint`
     #[account(
-        init,
-        payer = proposer,
-        associated_token::mint = conditional_meta_mint,
-        associated_token::authority = proposer,
+        mut,
+        seeds = [
+            CONDITIONAL_MINT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            &[outcome_index],
+            meta_mint.key().as_ref(),
+        ],
+        bump
     )]
-    pub conditional_meta_proposer_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_meta_mint: UncheckedAccount<'info>,
+    /// CHECK: created in the handler, see `create_conditional_mint`
     #[account(
-        init,
-        payer = proposer,
-        associated_token::mint = conditional_usdc_mint,
-        associated_token::authority = proposer,
+        mut,
+        seeds = [
+            CONDITIONAL_MINT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            &[outcome_index],
+            usdc_mint.key().as_ref(),
+        ],
+        bump
     )]
-    pub conditional_usdc_proposer_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_usdc_mint: UncheckedAccount<'info>,
+    /// CHECK: initialized in the token metadata program, unless the conditional
+    /// mint is a token-2022 mint
     #[account(
-        init,
-        payer = proposer,
-        associated_token::mint = conditional_meta_mint,
-        associated_token::authority = amm,
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            conditional_meta_mint.key().as_ref(),
+        ],
+        seeds::program = token_metadata_program.key(),
+        bump
     )]
-    pub conditional_meta_amm_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_meta_metadata: UncheckedAccount<'info>,
+    /// CHECK: initialized in the token metadata program, unless the conditional
+    /// mint is a token-2022 mint
     #[account(
-        init,
-        payer = proposer,
-        associated_token::mint = conditional_usdc_mint,
-        associated_token::authority = amm,
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            conditional_usdc_mint.key().as_ref(),
+        ],
+        seeds::program = token_metadata_program.key(),
+        bump
     )]
-    pub conditional_usdc_amm_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub conditional_usdc_metadata: UncheckedAccount<'info>,
+    /// CHECK: created in the handler once the mint exists, checked in the
+    /// associated token program
+    #[account(mut)]
+    pub conditional_meta_proposer_ata: UncheckedAccount<'info>,
+    /// CHECK: created in the handler once the mint exists, checked in the
+    /// associated token program
+    #[account(mut)]
+    pub conditional_usdc_proposer_ata: UncheckedAccount<'info>,
+    /// CHECK: created in the AMM program
+    #[account(mut)]
+    pub conditional_meta_amm_vault_ata: UncheckedAccount<'info>,
+    /// CHECK: created in the AMM program
+    #[account(mut)]
+    pub conditional_usdc_amm_vault_ata: UncheckedAccount<'info>,
     #[account(address = amm::ID)]
     pub amm_program: Program<'info, Amm>,
+    pub token_metadata_program: Program<'info, Metadata>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    // each side's conditional mint is under its underlying mint's token program
+    pub meta_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(
     ctx: Context<CreateProposalMarketSide>,
-    is_pass_market: bool,
+    outcome_index: u8,
     amm_cond_meta_deposit: u64,
     amm_cond_usdc_deposit: u64,
 ) -> Result<()> {
     let CreateProposalMarketSide {
         proposer,
         proposal,
-        proposal_vault,
+        meta_vault,
+        usdc_vault,
         dao,
         amm,
         amm_position: _,
@@ -128,63 +181,136 @@ pub fn handler(
         usdc_mint: _,
         conditional_meta_mint,
         conditional_usdc_mint,
-        conditional_meta_proposer_ata,
-        conditional_usdc_proposer_ata,
+        conditional_meta_proposer_ata: _,
+        conditional_usdc_proposer_ata: _,
         conditional_meta_amm_vault_ata: _,
         conditional_usdc_amm_vault_ata: _,
+        conditional_meta_metadata: _,
+        conditional_usdc_metadata: _,
         amm_program: _,
+        token_metadata_program: _,
         associated_token_program: _,
-        token_program,
+        meta_token_program: _,
+        usdc_token_program: _,
         system_program: _,
+        rent: _,
     } = ctx.accounts;
 
     assert_eq!(proposal.proposer, proposer.key());
     assert_eq!(proposal.state, ProposalState::Initialize);
 
-    // set the corresponding pass/fail parameters
-    if is_pass_market {
-        assert!(!proposal.is_pass_market_created);
-        proposal.is_pass_market_created = true;
-        proposal.pass_market_amm = amm.key();
-        proposal.conditional_on_pass_meta_mint = conditional_meta_mint.key();
-        proposal.conditional_on_pass_usdc_mint = conditional_usdc_mint.key();
-    } else {
-        assert!(!proposal.is_fail_market_created);
-        proposal.is_fail_market_created = true;
-        proposal.fail_market_amm = amm.key();
-        proposal.conditional_on_fail_meta_mint = conditional_meta_mint.key();
-        proposal.conditional_on_fail_usdc_mint = conditional_usdc_mint.key();
-    }
+    // set the corresponding outcome's parameters
+    let outcome = proposal.outcome_mut(outcome_index)?;
+    assert!(!outcome.is_market_created);
+    outcome.is_market_created = true;
+    outcome.amm = amm.key();
+    outcome.conditional_meta_mint = conditional_meta_mint.key();
+    outcome.conditional_usdc_mint = conditional_usdc_mint.key();
+
+    meta_vault.set_conditional_mint(outcome_index, conditional_meta_mint.key())?;
+    usdc_vault.set_conditional_mint(outcome_index, conditional_usdc_mint.key())?;
 
     // sanity check
     assert!(proposal.proposer_inititial_conditional_meta_minted >= amm_cond_meta_deposit);
     assert!(proposal.proposer_inititial_conditional_usdc_minted >= amm_cond_usdc_deposit);
 
-    // mint the proposer's conditional tokens
+    // make sure the quote amount meets liquidity requirements
+    assert!(amm_cond_usdc_deposit >= dao.config.amm_initial_quote_liquidity_amount);
+    assert!(amm_cond_meta_deposit > 0);
+
+    // name the conditional tokens after the proposal and outcome, so that
+    // wallets can tell them apart
+    let conditional_meta_data = DataV2 {
+        name: proposal.conditional_token_name(outcome_index, dao.meta_ticker()),
+        symbol: proposal.conditional_token_symbol(outcome_index, dao.meta_ticker()),
+        uri: proposal.description_url.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+    let conditional_usdc_data = DataV2 {
+        name: proposal.conditional_token_name(outcome_index, dao.usdc_ticker()),
+        symbol: proposal.conditional_token_symbol(outcome_index, dao.usdc_ticker()),
+        uri: proposal.description_url.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+    let (meta_decimals, usdc_decimals) = (dao.meta_mint_decimals, dao.usdc_mint_decimals);
+    let (meta_underlying_mint, meta_vault_bump) = (meta_vault.underlying_mint, meta_vault.bump);
+    let (usdc_underlying_mint, usdc_vault_bump) = (usdc_vault.underlying_mint, usdc_vault.bump);
+    let proposer_conditional_meta_amount = proposal.proposer_inititial_conditional_meta_minted;
+    let proposer_conditional_usdc_amount = proposal.proposer_inititial_conditional_usdc_minted;
+
     let proposal_key = proposal.key();
-    let mint_seeds = generate_proposal_vault_seeds!(proposal_key, ctx.bumps.proposal_vault);
+    let conditional_meta_mint_seeds = &[
+        CONDITIONAL_MINT_SEED_PREFIX,
+        proposal_key.as_ref(),
+        &[outcome_index],
+        meta_underlying_mint.as_ref(),
+        &[ctx.bumps.conditional_meta_mint],
+    ];
+    let conditional_usdc_mint_seeds = &[
+        CONDITIONAL_MINT_SEED_PREFIX,
+        proposal_key.as_ref(),
+        &[outcome_index],
+        usdc_underlying_mint.as_ref(),
+        &[ctx.bumps.conditional_usdc_mint],
+    ];
 
-    token_mint_signed(
-        proposal.proposer_inititial_conditional_meta_minted,
-        token_program,
-        conditional_meta_mint.as_ref(),
-        conditional_meta_proposer_ata.as_ref(),
-        proposal_vault.as_ref(),
-        mint_seeds,
+    ctx.accounts.create_conditional_mint(
+        &ctx.accounts.conditional_meta_mint,
+        conditional_meta_mint_seeds,
+        &ctx.accounts.meta_vault,
+        meta_decimals,
+        &ctx.accounts.meta_token_program,
+        &conditional_meta_data,
     )?;
+    ctx.accounts.create_conditional_mint(
+        &ctx.accounts.conditional_usdc_mint,
+        conditional_usdc_mint_seeds,
+        &ctx.accounts.usdc_vault,
+        usdc_decimals,
+        &ctx.accounts.usdc_token_program,
+        &conditional_usdc_data,
+    )?;
+
+    // mint the proposer's conditional tokens
+    associated_token::create(ctx.accounts.into_create_ata_context(
+        &ctx.accounts.conditional_meta_proposer_ata,
+        &ctx.accounts.conditional_meta_mint,
+        &ctx.accounts.meta_token_program,
+    ))?;
+    associated_token::create(ctx.accounts.into_create_ata_context(
+        &ctx.accounts.conditional_usdc_proposer_ata,
+        &ctx.accounts.conditional_usdc_mint,
+        &ctx.accounts.usdc_token_program,
+    ))?;
+
+    let meta_vault_seeds =
+        generate_conditional_vault_seeds!(proposal_key, meta_underlying_mint, meta_vault_bump);
+    let usdc_vault_seeds =
+        generate_conditional_vault_seeds!(proposal_key, usdc_underlying_mint, usdc_vault_bump);
 
     token_mint_signed(
-        proposal.proposer_inititial_conditional_usdc_minted,
-        token_program,
-        conditional_usdc_mint.as_ref(),
-        conditional_usdc_proposer_ata.as_ref(),
-        proposal_vault.as_ref(),
-        mint_seeds,
+        proposer_conditional_meta_amount,
+        &ctx.accounts.meta_token_program,
+        &ctx.accounts.conditional_meta_mint,
+        &ctx.accounts.conditional_meta_proposer_ata,
+        ctx.accounts.meta_vault.as_ref(),
+        meta_vault_seeds,
     )?;
 
-    // make sure the quote amount meets liquidity requirements
-    assert!(amm_cond_usdc_deposit >= dao.amm_initial_quote_liquidity_amount);
-    assert!(amm_cond_meta_deposit > 0);
+    token_mint_signed(
+        proposer_conditional_usdc_amount,
+        &ctx.accounts.usdc_token_program,
+        &ctx.accounts.conditional_usdc_mint,
+        &ctx.accounts.conditional_usdc_proposer_ata,
+        ctx.accounts.usdc_vault.as_ref(),
+        usdc_vault_seeds,
+    )?;
 
     let (_auth_pda, auth_pda_bump) =
         Pubkey::find_program_address(&[AMM_AUTH_SEED_PREFIX], &Autocrat::id());
@@ -192,8 +318,8 @@ pub fn handler(
     let amm_auth_signer = [&amm_auth_seeds[..]];
 
     // create amm
-    let swap_fee_bps = dao.amm_swap_fee_bps;
-    let ltwap_decimals = dao.amm_ltwap_decimals;
+    let swap_fee_bps = ctx.accounts.proposal.amm_swap_fee_bps;
+    let ltwap_decimals = ctx.accounts.proposal.amm_ltwap_decimals;
 
     let create_amm_ctx = ctx.accounts.into_create_amm_context(&amm_auth_signer);
 
@@ -222,14 +348,188 @@ pub fn handler(
         amm_cond_usdc_deposit,
     )?;
 
+    // the vaults are the mints' update authority, and never update them.
+    // Token-2022 mints already hold their metadata.
+    if ctx.accounts.meta_token_program.key() == token::ID {
+        let meta_vault_signer = [&meta_vault_seeds[..]];
+        let create_meta_metadata_ctx = ctx.accounts.into_create_metadata_context(
+            &ctx.accounts.conditional_meta_metadata,
+            &ctx.accounts.conditional_meta_mint,
+            &ctx.accounts.meta_vault,
+            &meta_vault_signer,
+        );
+        create_metadata_accounts_v3(
+            create_meta_metadata_ctx,
+            conditional_meta_data,
+            false,
+            true,
+            None,
+        )?;
+    }
+
+    if ctx.accounts.usdc_token_program.key() == token::ID {
+        let usdc_vault_signer = [&usdc_vault_seeds[..]];
+        let create_usdc_metadata_ctx = ctx.accounts.into_create_metadata_context(
+            &ctx.accounts.conditional_usdc_metadata,
+            &ctx.accounts.conditional_usdc_mint,
+            &ctx.accounts.usdc_vault,
+            &usdc_vault_signer,
+        );
+        create_metadata_accounts_v3(
+            create_usdc_metadata_ctx,
+            conditional_usdc_data,
+            false,
+            true,
+            None,
+        )?;
+    }
+
     Ok(())
 }
 
+impl<'info> CreateProposalMarketSide<'info> {
+    // creates the outcome's conditional mint of one side under the side's token
+    // program, with the side's vault as its mint authority. Metaplex metadata is
+    // only for classic mints, so a token-2022 mint carries its own in the token
+    // metadata extension, which has to be pointed to before the mint is
+    // initialized.
+    fn create_conditional_mint(
+        &self,
+        conditional_mint: &UncheckedAccount<'info>,
+        conditional_mint_seeds: &[&[u8]],
+        vault: &Account<'info, ConditionalVault>,
+        decimals: u8,
+        token_program: &Interface<'info, TokenInterface>,
+        data: &DataV2,
+    ) -> Result<()> {
+        let is_token_2022 = token_program.key() == token_2022::ID;
+        let vault_seeds = generate_conditional_vault_seeds!(
+            vault.settlement_authority,
+            vault.underlying_mint,
+            vault.bump
+        );
+
+        let token_metadata = TokenMetadata {
+            update_authority: Some(vault.key()).try_into()?,
+            mint: conditional_mint.key(),
+            name: data.name.clone(),
+            symbol: data.symbol.clone(),
+            uri: data.uri.clone(),
+            additional_metadata: Vec::new(),
+        };
+
+        // the token program reallocates the mint to fit the metadata when it's
+        // initialized, so the rent for it is paid up front
+        let (space, rent_space) = if is_token_2022 {
+            let space =
+                ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+                    ExtensionType::MetadataPointer,
+                ])?;
+            (space, space + token_metadata.tlv_size_of()?)
+        } else {
+            (token::Mint::LEN, token::Mint::LEN)
+        };
+
+        create_pda_account(
+            &self.proposer,
+            conditional_mint,
+            space,
+            self.rent.minimum_balance(rent_space),
+            &token_program.key(),
+            conditional_mint_seeds,
+            &self.system_program,
+        )?;
+
+        if is_token_2022 {
+            invoke(
+                &metadata_pointer::instruction::initialize(
+                    &token_program.key(),
+                    &conditional_mint.key(),
+                    None,
+                    Some(conditional_mint.key()),
+                )?,
+                &[conditional_mint.to_account_info()],
+            )?;
+        }
+
+        token_interface::initialize_mint2(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token_interface::InitializeMint2 {
+                    mint: conditional_mint.to_account_info(),
+                },
+            ),
+            decimals,
+            &vault.key(),
+            None,
+        )?;
+
+        if is_token_2022 {
+            invoke_signed(
+                &token_metadata_instruction::initialize(
+                    &token_program.key(),
+                    &conditional_mint.key(),
+                    &vault.key(),
+                    &conditional_mint.key(),
+                    &vault.key(),
+                    token_metadata.name,
+                    token_metadata.symbol,
+                    token_metadata.uri,
+                ),
+                &[conditional_mint.to_account_info(), vault.to_account_info()],
+                &[vault_seeds],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn into_create_ata_context<'a>(
+        &'a self,
+        ata: &UncheckedAccount<'info>,
+        mint: &UncheckedAccount<'info>,
+        token_program: &Interface<'info, TokenInterface>,
+    ) -> CpiContext<'a, 'a, 'a, 'info, associated_token::Create<'info>> {
+        let cpi_accounts = associated_token::Create {
+            payer: self.proposer.to_account_info(),
+            associated_token: ata.to_account_info(),
+            authority: self.proposer.to_account_info(),
+            mint: mint.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        };
+        let cpi_program = self.associated_token_program.to_account_info();
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> CreateProposalMarketSide<'info> {
+    fn into_create_metadata_context<'a, 'b, 'c>(
+        &'a self,
+        metadata: &UncheckedAccount<'info>,
+        conditional_mint: &UncheckedAccount<'info>,
+        vault: &Account<'info, ConditionalVault>,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, CreateMetadataAccountsV3<'info>> {
+        let cpi_accounts = CreateMetadataAccountsV3 {
+            metadata: metadata.to_account_info(),
+            mint: conditional_mint.to_account_info(),
+            mint_authority: vault.to_account_info(),
+            payer: self.proposer.to_account_info(),
+            update_authority: vault.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        };
+        let cpi_program = self.token_metadata_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
+
 impl<'info> CreateProposalMarketSide<'info> {
     fn into_create_amm_context<'a, 'b, 'c>(
         &'a self,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, CreateAmm<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, CreateAmm<'info>> {
         let cpi_accounts = CreateAmm {
             user: self.proposer.to_account_info(),
             amm: self.amm.to_account_info(),
@@ -238,7 +538,8 @@ impl<'info> CreateProposalMarketSide<'info> {
             vault_ata_base: self.conditional_meta_amm_vault_ata.to_account_info(),
             vault_ata_quote: self.conditional_usdc_amm_vault_ata.to_account_info(),
             associated_token_program: self.associated_token_program.to_account_info(),
-            token_program: self.token_program.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
             system_program: self.system_program.to_account_info(),
             auth_pda: Some(self.amm_auth_pda.to_account_info()),
         };
@@ -251,7 +552,7 @@ impl<'info> CreateProposalMarketSide<'info> {
     fn into_create_amm_position_context<'a, 'b, 'c>(
         &'a self,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, CreatePosition<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, CreatePosition<'info>> {
         let cpi_accounts = CreatePosition {
             user: self.proposer.to_account_info(),
             amm: self.amm.to_account_info(),
@@ -268,7 +569,7 @@ impl<'info> CreateProposalMarketSide<'info> {
     fn into_add_liquidity_context<'a, 'b, 'c>(
         &'a self,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, 'a, 'info, AddLiquidity<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, AddLiquidity<'info>> {
         let cpi_accounts = AddLiquidity {
             user: self.proposer.to_account_info(),
             amm: self.amm.to_account_info(),
@@ -280,7 +581,8 @@ impl<'info> CreateProposalMarketSide<'info> {
             vault_ata_base: self.conditional_meta_amm_vault_ata.to_account_info(),
             vault_ata_quote: self.conditional_usdc_amm_vault_ata.to_account_info(),
             associated_token_program: self.associated_token_program.to_account_info(),
-            token_program: self.token_program.to_account_info(),
+            base_token_program: self.meta_token_program.to_account_info(),
+            quote_token_program: self.usdc_token_program.to_account_info(),
             system_program: self.system_program.to_account_info(),
             auth_pda: Some(self.amm_auth_pda.to_account_info()),
         };
@@ -289,3 +591,200 @@ impl<'info> CreateProposalMarketSide<'info> {
         CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+    use anchor_lang::solana_program::system_instruction;
+    use anchor_lang::solana_program::system_program;
+    use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+    #[test]
+    fn token_2022_conditional_mints_hold_their_own_metadata() {
+        let mut dao = test_dao();
+        let (dao_key, dao_bump) = Pubkey::find_program_address(
+            &[
+                DAO_SEED_PREFIX,
+                dao.creator.as_ref(),
+                dao.meta_mint.as_ref(),
+            ],
+            &crate::ID,
+        );
+        dao.bump = dao_bump;
+
+        let mut proposal = test_proposal(dao_key, 0, 2);
+        proposal.state = ProposalState::Initialize;
+        proposal.meta_mint = dao.meta_mint;
+        proposal.usdc_mint = dao.usdc_mint;
+        proposal.outcomes[1] = ProposalOutcome::default();
+        proposal.proposer_inititial_conditional_meta_minted = 1_000;
+        proposal.proposer_inititial_conditional_usdc_minted =
+            dao.config.amm_initial_quote_liquidity_amount;
+        let proposer = proposal.proposer;
+        let accounts = test_proposal_accounts(proposal.clone());
+        let TestProposalAccounts {
+            proposal: proposal_info,
+            meta_vault,
+            usdc_vault,
+            token_program,
+        } = &accounts;
+        let conditional_meta_mint = *meta_vault.conditional_mints[1].key;
+        let conditional_usdc_mint = *usdc_vault.conditional_mints[1].key;
+
+        let metadata = |mint: &Pubkey| {
+            let (metadata, _) = Pubkey::find_program_address(
+                &[b"metadata", Metadata::id().as_ref(), mint.as_ref()],
+                &Metadata::id(),
+            );
+            leak_info(TestAccount::empty().with_key(metadata).writable())
+        };
+        let ata = |owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey| {
+            leak_info(
+                TestAccount::empty()
+                    .with_key(get_associated_token_address_with_program_id(
+                        owner,
+                        mint,
+                        token_program,
+                    ))
+                    .writable(),
+            )
+        };
+        let amm = Pubkey::new_unique();
+
+        // META is a token-2022 mint, USDC a classic one
+        let create_accounts = [
+            leak_info(
+                TestAccount::new(system_program::ID, Vec::new())
+                    .with_key(proposer)
+                    .signer()
+                    .writable(),
+            ),
+            proposal_info.clone(),
+            meta_vault.vault.clone(),
+            usdc_vault.vault.clone(),
+            leak_info(TestAccount::account(&dao).with_key(dao_key).writable()),
+            leak_info(TestAccount::empty().with_key(amm).writable()),
+            leak_info(TestAccount::empty().writable()),
+            leak_info(TestAccount::empty()),
+            leak_info(TestAccount::mint_2022(u64::MAX, false).with_key(dao.meta_mint)),
+            usdc_vault.underlying_mint.clone(),
+            meta_vault.conditional_mints[1].clone(),
+            usdc_vault.conditional_mints[1].clone(),
+            metadata(&conditional_meta_mint),
+            metadata(&conditional_usdc_mint),
+            ata(&proposer, &conditional_meta_mint, &token_2022::ID),
+            ata(&proposer, &conditional_usdc_mint, &token::ID),
+            ata(&amm, &conditional_meta_mint, &token_2022::ID),
+            ata(&amm, &conditional_usdc_mint, &token::ID),
+            leak_info(TestAccount::program(amm::ID)),
+            leak_info(TestAccount::program(Metadata::id())),
+            leak_info(TestAccount::program(associated_token::ID)),
+            leak_info(TestAccount::program(token_2022::ID)),
+            token_program.clone(),
+            leak_info(TestAccount::program(system_program::ID)),
+            leak_info(TestAccount::rent()),
+        ];
+
+        let invoked = invoked_instructions(|| {
+            run_instruction::<CreateProposalMarketSide>(&create_accounts, &[1], |ctx| {
+                handler(ctx, 1, 1_000, dao.config.amm_initial_quote_liquidity_amount)
+            })
+        })
+        .unwrap();
+
+        let name = proposal.conditional_token_name(1, dao.meta_ticker());
+        let symbol = proposal.conditional_token_symbol(1, dao.meta_ticker());
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::MetadataPointer,
+        ])
+        .unwrap();
+        let metadata_space = TokenMetadata {
+            update_authority: Some(*meta_vault.vault.key).try_into().unwrap(),
+            mint: conditional_meta_mint,
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: proposal.description_url.clone(),
+            additional_metadata: Vec::new(),
+        }
+        .tlv_size_of()
+        .unwrap();
+
+        // the metadata is pointed to and initialized in the mint itself
+        assert_eq!(
+            invoked[..4],
+            [
+                system_instruction::create_account(
+                    &proposer,
+                    &conditional_meta_mint,
+                    Rent::default().minimum_balance(space + metadata_space),
+                    space as u64,
+                    &token_2022::ID,
+                ),
+                metadata_pointer::instruction::initialize(
+                    &token_2022::ID,
+                    &conditional_meta_mint,
+                    None,
+                    Some(conditional_meta_mint),
+                )
+                .unwrap(),
+                spl_token_2022::instruction::initialize_mint2(
+                    &token_2022::ID,
+                    &conditional_meta_mint,
+                    meta_vault.vault.key,
+                    None,
+                    dao.meta_mint_decimals,
+                )
+                .unwrap(),
+                token_metadata_instruction::initialize(
+                    &token_2022::ID,
+                    &conditional_meta_mint,
+                    meta_vault.vault.key,
+                    &conditional_meta_mint,
+                    meta_vault.vault.key,
+                    name,
+                    symbol,
+                    proposal.description_url.clone(),
+                ),
+            ]
+        );
+        assert_eq!(
+            invoked[4..6],
+            [
+                system_instruction::create_account(
+                    &proposer,
+                    &conditional_usdc_mint,
+                    Rent::default().minimum_balance(token::Mint::LEN),
+                    token::Mint::LEN as u64,
+                    &token::ID,
+                ),
+                token::spl_token::instruction::initialize_mint2(
+                    &token::ID,
+                    &conditional_usdc_mint,
+                    usdc_vault.vault.key,
+                    None,
+                    dao.usdc_mint_decimals,
+                )
+                .unwrap(),
+            ]
+        );
+
+        // only the classic mint gets metaplex metadata
+        let metaplex_mints: Vec<Pubkey> = invoked
+            .iter()
+            .filter(|ix| ix.program_id == Metadata::id())
+            .map(|ix| ix.accounts[1].pubkey)
+            .collect();
+        assert_eq!(metaplex_mints, [conditional_usdc_mint]);
+
+        let outcome = read::<Proposal>(proposal_info).outcomes[1];
+        assert!(outcome.is_market_created);
+        assert_eq!(outcome.amm, amm);
+        assert_eq!(outcome.conditional_meta_mint, conditional_meta_mint);
+        assert_eq!(outcome.conditional_usdc_mint, conditional_usdc_mint);
+        assert_eq!(
+            read::<ConditionalVault>(&meta_vault.vault).conditional_mints[1],
+            conditional_meta_mint
+        );
+    }
+}
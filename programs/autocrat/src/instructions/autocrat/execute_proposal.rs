@@ -0,0 +1,342 @@
+use std::ops::Range;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program;
+use anchor_lang::solana_program::instruction::Instruction;
+
+use crate::error::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = dao,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    // the winning outcome's instructions, only required if it has any
+    #[account(
+        mut,
+        has_one = proposal,
+        seeds = [
+            PROPOSAL_INSTRUCTIONS_SEED_PREFIX,
+            proposal.key().as_ref(),
+            &[proposal_instructions.outcome_index]
+        ],
+        bump
+    )]
+    pub proposal_instructions: Option<Box<Account<'info, ProposalInstructions>>>,
+    #[account(
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
+    )]
+    pub dao: Box<Account<'info, Dao>>,
+    /// CHECK: never read
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED_PREFIX, dao.key().as_ref()],
+        bump = dao.treasury_pda_bump
+    )]
+    pub dao_treasury: UncheckedAccount<'info>,
+    #[account(
+        seeds = [INSTRUCTION_POLICY_SEED_PREFIX, dao.key().as_ref()],
+        bump = instruction_policy.bump
+    )]
+    pub instruction_policy: Box<Account<'info, InstructionPolicy>>,
+}
+
+// runs the next `instruction_count` instructions of the winning outcome, starting
+// at `instruction_index`, which must be the execution cursor. Large proposals
+// can be executed over several transactions, and if an instruction fails the
+// transaction reverts and the proposal stays `Passed`, so execution can be
+// retried until the execution window closes. Nothing can be executed while the
+// proposal is still in its execution delay, or before its prerequisites have
+// been executed.
+pub fn handler(
+    ctx: Context<ExecuteProposal>,
+    instruction_index: u64,
+    instruction_count: u64,
+) -> Result<()> {
+    let ExecuteProposal {
+        user: _,
+        proposal,
+        proposal_instructions,
+        dao,
+        dao_treasury,
+        instruction_policy,
+    } = ctx.accounts;
+
+    require!(
+        proposal.state == ProposalState::Passed,
+        ErrorCode::ProposalNotPassed
+    );
+
+    let clock = Clock::get()?;
+
+    let executable_slot = proposal.slot_finalized + proposal.execution_delay_slots;
+
+    require!(
+        clock.slot >= executable_slot,
+        ErrorCode::ProposalExecutionDelayed
+    );
+
+    // the market's decision stands, but a proposal that can't be executed in time
+    // is given up on rather than left executable forever
+    if clock.slot >= executable_slot + proposal.execution_window_slots {
+        proposal.state = ProposalState::ExecutionFailed;
+        return Ok(());
+    }
+
+    // the proposal's prerequisites come first in the remaining accounts
+    require!(
+        ctx.remaining_accounts.len() >= proposal.prerequisites.len(),
+        ErrorCode::InvalidPrerequisiteAccounts
+    );
+
+    let (prerequisite_infos, remaining_accounts) = ctx
+        .remaining_accounts
+        .split_at(proposal.prerequisites.len());
+
+    let prerequisites = get_prerequisites(proposal, prerequisite_infos)?;
+
+    // if a prerequisite will never be executed, neither will this proposal
+    if !check_prerequisites_executed(&prerequisites)? {
+        proposal.state = ProposalState::ExecutionFailed;
+        return Ok(());
+    }
+
+    let winning_outcome = *proposal.outcome(proposal.winning_outcome_index)?;
+
+    // an outcome without instructions has nothing to run
+    if winning_outcome.instructions == Pubkey::default() {
+        proposal.state = ProposalState::Executed;
+
+        emit!(ProposalExecuted {
+            dao: dao.key(),
+            proposal: proposal.key(),
+        });
+
+        return Ok(());
+    }
+
+    let proposal_instructions = proposal_instructions
+        .as_mut()
+        .filter(|proposal_instructions| proposal_instructions.key() == winning_outcome.instructions)
+        .ok_or(ErrorCode::InvalidProposalInstructions)?;
+
+    let dao_pubkey = dao.key();
+    let treasury_seeds = &[
+        DAO_TREASURY_SEED_PREFIX,
+        dao_pubkey.as_ref(),
+        &[dao.treasury_pda_bump],
+    ];
+    let signer = &[&treasury_seeds[..]];
+
+    let Range { start, end } =
+        proposal_instructions.execution_range(instruction_index, instruction_count)?;
+
+    // everything is checked before anything is invoked, so that whoever cranks
+    // the execution can't steer it by supplying accounts of their choosing
+    // the proposal's lookup tables come next, followed by the accounts of each instruction
+    let lookup_table_count = proposal_instructions.lookup_tables.len();
+
+    require!(
+        remaining_accounts.len() >= lookup_table_count,
+        ErrorCode::InvalidLookupTables
+    );
+
+    let (lookup_tables, mut remaining_accounts) = remaining_accounts.split_at(lookup_table_count);
+
+    proposal_instructions.validate_lookup_tables(lookup_tables)?;
+
+    let mut svm_instructions = Vec::with_capacity(end - start);
+
+    for (index, ix) in proposal_instructions.instructions[start..end]
+        .iter()
+        .enumerate()
+    {
+        let mut ix = ix.clone();
+
+        // scalar proposals have the winning value written into their template
+        if let Some(scalar) = proposal.scalar {
+            if start + index == scalar.instruction_index as usize {
+                scalar.write_value(&mut ix.data, proposal.scalar_value()?.unwrap())?;
+            }
+        }
+
+        // the policy may have been tightened since the instruction was added
+        instruction_policy.check_instruction(&ix)?;
+
+        let mut svm_instruction = ix.to_instruction(lookup_tables)?;
+
+        for acc in svm_instruction.accounts.iter_mut() {
+            if &acc.pubkey == dao_treasury.key {
+                acc.is_signer = true;
+            }
+        }
+
+        // each instruction takes its own accounts, in order, followed by its program
+        let account_count = svm_instruction.accounts.len() + 1;
+
+        require!(
+            remaining_accounts.len() >= account_count,
+            ErrorCode::InstructionAccountsMismatch
+        );
+
+        let (account_infos, rest) = remaining_accounts.split_at(account_count);
+        remaining_accounts = rest;
+
+        validate_instruction_accounts(&svm_instruction, account_infos, dao_treasury.key)?;
+
+        svm_instructions.push((svm_instruction, account_infos));
+    }
+
+    require!(
+        remaining_accounts.is_empty(),
+        ErrorCode::InstructionAccountsMismatch
+    );
+
+    for (svm_instruction, account_infos) in svm_instructions.iter() {
+        solana_program::program::invoke_signed(svm_instruction, account_infos, signer)?;
+    }
+
+    proposal_instructions.execution_cursor = end as u64;
+
+    if end == proposal_instructions.instructions.len() {
+        proposal.state = ProposalState::Executed;
+
+        emit!(ProposalExecuted {
+            dao: dao.key(),
+            proposal: proposal.key(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_instruction_accounts(
+    svm_instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    dao_treasury: &Pubkey,
+) -> Result<()> {
+    let (program_info, meta_infos) = account_infos.split_last().unwrap();
+
+    require!(
+        program_info.key == &svm_instruction.program_id && program_info.executable,
+        ErrorCode::InstructionAccountsMismatch
+    );
+
+    for (meta, info) in svm_instruction.accounts.iter().zip(meta_infos.iter()) {
+        // privileges are merged across a transaction, so an account can be
+        // writable here even if this instruction only reads it
+        require!(
+            info.key == &meta.pubkey
+                && (!meta.is_writable || info.is_writable)
+                && (!meta.is_signer || info.is_signer || &meta.pubkey == dao_treasury),
+            ErrorCode::InstructionAccountsMismatch
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    // a signer, a writable account, the DAO treasury and the program
+    fn test_accounts(keys: &[Pubkey; 4]) -> Vec<TestAccount> {
+        let mut accounts: Vec<TestAccount> = keys
+            .iter()
+            .map(|key| TestAccount::new(Pubkey::new_unique(), Vec::new()).with_key(*key))
+            .collect();
+        accounts[0].is_signer = true;
+        accounts[1].is_writable = true;
+        accounts[2].is_signer = true;
+        accounts[2].is_writable = true;
+        accounts[3].executable = true;
+
+        accounts
+    }
+
+    fn test_instruction(accounts: &[TestAccount]) -> Instruction {
+        let (program, accounts) = accounts.split_last().unwrap();
+
+        Instruction {
+            program_id: program.key,
+            accounts: accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn instruction_accounts_in_order_are_accepted() {
+        let keys = [(); 4].map(|_| Pubkey::new_unique());
+        let dao_treasury = keys[2];
+        let mut accounts = test_accounts(&keys);
+        let svm_instruction = test_instruction(&accounts);
+
+        // the treasury signs through the program, and a readonly account can
+        // be passed as writable
+        accounts[2].is_signer = false;
+        accounts[0].is_writable = true;
+
+        assert_eq!(
+            validate_instruction_accounts(&svm_instruction, &infos(&mut accounts), &dao_treasury),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn instruction_accounts_must_match_the_instruction() {
+        let keys = [(); 4].map(|_| Pubkey::new_unique());
+        let dao_treasury = keys[2];
+        let svm_instruction = test_instruction(&test_accounts(&keys));
+
+        let tamperings: [fn(&mut Vec<TestAccount>); 6] = [
+            // a different account
+            |accounts| accounts[1].key = Pubkey::new_unique(),
+            // accounts out of order
+            |accounts| accounts.swap(0, 1),
+            // a writable account passed as readonly
+            |accounts| accounts[1].is_writable = false,
+            // a signer that didn't sign
+            |accounts| accounts[0].is_signer = false,
+            // a different program
+            |accounts| accounts[3].key = Pubkey::new_unique(),
+            // a program that isn't executable
+            |accounts| accounts[3].executable = false,
+        ];
+
+        for tamper in tamperings {
+            let mut accounts = test_accounts(&keys);
+            tamper(&mut accounts);
+
+            assert_eq!(
+                validate_instruction_accounts(
+                    &svm_instruction,
+                    &infos(&mut accounts),
+                    &dao_treasury
+                ),
+                err!(ErrorCode::InstructionAccountsMismatch)
+            );
+        }
+    }
+}
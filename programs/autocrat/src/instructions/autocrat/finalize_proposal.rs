@@ -1,11 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program;
-use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::associated_token;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 
+use amm::cpi::accounts::ResetLtwap;
+use amm::program::Amm as AmmProgram;
 use amm::state::Amm;
 
 use crate::error::ErrorCode;
+use crate::events::*;
+use crate::generate_proposal_seeds;
+use crate::program::Autocrat;
 use crate::state::*;
+use crate::utils::*;
 
 #[derive(Accounts)]
 pub struct FinalizeProposal<'info> {
@@ -13,57 +22,104 @@ pub struct FinalizeProposal<'info> {
     pub user: Signer<'info>,
     #[account(
         mut,
-        has_one = pass_market_amm,
-        has_one = fail_market_amm,
+        has_one = dao,
+        has_one = proposer,
         seeds = [
             PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
             proposal.number.to_le_bytes().as_ref()
         ],
         bump
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// CHECK: paid for the fee escrow, so it receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
     #[account(
         mut,
-        has_one = proposal,
-        constraint = proposal_instructions.key() == proposal.instructions
+        seeds = [
+            PROPOSAL_FEE_ESCROW_SEED_PREFIX,
+            proposal.key().as_ref(),
+        ],
+        bump
     )]
-    pub proposal_instructions: Account<'info, ProposalInstructions>,
+    pub usdc_fee_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
-        seeds = [b"WWCACOTMICMIBMHAFTTWYGHMB"],
-        bump
+        has_one = usdc_mint,
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
     )]
     pub dao: Box<Account<'info, Dao>>,
-    /// CHECK: never read
     #[account(
-        mut,
         seeds = [DAO_TREASURY_SEED_PREFIX, dao.key().as_ref()],
-        bump = dao.treasury_pda_bump
+        bump = dao.treasury_pda_bump,
     )]
-    pub dao_treasury: UncheckedAccount<'info>,
-    #[account(mut)]
-    pub pass_market_amm: Account<'info, Amm>,
-    #[account(mut)]
-    pub fail_market_amm: Account<'info, Amm>,
+    pub dao_treasury: Box<Account<'info, DaoTreasury>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = dao_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub usdc_treasury_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program,
+    )]
+    pub usdc_proposer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub usdc_user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK
+    pub amm_auth_pda: UncheckedAccount<'info>,
+    #[account(address = amm::ID)]
+    pub amm_program: Program<'info, AmmProgram>,
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<FinalizeProposal>) -> Result<()> {
+// the AMM of every outcome is passed as a remaining account, in outcome order,
+// followed by the next queued proposal and its AMMs if one can be promoted into
+// the slot this proposal frees up. Settles the escrowed proposal fee: whoever finalizes is paid the bounty out of
+// it, and the proposer gets the DAO's refund share of the rest if the proposal
+// passed, with everything else going to the treasury.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeProposal<'info>>) -> Result<()> {
     let FinalizeProposal {
         user: _,
         proposal,
-        proposal_instructions,
+        proposer,
+        usdc_fee_escrow,
         dao,
-        dao_treasury,
-        pass_market_amm,
-        fail_market_amm,
+        dao_treasury: _,
+        usdc_mint,
+        usdc_treasury_vault_ata,
+        usdc_proposer_ata,
+        usdc_user_ata,
+        amm_auth_pda: _,
+        amm_program: _,
+        associated_token_program: _,
+        token_program,
         system_program: _,
     } = ctx.accounts;
 
     let clock = Clock::get()?;
 
     require!(
-        clock.slot >= proposal.slot_enqueued + dao.proposal_duration_slots,
+        clock.slot >= proposal.slot_enqueued + proposal.slots_duration,
         ErrorCode::ProposalTooYoung
     );
 
@@ -74,46 +130,143 @@ pub fn handler(ctx: Context<FinalizeProposal>) -> Result<()> {
 
     dao.proposals_active = dao.proposals_active.checked_sub(1).unwrap();
 
-    // if the proposal has not been finalized within the `findalize_window_slots`, then fail it
-    // this is important if there is a bug in the proposal instructions
-    if clock.slot
-        >= proposal.slot_enqueued + dao.proposal_duration_slots + dao.finalize_window_slots
-    {
-        proposal.state = ProposalState::Failed;
-        return Ok(());
+    proposal.slot_finalized = clock.slot;
+
+    require!(
+        ctx.remaining_accounts.len() >= proposal.outcomes.len(),
+        ErrorCode::InvalidOutcomeAccounts
+    );
+
+    let (amms, queued_accounts) = ctx.remaining_accounts.split_at(proposal.outcomes.len());
+
+    let mut ltwaps = Vec::with_capacity(proposal.outcomes.len());
+
+    for (outcome, amm) in proposal.outcomes.iter().zip(amms.iter()) {
+        require_keys_eq!(amm.key(), outcome.amm, ErrorCode::AmmProposalMismatch);
+        require_keys_eq!(*amm.owner, amm::ID, ErrorCode::AmmProposalMismatch);
+
+        let amm = Amm::try_deserialize(&mut amm.data.borrow().as_ref())?;
+        ltwaps.push(amm.ltwap_latest);
     }
 
-    let dao_pubkey = dao.key();
-    let treasury_seeds = &[dao_pubkey.as_ref(), &[dao.treasury_pda_bump]];
-    let signer = &[&treasury_seeds[..]];
+    // a proposal that isn't finalized within `finalize_window_slots` of its
+    // markets closing fails regardless of its prices, the fee is still settled
+    let is_finalize_window_over = clock.slot
+        >= proposal.slot_enqueued + proposal.slots_duration + proposal.finalize_window_slots;
 
-    let threshold = (fail_market_amm.ltwap_latest as u128)
-        .checked_mul(BPS_SCALE.checked_add(dao.pass_threshold_bps).unwrap() as u128)
-        .unwrap()
-        .checked_div(BPS_SCALE as u128)
-        .unwrap();
+    let winning_outcome_index = proposal
+        .winning_outcome_index(&ltwaps)
+        .filter(|_| !is_finalize_window_over);
 
-    if (pass_market_amm.ltwap_latest as u128) > threshold {
+    // only records the market's decision, the instructions are run separately
+    // by `execute_proposal` so that a failing instruction can't revert it
+    if let Some(winning_outcome_index) = winning_outcome_index {
         proposal.state = ProposalState::Passed;
+        proposal.winning_outcome_index = winning_outcome_index;
 
-        for ix in proposal_instructions.instructions.iter() {
-            let mut svm_instruction: Instruction = ix.into();
-
-            for acc in svm_instruction.accounts.iter_mut() {
-                if &acc.pubkey == dao_treasury.key {
-                    acc.is_signer = true;
-                }
-            }
-
-            solana_program::program::invoke_signed(
-                &svm_instruction,
-                ctx.remaining_accounts,
-                signer,
-            )?;
-        }
+        emit!(ProposalExecutionQueued {
+            dao: dao.key(),
+            proposal: proposal.key(),
+            winning_outcome_index: proposal.winning_outcome_index,
+            scalar_value: proposal.scalar_value()?,
+            executable_slot: proposal.slot_finalized + proposal.execution_delay_slots,
+        });
     } else {
         proposal.state = ProposalState::Failed;
+        proposal.winning_outcome_index = STATUS_QUO_OUTCOME_INDEX;
+    }
+
+    let (bounty, refund, forfeited) = proposal.fee_split(usdc_fee_escrow.amount);
+
+    let proposal_dao = proposal.dao;
+    let proposal_number = proposal.number.to_le_bytes();
+    let seeds = generate_proposal_seeds!(proposal_dao, proposal_number, ctx.bumps.proposal);
+
+    token_transfer_signed(
+        bounty,
+        token_program,
+        usdc_fee_escrow.as_ref(),
+        usdc_mint,
+        usdc_user_ata.as_ref(),
+        proposal.as_ref(),
+        seeds,
+    )?;
+
+    token_transfer_signed(
+        refund,
+        token_program,
+        usdc_fee_escrow.as_ref(),
+        usdc_mint,
+        usdc_proposer_ata.as_ref(),
+        proposal.as_ref(),
+        seeds,
+    )?;
+
+    token_transfer_signed(
+        forfeited,
+        token_program,
+        usdc_fee_escrow.as_ref(),
+        usdc_mint,
+        usdc_treasury_vault_ata.as_ref(),
+        proposal.as_ref(),
+        seeds,
+    )?;
+
+    token_harvest_withheld_fees(token_program, usdc_mint, usdc_fee_escrow.as_ref())?;
+
+    token_close_account_signed(
+        token_program,
+        usdc_fee_escrow.as_ref(),
+        proposer,
+        proposal.as_ref(),
+        seeds,
+    )?;
+
+    let dao = &ctx.accounts.dao;
+
+    let Some((queued_proposal_info, mut queued_proposal, queued_amms)) =
+        get_next_queued_proposal(&dao.key(), dao, queued_accounts)?
+    else {
+        return Ok(());
+    };
+
+    // the markets only start counting once the proposal starts trading
+    let (_auth_pda, auth_pda_bump) =
+        Pubkey::find_program_address(&[AMM_AUTH_SEED_PREFIX], &Autocrat::id());
+    let amm_auth_seeds = &[AMM_AUTH_SEED_PREFIX, &[auth_pda_bump]];
+    let amm_auth_signer = [&amm_auth_seeds[..]];
+
+    for amm in queued_amms.iter() {
+        let reset_ltwap_ctx = ctx.accounts.into_reset_ltwap_context(amm, &amm_auth_signer);
+        amm::cpi::reset_ltwap(reset_ltwap_ctx)?;
     }
 
+    queued_proposal.state = ProposalState::Pending;
+    queued_proposal.slot_enqueued = clock.slot;
+    save_proposal(queued_proposal_info, &queued_proposal)?;
+
+    let dao = &mut ctx.accounts.dao;
+    dao.queue_head = dao.queue_head.checked_add(1).unwrap();
+    dao.proposals_active = dao
+        .proposals_active
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
     Ok(())
 }
+
+impl<'info> FinalizeProposal<'info> {
+    fn into_reset_ltwap_context<'a, 'b, 'c>(
+        &'a self,
+        amm: &AccountInfo<'info>,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, ResetLtwap<'info>> {
+        let cpi_accounts = ResetLtwap {
+            user: self.user.to_account_info(),
+            amm: amm.clone(),
+            auth_pda: Some(self.amm_auth_pda.to_account_info()),
+        };
+        let cpi_program = self.amm_program.to_account_info();
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
@@ -1,19 +1,32 @@
+// every instruction module has its own `handler`, which is only ever called by its full path
+#![allow(ambiguous_glob_reexports)]
+
 pub use add_proposal_instructions::*;
+pub use cancel_proposal::*;
+pub use cancel_proposal_market_side::*;
+pub use close_proposal::*;
+pub use close_proposal_amm_position::*;
+pub use close_proposal_instructions::*;
+pub use close_proposal_market_side::*;
 pub use create_proposal::*;
 pub use create_proposal_instructions::*;
 pub use create_proposal_market_side::*;
+pub use execute_proposal::*;
 pub use finalize_proposal::*;
-pub use merge_conditional_tokens::*;
-pub use mint_conditional_tokens::*;
-pub use redeem_conditional_tokens::*;
 pub use submit_proposal::*;
+pub use veto_proposal::*;
 
 pub mod add_proposal_instructions;
+pub mod cancel_proposal;
+pub mod cancel_proposal_market_side;
+pub mod close_proposal;
+pub mod close_proposal_amm_position;
+pub mod close_proposal_instructions;
+pub mod close_proposal_market_side;
 pub mod create_proposal;
 pub mod create_proposal_instructions;
 pub mod create_proposal_market_side;
+pub mod execute_proposal;
 pub mod finalize_proposal;
-pub mod merge_conditional_tokens;
-pub mod mint_conditional_tokens;
-pub mod redeem_conditional_tokens;
 pub mod submit_proposal;
+pub mod veto_proposal;
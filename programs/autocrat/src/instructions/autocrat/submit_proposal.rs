@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::Mint;
-use anchor_spl::token::Token;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
 
 use crate::program::Autocrat;
 use amm::cpi::accounts::UpdateLtwap;
@@ -21,86 +20,65 @@ pub struct SubmitProposal<'info> {
     #[account(
         mut,
         has_one = usdc_mint,
-        seeds = [b"WWCACOTMICMIBMHAFTTWYGHMB"],
-        bump
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
     )]
     pub dao: Box<Account<'info, Dao>>,
-    #[account(
-        seeds = [DAO_TREASURY_SEED_PREFIX, dao.key().as_ref()],
-        bump = dao.treasury_pda_bump,
-    )]
-    pub dao_treasury: Account<'info, DaoTreasury>,
     #[account(
         mut,
+        has_one = dao,
         has_one = proposer,
-        has_one = pass_market_amm,
-        has_one = fail_market_amm,
         seeds = [
             PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
             proposal.number.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub proposal: Box<Account<'info, Proposal>>,
-    #[account(
-        mut,
-        seeds = [
-            PROPOSAL_VAULT_SEED_PREFIX,
-            proposal.key().as_ref(),
-        ],
-        bump
-    )]
-    pub proposal_vault: Box<Account<'info, ProposalVault>>,
-    #[account(
-        mut,
-        has_one = proposer,
-        has_one = proposal,
-    )]
-    pub proposal_instructions: Box<Account<'info, ProposalInstructions>>,
-    pub usdc_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = token_program)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
         associated_token::mint = usdc_mint,
         associated_token::authority = proposer,
+        associated_token::token_program = token_program,
     )]
-    pub usdc_proposer_ata: Box<Account<'info, TokenAccount>>,
+    pub usdc_proposer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    // holds the proposal fee until the proposal is finalized
     #[account(
-        init_if_needed,
+        init,
         payer = proposer,
-        associated_token::mint = usdc_mint,
-        associated_token::authority = dao_treasury,
+        token::mint = usdc_mint,
+        token::authority = proposal,
+        token::token_program = token_program,
+        seeds = [
+            PROPOSAL_FEE_ESCROW_SEED_PREFIX,
+            proposal.key().as_ref(),
+        ],
+        bump
     )]
-    pub usdc_treasury_vault_ata: Box<Account<'info, TokenAccount>>,
-    #[account(mut)]
-    /// CHECK:
-    pub pass_market_amm: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK:
-    pub fail_market_amm: UncheckedAccount<'info>,
+    pub usdc_fee_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK
     pub amm_auth_pda: UncheckedAccount<'info>,
     #[account(address = amm::ID)]
     pub amm_program: Program<'info, Amm>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<SubmitProposal>) -> Result<()> {
+// the AMM of every outcome is passed as a remaining account, in outcome order,
+// followed by the proposal's prerequisites
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SubmitProposal<'info>>) -> Result<()> {
     let SubmitProposal {
         proposer,
         dao,
-        dao_treasury: _,
         proposal,
-        proposal_vault: _,
-        proposal_instructions,
-        usdc_mint: _,
+        usdc_mint,
         usdc_proposer_ata,
-        usdc_treasury_vault_ata,
-        pass_market_amm: _,
-        fail_market_amm: _,
+        usdc_fee_escrow,
         amm_auth_pda: _,
         amm_program: _,
         associated_token_program: _,
@@ -110,30 +88,81 @@ pub fn handler(ctx: Context<SubmitProposal>) -> Result<()> {
 
     assert_eq!(proposal.proposer, proposer.key());
 
-    assert!(proposal.is_pass_market_created);
-    assert!(proposal.is_fail_market_created);
+    require!(
+        proposal
+            .outcomes
+            .iter()
+            .all(|outcome| outcome.is_market_created),
+        ErrorCode::OutcomeMarketsNotCreated
+    );
 
-    // pay proposal spam deterrent fee
-    let usdc_fee = dao.proposal_fee_usdc;
-    let active_proposals = dao.proposals_active;
-    let fee_multiplier = 2u64.checked_pow(active_proposals).unwrap();
-    let effective_usdc_fee = usdc_fee.checked_mul(fee_multiplier).unwrap();
+    require!(
+        ctx.remaining_accounts.len() >= proposal.outcomes.len(),
+        ErrorCode::InvalidOutcomeAccounts
+    );
+
+    let (amms, prerequisite_infos) = ctx.remaining_accounts.split_at(proposal.outcomes.len());
+
+    for (outcome, amm) in proposal.outcomes.iter().zip(amms.iter()) {
+        require_keys_eq!(amm.key(), outcome.amm, ErrorCode::AmmProposalMismatch);
+    }
+
+    // a prerequisite that already won't be executed would make this proposal
+    // pointless, and every prerequisite has to stay open until this one is closed
+    for (prerequisite_info, mut prerequisite) in get_prerequisites(proposal, prerequisite_infos)? {
+        require!(
+            prerequisite.state != ProposalState::Initialize && !prerequisite.state.is_abandoned(),
+            ErrorCode::InvalidPrerequisites
+        );
+
+        prerequisite.dependent_count = prerequisite.dependent_count.checked_add(1).unwrap();
+        save_proposal(prerequisite_info, &prerequisite)?;
+    }
+
+    // escrow the proposal spam deterrent fee, which is settled on finalization
+    let clock = Clock::get()?;
+    let effective_usdc_fee = dao.config.proposal_fee_curve.fee(
+        dao.config.proposal_fee_usdc,
+        dao.proposals_active,
+        clock.slot.saturating_sub(dao.last_proposal_slot),
+    )?;
 
     token_transfer(
         effective_usdc_fee,
         token_program,
         usdc_proposer_ata.as_ref(),
-        usdc_treasury_vault_ata.as_ref(),
+        usdc_mint,
+        usdc_fee_escrow.as_ref(),
         proposer.as_ref(),
     )?;
 
     assert_eq!(proposal.state, ProposalState::Initialize);
-    proposal.state = ProposalState::Pending;
-    dao.proposals_active = dao.proposals_active.checked_add(1).unwrap();
+    dao.last_proposal_slot = clock.slot;
+
+    proposal.slots_duration = dao.config.proposal_duration_slots;
+    proposal.finalize_window_slots = dao.config.finalize_window_slots;
+    proposal.execution_window_slots = dao.config.execution_window_slots;
+    proposal.execution_delay_slots = dao.config.execution_delay_slots;
+    proposal.pass_threshold_bps = dao.config.pass_threshold_bps;
+    proposal.finalizer_bounty_usdc = dao.config.finalizer_bounty_usdc;
+    proposal.proposal_fee_refund_bps = dao.config.proposal_fee_refund_bps;
+
+    // while the DAO is at capacity, or others are already waiting, the proposal
+    // joins the back of the queue and is started by `finalize_proposal`
+    if dao.proposals_active >= dao.config.max_active_proposals || dao.queue_head < dao.queue_tail {
+        proposal.state = ProposalState::Queued;
+        proposal.queue_position = dao.queue_tail;
+        dao.queue_tail = dao.queue_tail.checked_add(1).unwrap();
+
+        return Ok(());
+    }
 
-    proposal.slot_enqueued = Clock::get()?.slot;
-    proposal.slots_duration = dao.proposal_duration_slots;
-    proposal_instructions.proposal_instructions_frozen = true;
+    proposal.state = ProposalState::Pending;
+    proposal.slot_enqueued = clock.slot;
+    dao.proposals_active = dao
+        .proposals_active
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     // start LTWAP
     let (_auth_pda, auth_pda_bump) =
@@ -141,37 +170,23 @@ pub fn handler(ctx: Context<SubmitProposal>) -> Result<()> {
     let seeds = &[AMM_AUTH_SEED_PREFIX, &[auth_pda_bump]];
     let signer = [&seeds[..]];
 
-    let update_pass_market_ltwap_ctx = ctx.accounts.into_update_pass_market_ltwap_context(&signer);
-    amm::cpi::update_ltwap(update_pass_market_ltwap_ctx, None)?;
-
-    let update_fail_market_ltwap_ctx = ctx.accounts.into_update_fail_market_ltwap_context(&signer);
-    amm::cpi::update_ltwap(update_fail_market_ltwap_ctx, None)?;
+    for amm in amms.iter() {
+        let update_ltwap_ctx = ctx.accounts.into_update_ltwap_context(amm, &signer);
+        amm::cpi::update_ltwap(update_ltwap_ctx, None)?;
+    }
 
     Ok(())
 }
 
 impl<'info> SubmitProposal<'info> {
-    fn into_update_pass_market_ltwap_context<'a, 'b, 'c>(
-        &'a self,
-        signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, UpdateLtwap<'info>> {
-        let cpi_accounts = UpdateLtwap {
-            user: self.proposer.to_account_info(),
-            amm: self.pass_market_amm.to_account_info(),
-            system_program: self.system_program.to_account_info(),
-            auth_pda: Some(self.amm_auth_pda.to_account_info()),
-        };
-        let cpi_program = self.amm_program.to_account_info();
-        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
-    }
-
-    fn into_update_fail_market_ltwap_context<'a, 'b, 'c>(
+    fn into_update_ltwap_context<'a, 'b, 'c>(
         &'a self,
+        amm: &AccountInfo<'info>,
         signer_seeds: &'a [&'b [&'c [u8]]],
-    ) -> CpiContext<'_, '_, '_, 'info, UpdateLtwap<'info>> {
+    ) -> CpiContext<'a, 'b, 'c, 'info, UpdateLtwap<'info>> {
         let cpi_accounts = UpdateLtwap {
             user: self.proposer.to_account_info(),
-            amm: self.fail_market_amm.to_account_info(),
+            amm: amm.clone(),
             system_program: self.system_program.to_account_info(),
             auth_pda: Some(self.amm_auth_pda.to_account_info()),
         };
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    pub guardian: Signer<'info>,
+    #[account(
+        mut,
+        has_one = dao,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        has_one = guardian @ ErrorCode::InvalidGuardian,
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
+    )]
+    pub dao: Box<Account<'info, Dao>>,
+}
+
+// lets the DAO's guardian stop a passed proposal while it's still queued
+pub fn handler(ctx: Context<VetoProposal>) -> Result<()> {
+    let VetoProposal {
+        guardian,
+        proposal,
+        dao,
+    } = ctx.accounts;
+
+    // DAOs without a guardian can't have their proposals vetoed
+    require_keys_neq!(dao.guardian, Pubkey::default(), ErrorCode::InvalidGuardian);
+
+    require!(
+        proposal.state == ProposalState::Passed,
+        ErrorCode::ProposalNotPassed
+    );
+
+    let clock = Clock::get()?;
+
+    require!(
+        clock.slot < proposal.slot_finalized + proposal.execution_delay_slots,
+        ErrorCode::VetoWindowClosed
+    );
+
+    proposal.state = ProposalState::Vetoed;
+
+    emit!(ProposalVetoed {
+        dao: dao.key(),
+        proposal: proposal.key(),
+        guardian: guardian.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::generate_conditional_vault_seeds;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct CloseConditionalVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// CHECK: paid for the vault, so it receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = underlying_mint,
+        close = proposer,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            underlying_mint.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, ConditionalVault>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        address = vault.underlying_token_account @ ErrorCode::InvalidVaultUnderlyingTokenAccount
+    )]
+    pub vault_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program,
+    )]
+    pub proposer_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// permissionlessly closes a settled vault once none of its conditional tokens
+// are a claim on it anymore, returning the rent to the proposer. The conditional
+// mints whose supply has to be zero are passed as remaining accounts, see
+// `ConditionalVault::get_outstanding_supply`. The mints are created without a
+// close authority, so they're left behind.
+pub fn handler(ctx: Context<CloseConditionalVault>) -> Result<()> {
+    let CloseConditionalVault {
+        user: _,
+        proposal,
+        proposer,
+        vault,
+        underlying_mint,
+        vault_underlying_token_account,
+        proposer_underlying_token_account,
+        associated_token_program: _,
+        token_program,
+        system_program: _,
+    } = ctx.accounts;
+
+    require_eq!(
+        vault.get_outstanding_supply(ctx.remaining_accounts)?,
+        0,
+        ErrorCode::ConditionalTokensNotRedeemed
+    );
+
+    let proposal_key = proposal.key();
+    let seeds = generate_conditional_vault_seeds!(proposal_key, vault.underlying_mint, vault.bump);
+
+    if vault.status == VaultStatus::Reverted {
+        // a cancelled proposal's markets never traded, so what's left is the
+        // proposer's deposit
+        token_transfer_signed(
+            vault_underlying_token_account.amount,
+            token_program,
+            vault_underlying_token_account.as_ref(),
+            underlying_mint,
+            proposer_underlying_token_account.as_ref(),
+            vault.as_ref(),
+            seeds,
+        )?;
+    } else {
+        // with no claims left, anything still in the vault was donated to it and
+        // belongs to no one, so burn it to allow the vault to be closed
+        token_burn_signed(
+            vault_underlying_token_account.amount,
+            token_program,
+            underlying_mint.as_ref(),
+            vault_underlying_token_account.as_ref(),
+            vault.as_ref(),
+            seeds,
+        )?;
+    }
+
+    token_harvest_withheld_fees(
+        token_program,
+        underlying_mint,
+        vault_underlying_token_account.as_ref(),
+    )?;
+
+    token_close_account_signed(
+        token_program,
+        vault_underlying_token_account.as_ref(),
+        proposer,
+        vault.as_ref(),
+        seeds,
+    )?;
+
+    proposal.conditional_vault_count = proposal.conditional_vault_count.checked_sub(1).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(outcome_index: u8)]
+pub struct CreateConditionalVaultMint<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        has_one = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        mut,
+        has_one = underlying_mint,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            underlying_mint.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, ConditionalVault>>,
+    #[account(mint::token_program = token_program)]
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+    // under the same token program as the underlying mint, so that one program
+    // moves both in the vault's instructions
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [
+            CONDITIONAL_MINT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            &[outcome_index],
+            underlying_mint.key().as_ref(),
+        ],
+        bump,
+        mint::authority = vault,
+        mint::decimals = underlying_mint.decimals,
+        mint::token_program = token_program,
+    )]
+    pub conditional_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// the conditional mints of the vaults that back the proposal's markets are
+// created along with each market, see `create_proposal_market_side`
+pub fn handler(ctx: Context<CreateConditionalVaultMint>, outcome_index: u8) -> Result<()> {
+    let CreateConditionalVaultMint {
+        proposer: _,
+        proposal,
+        vault,
+        underlying_mint: _,
+        conditional_mint,
+        token_program: _,
+        system_program: _,
+    } = ctx.accounts;
+
+    require!(
+        proposal.state == ProposalState::Initialize,
+        ErrorCode::ProposalAlreadySubmitted
+    );
+
+    vault.set_conditional_mint(outcome_index, conditional_mint.key())?;
+
+    Ok(())
+}
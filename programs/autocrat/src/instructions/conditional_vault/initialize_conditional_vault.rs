@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeConditionalVault<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = proposer,
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ConditionalVault::INIT_SPACE,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            underlying_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vault: Box<Account<'info, ConditionalVault>>,
+    #[account(mint::token_program = token_program)]
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = proposer,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// lets the proposer condition any token on the proposal's outcome, on top of
+// the governance and quote tokens its markets trade. The vault's conditional
+// mints are created separately, one per outcome.
+pub fn handler(ctx: Context<InitializeConditionalVault>) -> Result<()> {
+    let InitializeConditionalVault {
+        proposer: _,
+        proposal,
+        vault,
+        underlying_mint,
+        vault_underlying_token_account,
+        associated_token_program: _,
+        token_program: _,
+        system_program: _,
+    } = ctx.accounts;
+
+    require!(
+        proposal.state == ProposalState::Initialize,
+        ErrorCode::ProposalAlreadySubmitted
+    );
+
+    vault.initialize(
+        ctx.bumps.vault,
+        proposal,
+        proposal.key(),
+        underlying_mint.key(),
+        vault_underlying_token_account.key(),
+    );
+
+    proposal.conditional_vault_count = proposal
+        .conditional_vault_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    Ok(())
+}
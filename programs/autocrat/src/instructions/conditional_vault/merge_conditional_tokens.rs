@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::generate_conditional_vault_seeds;
+use crate::state::*;
+use crate::utils::*;
+
+// the user's conditional token accounts for every outcome are passed as remaining
+// accounts, see `ConditionalVault::get_conditional_token_accounts`
+#[derive(Accounts)]
+pub struct MergeConditionalTokens<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        has_one = underlying_mint,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            vault.settlement_authority.as_ref(),
+            vault.underlying_mint.as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, ConditionalVault>>,
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        address = vault.underlying_token_account @ ErrorCode::InvalidVaultUnderlyingTokenAccount
+    )]
+    pub vault_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = vault.underlying_mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// a conditional token of every outcome is always worth one underlying token,
+// whether or not the vault has been settled
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MergeConditionalTokens<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let MergeConditionalTokens {
+        user,
+        vault,
+        underlying_mint,
+        vault_underlying_token_account,
+        user_underlying_token_account,
+        token_program,
+    } = ctx.accounts;
+
+    let conditional_token_accounts =
+        vault.get_conditional_token_accounts(ctx.remaining_accounts)?;
+
+    let seeds = generate_conditional_vault_seeds!(
+        vault.settlement_authority,
+        vault.underlying_mint,
+        vault.bump
+    );
+
+    for (conditional_mint, user_conditional_token_account) in conditional_token_accounts {
+        token_burn(
+            amount,
+            token_program,
+            conditional_mint,
+            user_conditional_token_account,
+            user,
+        )?;
+    }
+
+    token_transfer_signed(
+        amount,
+        token_program,
+        vault_underlying_token_account.as_ref(),
+        underlying_mint,
+        user_underlying_token_account.as_ref(),
+        vault.as_ref(),
+        seeds,
+    )?;
+
+    Ok(())
+}
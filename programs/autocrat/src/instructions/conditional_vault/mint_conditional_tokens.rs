@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::generate_conditional_vault_seeds;
+use crate::state::*;
+use crate::utils::*;
+
+// the user's conditional token accounts for every outcome are passed as remaining
+// accounts, see `ConditionalVault::get_conditional_token_accounts`
+#[derive(Accounts)]
+pub struct MintConditionalTokens<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        has_one = underlying_mint,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            proposal.key().as_ref(),
+            vault.underlying_mint.as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, ConditionalVault>>,
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        address = vault.underlying_token_account @ ErrorCode::InvalidVaultUnderlyingTokenAccount
+    )]
+    pub vault_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = vault.underlying_mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintConditionalTokens<'info>>,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts
+        .deposit_and_mint(amount, ctx.remaining_accounts)?;
+
+    Ok(())
+}
+
+impl<'info> MintConditionalTokens<'info> {
+    // deposits `amount` of the user's underlying tokens and mints a conditional
+    // token of every outcome for each one that reaches the vault, returning how
+    // many of each were minted. Also used by `mint_and_swap`.
+    pub fn deposit_and_mint(
+        &mut self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u64> {
+        let MintConditionalTokens {
+            user,
+            proposal,
+            vault,
+            underlying_mint,
+            vault_underlying_token_account,
+            user_underlying_token_account,
+            token_program,
+        } = self;
+
+        // conditional tokens can only be minted while the markets are trading, so
+        // that an unsubmitted proposal can always be cancelled and a settled
+        // vault can always be closed
+        require!(
+            proposal.state == ProposalState::Pending,
+            ErrorCode::ProposalNotPending
+        );
+        require!(
+            vault.status == VaultStatus::Active,
+            ErrorCode::VaultAlreadySettled
+        );
+
+        require!(
+            user_underlying_token_account.amount >= amount,
+            ErrorCode::InsufficientUnderlyingTokens
+        );
+
+        let conditional_token_accounts =
+            vault.get_conditional_token_accounts(remaining_accounts)?;
+
+        let proposal_key = proposal.key();
+        let seeds =
+            generate_conditional_vault_seeds!(proposal_key, vault.underlying_mint, vault.bump);
+
+        let vault_balance_before = vault_underlying_token_account.amount;
+        token_transfer(
+            amount,
+            token_program,
+            user_underlying_token_account.as_ref(),
+            underlying_mint,
+            vault_underlying_token_account.as_ref(),
+            user.as_ref(),
+        )?;
+        let deposited = token_received(vault_underlying_token_account, vault_balance_before)?;
+
+        // exactly one outcome will win, so every token that reached the vault backs
+        // one conditional token of each outcome. A transfer fee is paid by the user.
+        for (conditional_mint, user_conditional_token_account) in conditional_token_accounts {
+            token_mint_signed(
+                deposited,
+                token_program,
+                conditional_mint,
+                user_conditional_token_account,
+                vault.as_ref(),
+                seeds,
+            )?;
+        }
+
+        Ok(deposited)
+    }
+}
@@ -0,0 +1,18 @@
+// every instruction module has its own `handler`, which is only ever called by its full path
+#![allow(ambiguous_glob_reexports)]
+
+pub use close_conditional_vault::*;
+pub use create_conditional_vault_mint::*;
+pub use initialize_conditional_vault::*;
+pub use merge_conditional_tokens::*;
+pub use mint_conditional_tokens::*;
+pub use redeem_conditional_tokens::*;
+pub use settle_conditional_vault::*;
+
+pub mod close_conditional_vault;
+pub mod create_conditional_vault_mint;
+pub mod initialize_conditional_vault;
+pub mod merge_conditional_tokens;
+pub mod mint_conditional_tokens;
+pub mod redeem_conditional_tokens;
+pub mod settle_conditional_vault;
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::generate_conditional_vault_seeds;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct RedeemConditionalTokens<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        has_one = underlying_mint,
+        seeds = [
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            vault.settlement_authority.as_ref(),
+            vault.underlying_mint.as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, ConditionalVault>>,
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        address = vault.underlying_token_account @ ErrorCode::InvalidVaultUnderlyingTokenAccount
+    )]
+    pub vault_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = vault.underlying_mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_underlying_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    // the winning outcome's
+    #[account(mut)]
+    pub conditional_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = conditional_mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_conditional_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<RedeemConditionalTokens>, amount: u64) -> Result<()> {
+    let RedeemConditionalTokens {
+        user,
+        vault,
+        underlying_mint,
+        vault_underlying_token_account,
+        user_underlying_token_account,
+        conditional_mint,
+        user_conditional_token_account,
+        token_program,
+    } = ctx.accounts;
+
+    vault.validate_redemption(
+        &conditional_mint.key(),
+        amount,
+        user_conditional_token_account.amount,
+    )?;
+
+    let seeds = generate_conditional_vault_seeds!(
+        vault.settlement_authority,
+        vault.underlying_mint,
+        vault.bump
+    );
+
+    // exactly what is burned is paid out, and every winning conditional token
+    // was minted against one underlying token. Losing conditional tokens are
+    // worthless and left untouched.
+    token_burn(
+        amount,
+        token_program,
+        conditional_mint.as_ref(),
+        user_conditional_token_account.as_ref(),
+        user,
+    )?;
+
+    token_transfer_signed(
+        amount,
+        token_program,
+        vault_underlying_token_account.as_ref(),
+        underlying_mint,
+        user_underlying_token_account.as_ref(),
+        vault.as_ref(),
+        seeds,
+    )?;
+
+    Ok(())
+}
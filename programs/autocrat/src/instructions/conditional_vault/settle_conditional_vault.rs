@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SettleConditionalVault<'info> {
+    #[account(
+        seeds = [
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        mut,
+        constraint = vault.settlement_authority == proposal.key() @ ErrorCode::InvalidSettlementAuthority
+    )]
+    pub vault: Box<Account<'info, ConditionalVault>>,
+}
+
+// permissionlessly settles a vault once its proposal has been decided: as
+// finalized with the winning outcome, or as reverted if the proposal was
+// cancelled. A vault can only be settled once.
+pub fn handler(ctx: Context<SettleConditionalVault>) -> Result<()> {
+    let SettleConditionalVault { proposal, vault } = ctx.accounts;
+
+    vault.settle(proposal)
+}
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenInterface;
 
+use crate::error::ErrorCode;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -14,10 +15,7 @@ pub struct InitializeDao<'info> {
         init,
         payer = payer,
         space = 8 + std::mem::size_of::<Dao>(),
-        // We will create a civilization of the Mind in Cyberspace. May it be
-        // more humane and fair than the world your governments have made before.
-        //  - John Perry Barlow, A Declaration of the Independence of Cyberspace
-        seeds = [b"WWCACOTMICMIBMHAFTTWYGHMB"],
+        seeds = [DAO_SEED_PREFIX, payer.key().as_ref(), meta_mint.key().as_ref()],
         bump
     )]
     pub dao: Account<'info, Dao>,
@@ -29,22 +27,51 @@ pub struct InitializeDao<'info> {
         bump
     )]
     pub dao_treasury: Account<'info, DaoTreasury>,
-    #[account(mint::decimals = 9)]
-    pub meta_mint: Account<'info, Mint>,
-    #[account(mint::decimals = 6)]
-    pub usdc_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InstructionPolicy::INIT_SPACE,
+        seeds = [INSTRUCTION_POLICY_SEED_PREFIX, dao.key().as_ref()],
+        bump
+    )]
+    pub instruction_policy: Account<'info, InstructionPolicy>,
+    pub meta_mint: InterfaceAccount<'info, Mint>,
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitializeDao>) -> Result<()> {
+// any parameter left as `None` falls back to the defaults in `state/mod.rs`,
+// with quote-denominated defaults scaled by the quote mint's decimals
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct InitializeDaoParams {
+    // the governance and quote tokens' tickers, e.g. `META` and `USDC`
+    pub meta_ticker: String,
+    pub usdc_ticker: String,
+    pub pass_threshold_bps: Option<u64>,
+    pub proposal_duration_slots: Option<u64>,
+    pub max_active_proposals: Option<u32>,
+    pub finalize_window_slots: Option<u64>,
+    pub execution_window_slots: Option<u64>,
+    pub execution_delay_slots: Option<u64>,
+    pub proposal_fee_usdc: Option<u64>,
+    pub proposal_fee_curve: Option<ProposalFeeCurve>,
+    pub finalizer_bounty_usdc: Option<u64>,
+    pub proposal_fee_refund_bps: Option<u64>,
+    pub amm_initial_quote_liquidity_amount: Option<u64>,
+    pub amm_swap_fee_bps: Option<u64>,
+    pub amm_ltwap_decimals: Option<u8>,
+    pub guardian: Option<Pubkey>,
+}
+
+pub fn handler(ctx: Context<InitializeDao>, dao_params: InitializeDaoParams) -> Result<()> {
     let InitializeDao {
-        payer: _,
+        payer,
         dao,
         dao_treasury,
+        instruction_policy,
         meta_mint,
         usdc_mint,
         associated_token_program: _,
@@ -52,30 +79,101 @@ pub fn handler(ctx: Context<InitializeDao>) -> Result<()> {
         system_program: _,
     } = ctx.accounts;
 
+    require_keys_neq!(
+        meta_mint.key(),
+        usdc_mint.key(),
+        ErrorCode::IdenticalDaoMints
+    );
+    require!(
+        meta_mint.decimals <= MAX_DECIMALS && usdc_mint.decimals <= MAX_DECIMALS,
+        ErrorCode::InvalidMintDecimals
+    );
+
+    dao.bump = ctx.bumps.dao;
+
     dao_treasury.dao = dao.key();
     dao_treasury.bump = ctx.bumps.dao_treasury;
 
     dao.treasury_pda_bump = ctx.bumps.dao_treasury;
     dao.treasury_pda = dao_treasury.key();
 
+    // disabled until the DAO passes a proposal that sets it up
+    instruction_policy.bump = ctx.bumps.instruction_policy;
+    instruction_policy.dao = dao.key();
+    instruction_policy.is_enabled = false;
+    instruction_policy.rules = vec![];
+
+    dao.creator = payer.key();
     dao.meta_mint = meta_mint.key();
     dao.usdc_mint = usdc_mint.key();
 
-    dao.proposal_count = 10;
-    dao.proposal_fee_usdc = PROPOSAL_FEE_USDC;
+    dao.meta_mint_decimals = meta_mint.decimals;
+    dao.usdc_mint_decimals = usdc_mint.decimals;
+
+    dao.meta_ticker = encode_ticker(&dao_params.meta_ticker)?;
+    dao.usdc_ticker = encode_ticker(&dao_params.usdc_ticker)?;
+
+    dao.proposal_count = 0;
+
+    dao.guardian = dao_params.guardian.unwrap_or_default();
+
+    let mut config = DaoConfig::default_for_usdc_decimals(usdc_mint.decimals)?;
+
+    if let Some(pass_threshold_bps) = dao_params.pass_threshold_bps {
+        config.pass_threshold_bps = pass_threshold_bps;
+    }
+
+    if let Some(proposal_duration_slots) = dao_params.proposal_duration_slots {
+        config.proposal_duration_slots = proposal_duration_slots;
+    }
+
+    if let Some(max_active_proposals) = dao_params.max_active_proposals {
+        config.max_active_proposals = max_active_proposals;
+    }
+
+    if let Some(finalize_window_slots) = dao_params.finalize_window_slots {
+        config.finalize_window_slots = finalize_window_slots;
+    }
+
+    if let Some(execution_window_slots) = dao_params.execution_window_slots {
+        config.execution_window_slots = execution_window_slots;
+    }
+
+    if let Some(execution_delay_slots) = dao_params.execution_delay_slots {
+        config.execution_delay_slots = execution_delay_slots;
+    }
+
+    if let Some(proposal_fee_usdc) = dao_params.proposal_fee_usdc {
+        config.proposal_fee_usdc = proposal_fee_usdc;
+    }
+
+    if let Some(proposal_fee_curve) = dao_params.proposal_fee_curve {
+        config.proposal_fee_curve = proposal_fee_curve;
+    }
+
+    if let Some(finalizer_bounty_usdc) = dao_params.finalizer_bounty_usdc {
+        config.finalizer_bounty_usdc = finalizer_bounty_usdc;
+    }
 
-    dao.pass_threshold_bps = DEFAULT_PASS_THRESHOLD_BPS;
+    if let Some(proposal_fee_refund_bps) = dao_params.proposal_fee_refund_bps {
+        config.proposal_fee_refund_bps = proposal_fee_refund_bps;
+    }
 
-    dao.proposal_duration_slots = PROPOSAL_DURATION_SLOTS;
-    dao.finalize_window_slots = FINALIZE_WINDOW_SLOTS;
+    if let Some(amm_initial_quote_liquidity_amount) = dao_params.amm_initial_quote_liquidity_amount
+    {
+        config.amm_initial_quote_liquidity_amount = amm_initial_quote_liquidity_amount;
+    }
 
-    dao.amm_initial_quote_liquidity_amount = AMM_INITIAL_QUOTE_LIQUIDITY;
+    if let Some(amm_swap_fee_bps) = dao_params.amm_swap_fee_bps {
+        config.amm_swap_fee_bps = amm_swap_fee_bps;
+    }
 
-    assert!(AMM_SWAP_FEE_BPS <= AMM_SWAP_FEE_BPS_MAX);
-    assert!(AMM_SWAP_FEE_BPS >= AMM_SWAP_FEE_BPS_MIN);
-    dao.amm_swap_fee_bps = AMM_SWAP_FEE_BPS;
+    if let Some(amm_ltwap_decimals) = dao_params.amm_ltwap_decimals {
+        config.amm_ltwap_decimals = amm_ltwap_decimals;
+    }
 
-    dao.amm_ltwap_decimals = 9;
+    config.validate()?;
+    dao.config = config;
 
     Ok(())
 }
@@ -1,5 +1,10 @@
+// every instruction module has its own `handler`, which is only ever called by its full path
+#![allow(ambiguous_glob_reexports)]
+
 pub use initialize::*;
 pub use update::*;
+pub use update_instruction_policy::*;
 
 pub mod initialize;
 pub mod update;
+pub mod update_instruction_policy;
@@ -6,8 +6,8 @@ use crate::state::*;
 pub struct UpdateDao<'info> {
     #[account(
         mut,
-        seeds = [b"WWCACOTMICMIBMHAFTTWYGHMB"],
-        bump
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
     )]
     pub dao: Account<'info, Dao>,
     #[account(
@@ -22,44 +22,85 @@ pub struct UpdateDao<'info> {
 pub struct UpdateDaoParams {
     pub pass_threshold_bps: Option<u64>,
     pub proposal_duration_slots: Option<u64>,
+    pub max_active_proposals: Option<u32>,
     pub finalize_window_slots: Option<u64>,
+    pub execution_window_slots: Option<u64>,
+    pub execution_delay_slots: Option<u64>,
     pub proposal_fee_usdc: Option<u64>,
+    pub proposal_fee_curve: Option<ProposalFeeCurve>,
+    pub finalizer_bounty_usdc: Option<u64>,
+    pub proposal_fee_refund_bps: Option<u64>,
     pub amm_initial_quote_liquidity_amount: Option<u64>,
     pub amm_swap_fee_bps: Option<u64>,
     pub amm_ltwap_decimals: Option<u8>,
+    pub guardian: Option<Pubkey>,
 }
 
 pub fn handler(ctx: Context<UpdateDao>, dao_params: UpdateDaoParams) -> Result<()> {
     let dao = &mut ctx.accounts.dao;
+    let mut config = dao.config;
 
     if let Some(pass_threshold_bps) = dao_params.pass_threshold_bps {
-        dao.pass_threshold_bps = pass_threshold_bps;
+        config.pass_threshold_bps = pass_threshold_bps;
     }
 
     if let Some(proposal_duration_slots) = dao_params.proposal_duration_slots {
-        dao.proposal_duration_slots = proposal_duration_slots;
+        config.proposal_duration_slots = proposal_duration_slots;
+    }
+
+    if let Some(max_active_proposals) = dao_params.max_active_proposals {
+        config.max_active_proposals = max_active_proposals;
     }
 
     if let Some(finalize_window_slots) = dao_params.finalize_window_slots {
-        dao.finalize_window_slots = finalize_window_slots;
+        config.finalize_window_slots = finalize_window_slots;
+    }
+
+    if let Some(execution_window_slots) = dao_params.execution_window_slots {
+        config.execution_window_slots = execution_window_slots;
+    }
+
+    if let Some(execution_delay_slots) = dao_params.execution_delay_slots {
+        config.execution_delay_slots = execution_delay_slots;
     }
 
     if let Some(proposal_fee_usdc) = dao_params.proposal_fee_usdc {
-        dao.proposal_fee_usdc = proposal_fee_usdc;
+        config.proposal_fee_usdc = proposal_fee_usdc;
+    }
+
+    if let Some(proposal_fee_curve) = dao_params.proposal_fee_curve {
+        config.proposal_fee_curve = proposal_fee_curve;
+    }
+
+    if let Some(finalizer_bounty_usdc) = dao_params.finalizer_bounty_usdc {
+        config.finalizer_bounty_usdc = finalizer_bounty_usdc;
+    }
+
+    if let Some(proposal_fee_refund_bps) = dao_params.proposal_fee_refund_bps {
+        config.proposal_fee_refund_bps = proposal_fee_refund_bps;
     }
 
     if let Some(amm_initial_quote_liquidity_amount) = dao_params.amm_initial_quote_liquidity_amount
     {
-        dao.amm_initial_quote_liquidity_amount = amm_initial_quote_liquidity_amount;
+        config.amm_initial_quote_liquidity_amount = amm_initial_quote_liquidity_amount;
     }
 
     if let Some(amm_swap_fee_bps) = dao_params.amm_swap_fee_bps {
-        dao.amm_swap_fee_bps = amm_swap_fee_bps;
+        config.amm_swap_fee_bps = amm_swap_fee_bps;
     }
 
     if let Some(amm_ltwap_decimals) = dao_params.amm_ltwap_decimals {
-        dao.amm_ltwap_decimals = amm_ltwap_decimals;
+        config.amm_ltwap_decimals = amm_ltwap_decimals;
     }
 
+    // set to `Pubkey::default()` to remove the guardian
+    if let Some(guardian) = dao_params.guardian {
+        dao.guardian = guardian;
+    }
+
+    // reject any update that would leave the DAO unable to run proposals
+    config.validate()?;
+    dao.config = config;
+
     Ok(())
 }
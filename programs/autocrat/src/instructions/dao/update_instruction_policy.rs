@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateInstructionPolicy<'info> {
+    #[account(
+        seeds = [DAO_SEED_PREFIX, dao.creator.as_ref(), dao.meta_mint.as_ref()],
+        bump = dao.bump
+    )]
+    pub dao: Account<'info, Dao>,
+    #[account(
+        signer,
+        seeds = [DAO_TREASURY_SEED_PREFIX, dao.key().as_ref()],
+        bump = dao.treasury_pda_bump,
+    )]
+    pub dao_treasury: Signer<'info>,
+    #[account(
+        mut,
+        has_one = dao,
+        seeds = [INSTRUCTION_POLICY_SEED_PREFIX, dao.key().as_ref()],
+        bump = instruction_policy.bump
+    )]
+    pub instruction_policy: Account<'info, InstructionPolicy>,
+}
+
+// replaces the whole policy, the treasury can only sign for it through a passed proposal
+pub fn handler(
+    ctx: Context<UpdateInstructionPolicy>,
+    is_enabled: bool,
+    rules: Vec<InstructionPolicyRule>,
+) -> Result<()> {
+    let instruction_policy = &mut ctx.accounts.instruction_policy;
+
+    InstructionPolicy::validate_rules(&rules)?;
+
+    instruction_policy.is_enabled = is_enabled;
+    instruction_policy.rules = rules;
+
+    Ok(())
+}
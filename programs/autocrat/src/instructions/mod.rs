@@ -1,7 +1,12 @@
+// every instruction module has its own `handler`, which is only ever called by its full path
+#![allow(ambiguous_glob_reexports)]
+
 pub use amm_cpi::*;
 pub use autocrat::*;
+pub use conditional_vault::*;
 pub use dao::*;
 
 pub mod amm_cpi;
 pub mod autocrat;
+pub mod conditional_vault;
 pub mod dao;
@@ -16,14 +16,13 @@ security_txt! {
 }
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
-use crate::error::*;
 use crate::instructions::*;
 use crate::state::*;
-use crate::utils::*;
 
 declare_id!("66629qDqH5vJuz4ZgaL1HVpeAC9kJXnzamMpvMJfr3kE");
 
@@ -32,20 +31,38 @@ pub mod autocrat {
     use super::*;
 
     // ==== dao
-    pub fn initialize_dao(ctx: Context<InitializeDao>) -> Result<()> {
-        instructions::dao::initialize::handler(ctx)
+    pub fn initialize_dao(
+        ctx: Context<InitializeDao>,
+        dao_params: InitializeDaoParams,
+    ) -> Result<()> {
+        instructions::dao::initialize::handler(ctx, dao_params)
     }
 
     pub fn update_dao(ctx: Context<UpdateDao>, dao_params: UpdateDaoParams) -> Result<()> {
         instructions::dao::update::handler(ctx, dao_params)
     }
 
+    pub fn update_instruction_policy(
+        ctx: Context<UpdateInstructionPolicy>,
+        is_enabled: bool,
+        rules: Vec<InstructionPolicyRule>,
+    ) -> Result<()> {
+        instructions::dao::update_instruction_policy::handler(ctx, is_enabled, rules)
+    }
+
     // ==== autocrat
     pub fn create_proposal_instructions(
         ctx: Context<CreateProposalInstructions>,
+        outcome_index: u8,
         instructions: Vec<ProposalInstruction>,
+        lookup_tables: Vec<Pubkey>,
     ) -> Result<()> {
-        instructions::autocrat::create_proposal_instructions::handler(ctx, instructions)
+        instructions::autocrat::create_proposal_instructions::handler(
+            ctx,
+            outcome_index,
+            instructions,
+            lookup_tables,
+        )
     }
 
     pub fn add_proposal_instructions(
@@ -55,60 +72,133 @@ pub mod autocrat {
         instructions::autocrat::add_proposal_instructions::handler(ctx, instructions)
     }
 
+    pub fn close_proposal_instructions(ctx: Context<CloseProposalInstructions>) -> Result<()> {
+        instructions::autocrat::close_proposal_instructions::handler(ctx)
+    }
+
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         description_url: String,
         mint_cond_meta: u64,
         mint_cond_usdc: u64,
+        outcome_count: u8,
+        scalar: Option<ScalarRange>,
+        prerequisites: Vec<u64>,
     ) -> Result<()> {
         instructions::autocrat::create_proposal::handler(
             ctx,
             description_url,
             mint_cond_meta,
             mint_cond_usdc,
+            outcome_count,
+            scalar,
+            prerequisites,
         )
     }
 
     pub fn create_proposal_market_side(
         ctx: Context<CreateProposalMarketSide>,
-        is_pass_market: bool,
+        outcome_index: u8,
         amm_cond_meta_deposit: u64,
         amm_cond_usdc_deposit: u64,
     ) -> Result<()> {
         instructions::autocrat::create_proposal_market_side::handler(
             ctx,
-            is_pass_market,
+            outcome_index,
             amm_cond_meta_deposit,
             amm_cond_usdc_deposit,
         )
     }
 
-    pub fn submit_proposal(ctx: Context<SubmitProposal>) -> Result<()> {
+    pub fn submit_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitProposal<'info>>,
+    ) -> Result<()> {
         instructions::autocrat::submit_proposal::handler(ctx)
     }
 
-    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+    pub fn cancel_proposal_market_side(
+        ctx: Context<CancelProposalMarketSide>,
+        outcome_index: u8,
+    ) -> Result<()> {
+        instructions::autocrat::cancel_proposal_market_side::handler(ctx, outcome_index)
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::autocrat::cancel_proposal::handler(ctx)
+    }
+
+    pub fn finalize_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeProposal<'info>>,
+    ) -> Result<()> {
         instructions::autocrat::finalize_proposal::handler(ctx)
     }
 
-    pub fn mint_conditional_tokens(
-        ctx: Context<MintConditionalTokens>,
-        meta_amount: u64,
-        usdc_amount: u64,
+    pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+        instructions::autocrat::veto_proposal::handler(ctx)
+    }
+
+    pub fn execute_proposal(
+        ctx: Context<ExecuteProposal>,
+        instruction_index: u64,
+        instruction_count: u64,
     ) -> Result<()> {
-        instructions::autocrat::mint_conditional_tokens::handler(ctx, meta_amount, usdc_amount)
+        instructions::autocrat::execute_proposal::handler(ctx, instruction_index, instruction_count)
+    }
+
+    pub fn close_proposal_amm_position(ctx: Context<CloseProposalAmmPosition>) -> Result<()> {
+        instructions::autocrat::close_proposal_amm_position::handler(ctx)
     }
 
-    pub fn merge_conditional_tokens(
-        ctx: Context<MergeConditionalTokens>,
-        meta_amount: u64,
-        usdc_amount: u64,
+    pub fn close_proposal_market_side(
+        ctx: Context<CloseProposalMarketSide>,
+        outcome_index: u8,
     ) -> Result<()> {
-        instructions::autocrat::merge_conditional_tokens::handler(ctx, meta_amount, usdc_amount)
+        instructions::autocrat::close_proposal_market_side::handler(ctx, outcome_index)
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::autocrat::close_proposal::handler(ctx)
     }
 
-    pub fn redeem_conditional_tokens(ctx: Context<RedeemConditionalTokens>) -> Result<()> {
-        instructions::autocrat::redeem_conditional_tokens::handler(ctx)
+    // ==== conditional vault
+    pub fn initialize_conditional_vault(ctx: Context<InitializeConditionalVault>) -> Result<()> {
+        instructions::conditional_vault::initialize_conditional_vault::handler(ctx)
+    }
+
+    pub fn create_conditional_vault_mint(
+        ctx: Context<CreateConditionalVaultMint>,
+        outcome_index: u8,
+    ) -> Result<()> {
+        instructions::conditional_vault::create_conditional_vault_mint::handler(ctx, outcome_index)
+    }
+
+    pub fn mint_conditional_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintConditionalTokens<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::conditional_vault::mint_conditional_tokens::handler(ctx, amount)
+    }
+
+    pub fn merge_conditional_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, MergeConditionalTokens<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::conditional_vault::merge_conditional_tokens::handler(ctx, amount)
+    }
+
+    pub fn redeem_conditional_tokens(
+        ctx: Context<RedeemConditionalTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::conditional_vault::redeem_conditional_tokens::handler(ctx, amount)
+    }
+
+    pub fn settle_conditional_vault(ctx: Context<SettleConditionalVault>) -> Result<()> {
+        instructions::conditional_vault::settle_conditional_vault::handler(ctx)
+    }
+
+    pub fn close_conditional_vault(ctx: Context<CloseConditionalVault>) -> Result<()> {
+        instructions::conditional_vault::close_conditional_vault::handler(ctx)
     }
 
     // ==== amm cpi
@@ -144,4 +234,13 @@ pub mod autocrat {
     ) -> Result<()> {
         instructions::amm_cpi::swap::handler(ctx, is_quote_to_base, input_amount, output_amount_min)
     }
+
+    pub fn mint_and_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintAndSwap<'info>>,
+        outcome_index: u8,
+        amount: u64,
+        output_amount_min: u64,
+    ) -> Result<()> {
+        instructions::amm_cpi::mint_and_swap::handler(ctx, outcome_index, amount, output_amount_min)
+    }
 }
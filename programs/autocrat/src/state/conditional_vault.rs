@@ -0,0 +1,574 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Ids;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, InitSpace)]
+pub enum VaultStatus {
+    Active,
+    // only the winning outcome's conditional tokens can be redeemed
+    Finalized { winning_outcome_index: u8 },
+    // the proposal was cancelled, conditional tokens can only be merged back
+    Reverted,
+}
+
+// holds the underlying tokens of one mint for a proposal, and issues a
+// conditional token of every outcome for each underlying token deposited
+#[account]
+#[derive(InitSpace)]
+pub struct ConditionalVault {
+    pub bump: u8,
+    // the proposal whose outcome the vault is settled by
+    pub settlement_authority: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_account: Pubkey,
+    pub status: VaultStatus,
+
+    // one per outcome of the proposal, `Pubkey::default()` until created
+    #[max_len(MAX_PROPOSAL_OUTCOMES)]
+    pub conditional_mints: Vec<Pubkey>,
+}
+
+impl ConditionalVault {
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        proposal: &Proposal,
+        proposal_key: Pubkey,
+        underlying_mint: Pubkey,
+        underlying_token_account: Pubkey,
+    ) {
+        self.bump = bump;
+        self.settlement_authority = proposal_key;
+        self.underlying_mint = underlying_mint;
+        self.underlying_token_account = underlying_token_account;
+        self.status = VaultStatus::Active;
+        self.conditional_mints = vec![Pubkey::default(); proposal.outcomes.len()];
+    }
+
+    pub fn set_conditional_mint(&mut self, outcome_index: u8, mint: Pubkey) -> Result<()> {
+        let conditional_mint = self
+            .conditional_mints
+            .get_mut(outcome_index as usize)
+            .ok_or(ErrorCode::InvalidOutcomeIndex)?;
+
+        require_keys_eq!(
+            *conditional_mint,
+            Pubkey::default(),
+            ErrorCode::InvalidConditionalTokenMint
+        );
+
+        *conditional_mint = mint;
+
+        Ok(())
+    }
+
+    pub fn settle(&mut self, proposal: &Proposal) -> Result<()> {
+        require!(
+            self.status == VaultStatus::Active,
+            ErrorCode::VaultAlreadySettled
+        );
+
+        self.status = if proposal.state.is_finalized() {
+            VaultStatus::Finalized {
+                winning_outcome_index: proposal.winning_outcome_index,
+            }
+        } else if proposal.state == ProposalState::Cancelled {
+            VaultStatus::Reverted
+        } else {
+            return err!(ErrorCode::ProposalNotFinalized);
+        };
+
+        Ok(())
+    }
+
+    // only the winning outcome's conditional tokens can be redeemed, one for one
+    // against the underlying tokens. Each redemption takes the same amount out
+    // of the winning supply as out of the vault, so the vault always holds at
+    // least the winning supply.
+    pub fn validate_redemption(
+        &self,
+        conditional_mint: &Pubkey,
+        amount: u64,
+        conditional_balance: u64,
+    ) -> Result<()> {
+        let VaultStatus::Finalized {
+            winning_outcome_index,
+        } = self.status
+        else {
+            return err!(ErrorCode::CantRedeemConditionalTokens);
+        };
+
+        require!(
+            self.conditional_mints.get(winning_outcome_index as usize) == Some(conditional_mint),
+            ErrorCode::InvalidConditionalTokenMint
+        );
+
+        require!(
+            amount <= conditional_balance,
+            ErrorCode::InsufficientConditionalTokens
+        );
+
+        Ok(())
+    }
+
+    // a user's conditional token accounts for every outcome, passed as remaining
+    // accounts in outcome order as: [conditional_mint, conditional_user_token_account]
+    pub fn get_conditional_token_accounts<'a, 'info>(
+        &self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Vec<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)>> {
+        require_eq!(
+            remaining_accounts.len(),
+            self.conditional_mints.len() * 2,
+            ErrorCode::InvalidOutcomeAccounts
+        );
+
+        self.conditional_mints
+            .iter()
+            .zip(remaining_accounts.chunks_exact(2))
+            .map(|(conditional_mint, accounts)| {
+                require!(
+                    *conditional_mint != Pubkey::default() && accounts[0].key == conditional_mint,
+                    ErrorCode::InvalidConditionalTokenMint
+                );
+
+                Ok((&accounts[0], &accounts[1]))
+            })
+            .collect()
+    }
+
+    // the supply of every conditional mint that can still be a claim on the vault,
+    // passed as remaining accounts: the winning outcome's mint once finalized, or
+    // every mint in outcome order once reverted. Mints that were never created
+    // have no supply, and are left out.
+    pub fn get_outstanding_supply(&self, remaining_accounts: &[AccountInfo]) -> Result<u64> {
+        let claim_mints: Vec<Pubkey> = match self.status {
+            VaultStatus::Active => return err!(ErrorCode::ConditionalVaultNotSettled),
+            VaultStatus::Finalized {
+                winning_outcome_index,
+            } => self
+                .conditional_mints
+                .get(winning_outcome_index as usize)
+                .into_iter()
+                .copied()
+                .filter(|mint| *mint != Pubkey::default())
+                .collect(),
+            VaultStatus::Reverted => self
+                .conditional_mints
+                .iter()
+                .copied()
+                .filter(|mint| *mint != Pubkey::default())
+                .collect(),
+        };
+
+        require_eq!(
+            remaining_accounts.len(),
+            claim_mints.len(),
+            ErrorCode::InvalidOutcomeAccounts
+        );
+
+        let mut supply = 0u64;
+
+        for (mint, info) in claim_mints.iter().zip(remaining_accounts.iter()) {
+            require_keys_eq!(*info.key, *mint, ErrorCode::InvalidConditionalTokenMint);
+            require!(
+                TokenInterface::ids().contains(info.owner),
+                ErrorCode::InvalidOutcomeAccounts
+            );
+
+            let mint = Mint::try_deserialize(&mut info.data.borrow().as_ref())?;
+            supply = supply
+                .checked_add(mint.supply)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        Ok(supply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::conditional_vault::*;
+    use crate::utils::save_proposal;
+    use crate::utils::test_utils::*;
+    use anchor_lang::solana_program::system_program;
+    use anchor_spl::token;
+    use anchor_spl::token_interface::TokenAccount;
+
+    fn vault(status: VaultStatus, mints: &[&TestAccount]) -> ConditionalVault {
+        ConditionalVault {
+            bump: 255,
+            settlement_authority: Pubkey::new_unique(),
+            underlying_mint: Pubkey::new_unique(),
+            underlying_token_account: Pubkey::new_unique(),
+            status,
+            conditional_mints: mints.iter().map(|mint| mint.key).collect(),
+        }
+    }
+
+    #[test]
+    fn unredeemed_losing_tokens_dont_count_as_outstanding() {
+        let (mut status_quo, mut winner, mut loser) = (
+            TestAccount::mint(0),
+            TestAccount::mint(0),
+            TestAccount::mint(1_000),
+        );
+        let vault = vault(
+            VaultStatus::Finalized {
+                winning_outcome_index: 1,
+            },
+            &[&status_quo, &winner, &loser],
+        );
+
+        assert_eq!(vault.get_outstanding_supply(&[winner.info()]), Ok(0));
+
+        // the winning mint is the only claim, so it's the only one accepted
+        assert_eq!(
+            vault.get_outstanding_supply(&[loser.info()]),
+            err!(ErrorCode::InvalidConditionalTokenMint)
+        );
+        assert_eq!(
+            vault.get_outstanding_supply(&[winner.info(), status_quo.info()]),
+            err!(ErrorCode::InvalidOutcomeAccounts)
+        );
+    }
+
+    #[test]
+    fn unredeemed_winning_tokens_are_outstanding() {
+        let (status_quo, mut winner) = (TestAccount::mint(0), TestAccount::mint(7));
+        let vault = vault(
+            VaultStatus::Finalized {
+                winning_outcome_index: 1,
+            },
+            &[&status_quo, &winner],
+        );
+
+        assert_eq!(vault.get_outstanding_supply(&[winner.info()]), Ok(7));
+    }
+
+    #[test]
+    fn reverted_vault_counts_every_outcome() {
+        let mut mints = [TestAccount::mint(3), TestAccount::mint(0)];
+        let vault = vault(VaultStatus::Reverted, &[&mints[0], &mints[1]]);
+
+        assert_eq!(vault.get_outstanding_supply(&infos(&mut mints)), Ok(3));
+    }
+
+    #[test]
+    fn unsettled_vault_cant_be_closed() {
+        let mut mints = [TestAccount::mint(0), TestAccount::mint(0)];
+        let vault = vault(VaultStatus::Active, &[&mints[0], &mints[1]]);
+
+        assert_eq!(
+            vault.get_outstanding_supply(&infos(&mut mints)),
+            err!(ErrorCode::ConditionalVaultNotSettled)
+        );
+    }
+
+    #[test]
+    fn an_uncreated_winning_mint_has_no_outstanding_supply() {
+        let (mut status_quo, mut loser) = (TestAccount::mint(0), TestAccount::mint(1_000));
+        let mut vault = vault(
+            VaultStatus::Finalized {
+                winning_outcome_index: 1,
+            },
+            &[&status_quo, &loser, &loser],
+        );
+        vault.conditional_mints[1] = Pubkey::default();
+
+        // so the vault can be closed without any mint being passed
+        assert_eq!(vault.get_outstanding_supply(&[]), Ok(0));
+        assert_eq!(
+            vault.get_outstanding_supply(&[loser.info()]),
+            err!(ErrorCode::InvalidOutcomeAccounts)
+        );
+        assert_eq!(
+            vault.get_outstanding_supply(&[status_quo.info()]),
+            err!(ErrorCode::InvalidOutcomeAccounts)
+        );
+    }
+
+    #[test]
+    fn only_settled_winning_tokens_can_be_redeemed() {
+        let mints = [(); 3].map(|_| TestAccount::mint(0));
+        let mut vault = vault(VaultStatus::Active, &[&mints[0], &mints[1], &mints[2]]);
+
+        assert_eq!(
+            vault.validate_redemption(&mints[1].key, 1, 1),
+            err!(ErrorCode::CantRedeemConditionalTokens)
+        );
+
+        vault.status = VaultStatus::Reverted;
+
+        assert_eq!(
+            vault.validate_redemption(&mints[1].key, 1, 1),
+            err!(ErrorCode::CantRedeemConditionalTokens)
+        );
+
+        vault.status = VaultStatus::Finalized {
+            winning_outcome_index: 1,
+        };
+
+        assert_eq!(vault.validate_redemption(&mints[1].key, 1, 1), Ok(()));
+        assert_eq!(
+            vault.validate_redemption(&mints[2].key, 1, 1),
+            err!(ErrorCode::InvalidConditionalTokenMint)
+        );
+        assert_eq!(
+            vault.validate_redemption(&mints[1].key, 2, 1),
+            err!(ErrorCode::InsufficientConditionalTokens)
+        );
+    }
+
+    // a user's underlying token account and conditional token accounts
+    struct TestUser {
+        user: AccountInfo<'static>,
+        underlying_token_account: AccountInfo<'static>,
+        conditional_token_accounts: Vec<AccountInfo<'static>>,
+    }
+
+    impl TestUser {
+        fn new(vault: &TestVault, underlying_amount: u64) -> Self {
+            let user = Pubkey::new_unique();
+
+            Self {
+                user: leak_info(
+                    TestAccount::new(system_program::ID, Vec::new())
+                        .with_key(user)
+                        .signer()
+                        .writable(),
+                ),
+                underlying_token_account: leak_info(
+                    TestAccount::token_account(vault.underlying_mint.key, &user, underlying_amount)
+                        .writable(),
+                ),
+                conditional_token_accounts: vault
+                    .conditional_mints
+                    .iter()
+                    .map(|mint| {
+                        leak_info(TestAccount::token_account(mint.key, &user, 0).writable())
+                    })
+                    .collect(),
+            }
+        }
+
+        fn balance(&self, outcome_index: usize) -> u64 {
+            read::<TokenAccount>(&self.conditional_token_accounts[outcome_index]).amount
+        }
+
+        fn underlying_balance(&self) -> u64 {
+            read::<TokenAccount>(&self.underlying_token_account).amount
+        }
+
+        // the accounts that minting and merging share, followed by the remaining
+        // accounts of every outcome
+        fn mint_or_merge_accounts(
+            &self,
+            accounts: &TestProposalAccounts,
+            vault: &TestVault,
+            with_proposal: bool,
+        ) -> Vec<AccountInfo<'static>> {
+            [self.user.clone()]
+                .into_iter()
+                .chain(with_proposal.then(|| accounts.proposal.clone()))
+                .chain([
+                    vault.vault.clone(),
+                    vault.underlying_mint.clone(),
+                    vault.underlying_token_account.clone(),
+                    self.underlying_token_account.clone(),
+                    accounts.token_program.clone(),
+                ])
+                .chain(
+                    vault
+                        .conditional_mints
+                        .iter()
+                        .zip(&self.conditional_token_accounts)
+                        .flat_map(|(mint, account)| [mint.clone(), account.clone()]),
+                )
+                .collect()
+        }
+
+        fn mint(
+            &self,
+            accounts: &TestProposalAccounts,
+            vault: &TestVault,
+            amount: u64,
+        ) -> Result<()> {
+            let mint_accounts = self.mint_or_merge_accounts(accounts, vault, true);
+
+            with_token_program(|| {
+                run_instruction::<MintConditionalTokens>(&mint_accounts, &[], |ctx| {
+                    mint_conditional_tokens::handler(ctx, amount)
+                })
+            })
+        }
+
+        fn merge(
+            &self,
+            accounts: &TestProposalAccounts,
+            vault: &TestVault,
+            amount: u64,
+        ) -> Result<()> {
+            let merge_accounts = self.mint_or_merge_accounts(accounts, vault, false);
+
+            with_token_program(|| {
+                run_instruction::<MergeConditionalTokens>(&merge_accounts, &[], |ctx| {
+                    merge_conditional_tokens::handler(ctx, amount)
+                })
+            })
+        }
+
+        fn redeem(
+            &self,
+            accounts: &TestProposalAccounts,
+            vault: &TestVault,
+            outcome_index: usize,
+            amount: u64,
+        ) -> Result<()> {
+            let redeem_accounts = [
+                self.user.clone(),
+                vault.vault.clone(),
+                vault.underlying_mint.clone(),
+                vault.underlying_token_account.clone(),
+                self.underlying_token_account.clone(),
+                vault.conditional_mints[outcome_index].clone(),
+                self.conditional_token_accounts[outcome_index].clone(),
+                accounts.token_program.clone(),
+            ];
+
+            with_token_program(|| {
+                run_instruction::<RedeemConditionalTokens>(&redeem_accounts, &[], |ctx| {
+                    redeem_conditional_tokens::handler(ctx, amount)
+                })
+            })
+        }
+
+        fn transfer(
+            &self,
+            accounts: &TestProposalAccounts,
+            outcome_index: usize,
+            to: &TestUser,
+            amount: u64,
+        ) -> Result<()> {
+            with_token_program(|| {
+                token::transfer(
+                    CpiContext::new(
+                        accounts.token_program.clone(),
+                        token::Transfer {
+                            from: self.conditional_token_accounts[outcome_index].clone(),
+                            to: to.conditional_token_accounts[outcome_index].clone(),
+                            authority: self.user.clone(),
+                        },
+                    ),
+                    amount,
+                )
+            })
+        }
+    }
+
+    // the vault's underlying tokens back every conditional token of each outcome
+    // while it's active, and at least every winning conditional token once it's
+    // been finalized
+    fn assert_solvent(vault: &TestVault) {
+        let vault_balance = read::<TokenAccount>(&vault.underlying_token_account).amount;
+        let supplies: Vec<u64> = vault
+            .conditional_mints
+            .iter()
+            .map(|mint| read::<Mint>(mint).supply)
+            .collect();
+
+        match read::<ConditionalVault>(&vault.vault).status {
+            VaultStatus::Active => {
+                assert!(supplies.iter().all(|supply| *supply == vault_balance))
+            }
+            VaultStatus::Finalized {
+                winning_outcome_index,
+            } => assert!(vault_balance >= supplies[winning_outcome_index as usize]),
+            VaultStatus::Reverted => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn redemptions_never_pay_out_more_than_the_vault_holds() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 3);
+        let accounts = test_proposal_accounts(proposal.clone());
+        let vault = &accounts.usdc_vault;
+        let users = [(); 3].map(|_| TestUser::new(vault, 1_000));
+        let winning_outcome_index = 2;
+
+        for (user, amount) in users.iter().zip([1_000, 500, 250]) {
+            user.mint(&accounts, vault, amount).unwrap();
+            assert_solvent(vault);
+        }
+
+        // no more than the user holds of every outcome can be merged
+        assert!(users[1].merge(&accounts, vault, 501).is_err());
+        users[1].merge(&accounts, vault, 200).unwrap();
+        assert_solvent(vault);
+
+        // winning tokens traded between users
+        users[0]
+            .transfer(&accounts, winning_outcome_index, &users[2], 400)
+            .unwrap();
+
+        assert_eq!(
+            users[2].redeem(&accounts, vault, winning_outcome_index, 1),
+            err!(ErrorCode::CantRedeemConditionalTokens)
+        );
+
+        proposal.state = ProposalState::Passed;
+        proposal.winning_outcome_index = winning_outcome_index as u8;
+        save_proposal(&accounts.proposal, &proposal).unwrap();
+        run_instruction::<SettleConditionalVault>(
+            &[accounts.proposal.clone(), vault.vault.clone()],
+            &[],
+            settle_conditional_vault::handler,
+        )
+        .unwrap();
+        assert_solvent(vault);
+
+        assert_eq!(
+            users[0].mint(&accounts, vault, 1),
+            err!(ErrorCode::ProposalNotPending)
+        );
+
+        for user in &users {
+            let balance = user.balance(winning_outcome_index);
+
+            // more than the user holds, or the losing tokens they hold
+            assert_eq!(
+                user.redeem(&accounts, vault, winning_outcome_index, balance + 1),
+                err!(ErrorCode::InsufficientConditionalTokens)
+            );
+            assert_eq!(
+                user.redeem(&accounts, vault, 0, 1),
+                err!(ErrorCode::InvalidConditionalTokenMint)
+            );
+
+            for amount in [balance / 3, balance - balance / 3] {
+                user.redeem(&accounts, vault, winning_outcome_index, amount)
+                    .unwrap();
+                assert_solvent(vault);
+            }
+        }
+
+        // everything deposited and not merged has been redeemed, and nothing more
+        assert_eq!(
+            read::<TokenAccount>(&vault.underlying_token_account).amount,
+            0
+        );
+        assert_eq!(
+            users.iter().map(TestUser::underlying_balance).sum::<u64>(),
+            3_000
+        );
+        assert_eq!(
+            read::<Mint>(&vault.conditional_mints[winning_outcome_index]).supply,
+            0
+        );
+    }
+}
@@ -1,28 +1,582 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+
 #[account]
 pub struct Dao {
+    pub bump: u8,
+
     // treasury needed even though DAO is PDA for this reason: https://solana.stackexchange.com/questions/7667/a-peculiar-problem-with-cpis
     pub treasury_pda_bump: u8,
     pub treasury_pda: Pubkey,
 
+    // DAOs are namespaced by whoever initialized them, so that creating a DAO
+    // for a governance mint doesn't stop anyone else from creating their own
+    pub creator: Pubkey,
+
     pub meta_mint: Pubkey,
     pub usdc_mint: Pubkey,
 
+    pub meta_mint_decimals: u8,
+    pub usdc_mint_decimals: u8,
+
+    // the tickers conditional token symbols are derived from, zero-padded
+    pub meta_ticker: [u8; MAX_TICKER_LEN],
+    pub usdc_ticker: [u8; MAX_TICKER_LEN],
+
     pub proposal_count: u64,
     pub proposals_active: u32,
+    pub last_proposal_slot: u64,
+
+    // proposals submitted while `max_active_proposals` are trading wait in a
+    // FIFO queue, identified by their `queue_position`. `queue_head` is the
+    // position of the next proposal to start trading, `queue_tail` the position
+    // the next queued proposal gets
+    pub queue_head: u64,
+    pub queue_tail: u64,
+
+    // can veto passed proposals before they become executable, `Pubkey::default()` if none
+    pub guardian: Pubkey,
+
+    pub config: DaoConfig,
+}
 
+// every parameter that governance can change, always validated as a whole
+// before being written to the `Dao`
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct DaoConfig {
+    // the minimum fee, which `proposal_fee_curve` raises depending on activity
     pub proposal_fee_usdc: u64,
+    pub proposal_fee_curve: ProposalFeeCurve,
+    // paid out of the proposal fee to whoever finalizes the proposal
+    pub finalizer_bounty_usdc: u64,
+    // the share of the rest of the fee that's refunded to the proposer if the
+    // proposal passes, the remainder goes to the treasury
+    pub proposal_fee_refund_bps: u64,
 
     // the percentage, in basis points, the pass price needs to be above the
     // fail price in order for the proposal to pass
     pub pass_threshold_bps: u64,
 
     pub proposal_duration_slots: u64,
+    pub max_active_proposals: u32,
     pub finalize_window_slots: u64,
+    pub execution_window_slots: u64,
+    pub execution_delay_slots: u64,
 
     // amm
     pub amm_initial_quote_liquidity_amount: u64, // amount of quote liquidity to be deposited per market by the proposer
     pub amm_swap_fee_bps: u64,
     pub amm_ltwap_decimals: u8,
 }
+
+// how the proposal fee grows with the DAO's proposal activity. Every curve
+// charges at least `proposal_fee_usdc`.
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub enum ProposalFeeCurve {
+    // the fee goes up by `increment_usdc` for every active proposal
+    Linear {
+        increment_usdc: u64,
+    },
+    // the fee doubles for every active proposal, up to `max_fee_usdc`
+    Exponential {
+        max_fee_usdc: u64,
+    },
+    // the fee jumps to `peak_fee_usdc` on every submission and decays back by
+    // `decay_per_slot_usdc`, so it only limits how often proposals are submitted
+    TimeDecaying {
+        peak_fee_usdc: u64,
+        decay_per_slot_usdc: u64,
+    },
+}
+
+impl Dao {
+    pub fn meta_ticker(&self) -> &str {
+        decode_ticker(&self.meta_ticker)
+    }
+
+    pub fn usdc_ticker(&self) -> &str {
+        decode_ticker(&self.usdc_ticker)
+    }
+}
+
+// tickers are 1 to `MAX_TICKER_LEN` ASCII letters and digits, so that they
+// always fit in a metaplex symbol
+pub fn encode_ticker(ticker: &str) -> Result<[u8; MAX_TICKER_LEN]> {
+    require!(
+        !ticker.is_empty()
+            && ticker.len() <= MAX_TICKER_LEN
+            && ticker.bytes().all(|byte| byte.is_ascii_alphanumeric()),
+        ErrorCode::InvalidTicker
+    );
+
+    let mut encoded = [0; MAX_TICKER_LEN];
+    encoded[..ticker.len()].copy_from_slice(ticker.as_bytes());
+
+    Ok(encoded)
+}
+
+fn decode_ticker(ticker: &[u8; MAX_TICKER_LEN]) -> &str {
+    let len = ticker
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(MAX_TICKER_LEN);
+    // only ever written by `encode_ticker`
+    std::str::from_utf8(&ticker[..len]).unwrap()
+}
+
+impl ProposalFeeCurve {
+    pub fn fee(
+        &self,
+        proposal_fee_usdc: u64,
+        proposals_active: u32,
+        slots_since_last_proposal: u64,
+    ) -> Result<u64> {
+        match *self {
+            ProposalFeeCurve::Linear { increment_usdc } => increment_usdc
+                .checked_mul(proposals_active as u64)
+                .and_then(|increase| proposal_fee_usdc.checked_add(increase))
+                .ok_or(error!(ErrorCode::ProposalFeeOverflow)),
+            // past the cap, an overflow only means the cap applies
+            ProposalFeeCurve::Exponential { max_fee_usdc } => Ok(2u64
+                .checked_pow(proposals_active)
+                .and_then(|multiplier| proposal_fee_usdc.checked_mul(multiplier))
+                .map_or(max_fee_usdc, |fee| fee.min(max_fee_usdc))),
+            // likewise, an overflowing decay means the fee has fully decayed
+            ProposalFeeCurve::TimeDecaying {
+                peak_fee_usdc,
+                decay_per_slot_usdc,
+            } => Ok(decay_per_slot_usdc
+                .checked_mul(slots_since_last_proposal)
+                .map_or(0, |decay| peak_fee_usdc.saturating_sub(decay))
+                .max(proposal_fee_usdc)),
+        }
+    }
+
+    pub fn validate(&self, proposal_fee_usdc: u64) -> Result<()> {
+        let is_valid = match *self {
+            ProposalFeeCurve::Linear { .. } => true,
+            ProposalFeeCurve::Exponential { max_fee_usdc } => max_fee_usdc >= proposal_fee_usdc,
+            ProposalFeeCurve::TimeDecaying { peak_fee_usdc, .. } => {
+                peak_fee_usdc >= proposal_fee_usdc
+            }
+        };
+
+        require!(is_valid, ErrorCode::InvalidProposalFeeCurve);
+
+        Ok(())
+    }
+}
+
+impl DaoConfig {
+    // the default config, with quote-denominated amounts scaled by the quote mint's decimals
+    pub fn default_for_usdc_decimals(usdc_mint_decimals: u8) -> Result<Self> {
+        let usdc_decimal_scale = get_decimal_scale_u64(usdc_mint_decimals)?;
+        let proposal_fee_usdc = PROPOSAL_FEE_USDC
+            .checked_mul(usdc_decimal_scale)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        Ok(Self {
+            proposal_fee_usdc,
+            proposal_fee_curve: ProposalFeeCurve::Exponential {
+                max_fee_usdc: proposal_fee_usdc.saturating_mul(DEFAULT_PROPOSAL_FEE_MAX_MULTIPLIER),
+            },
+            finalizer_bounty_usdc: FINALIZER_BOUNTY_USDC
+                .checked_mul(usdc_decimal_scale)
+                .ok_or(ErrorCode::ArithmeticOverflow)?,
+            proposal_fee_refund_bps: DEFAULT_PROPOSAL_FEE_REFUND_BPS,
+            pass_threshold_bps: DEFAULT_PASS_THRESHOLD_BPS,
+            proposal_duration_slots: PROPOSAL_DURATION_SLOTS,
+            max_active_proposals: DEFAULT_MAX_ACTIVE_PROPOSALS,
+            finalize_window_slots: FINALIZE_WINDOW_SLOTS,
+            execution_window_slots: EXECUTION_WINDOW_SLOTS,
+            execution_delay_slots: EXECUTION_DELAY_SLOTS,
+            amm_initial_quote_liquidity_amount: AMM_INITIAL_QUOTE_LIQUIDITY
+                .checked_mul(usdc_decimal_scale)
+                .ok_or(ErrorCode::ArithmeticOverflow)?,
+            amm_swap_fee_bps: AMM_SWAP_FEE_BPS,
+            amm_ltwap_decimals: AMM_LTWAP_DECIMALS,
+        })
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.proposal_fee_curve.validate(self.proposal_fee_usdc)?;
+
+        // the bounty is funded by the proposal fee, so it can't be more than it
+        require!(
+            self.finalizer_bounty_usdc <= self.proposal_fee_usdc,
+            ErrorCode::InvalidFinalizerBountyUsdc
+        );
+
+        require!(
+            self.proposal_fee_refund_bps <= BPS_SCALE,
+            ErrorCode::InvalidProposalFeeRefundBps
+        );
+
+        require!(
+            self.pass_threshold_bps <= MAX_PASS_THRESHOLD_BPS,
+            ErrorCode::InvalidPassThresholdBps
+        );
+
+        require!(
+            self.proposal_duration_slots >= MIN_PROPOSAL_DURATION_SLOTS
+                && self.proposal_duration_slots <= MAX_PROPOSAL_DURATION_SLOTS,
+            ErrorCode::InvalidProposalDurationSlots
+        );
+
+        require!(
+            self.max_active_proposals > 0,
+            ErrorCode::InvalidMaxActiveProposals
+        );
+
+        require!(
+            self.finalize_window_slots >= MIN_FINALIZE_WINDOW_SLOTS
+                && self.finalize_window_slots <= MAX_FINALIZE_WINDOW_SLOTS,
+            ErrorCode::InvalidFinalizeWindowSlots
+        );
+
+        require!(
+            self.execution_window_slots >= MIN_EXECUTION_WINDOW_SLOTS
+                && self.execution_window_slots <= MAX_EXECUTION_WINDOW_SLOTS,
+            ErrorCode::InvalidExecutionWindowSlots
+        );
+
+        require!(
+            self.execution_delay_slots <= MAX_EXECUTION_DELAY_SLOTS,
+            ErrorCode::InvalidExecutionDelaySlots
+        );
+
+        require!(
+            self.amm_initial_quote_liquidity_amount > 0,
+            ErrorCode::InvalidAmmInitialQuoteLiquidityAmount
+        );
+
+        require!(
+            self.amm_swap_fee_bps >= AMM_SWAP_FEE_BPS_MIN
+                && self.amm_swap_fee_bps <= AMM_SWAP_FEE_BPS_MAX,
+            ErrorCode::InvalidAmmSwapFeeBps
+        );
+
+        require!(
+            self.amm_ltwap_decimals <= MAX_DECIMALS,
+            ErrorCode::InvalidAmmLtwapDecimals
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    #[test]
+    fn linear_fee_grows_by_the_increment() {
+        let curve = ProposalFeeCurve::Linear { increment_usdc: 10 };
+
+        assert_eq!(curve.fee(100, 0, 0), Ok(100));
+        assert_eq!(curve.fee(100, 3, 0), Ok(130));
+        assert_eq!(curve.fee(100, u32::MAX, 0), Ok(100 + 10 * u32::MAX as u64));
+    }
+
+    #[test]
+    fn linear_fee_overflow_is_an_error() {
+        let curve = ProposalFeeCurve::Linear {
+            increment_usdc: u64::MAX / 2,
+        };
+
+        assert_eq!(curve.fee(100, 2, 0), err!(ErrorCode::ProposalFeeOverflow));
+        assert_eq!(
+            curve.fee(100, u32::MAX, 0),
+            err!(ErrorCode::ProposalFeeOverflow)
+        );
+    }
+
+    #[test]
+    fn exponential_fee_doubles_up_to_the_cap() {
+        let curve = ProposalFeeCurve::Exponential { max_fee_usdc: 1000 };
+
+        assert_eq!(curve.fee(100, 0, 0), Ok(100));
+        assert_eq!(curve.fee(100, 1, 0), Ok(200));
+        assert_eq!(curve.fee(100, 3, 0), Ok(800));
+        assert_eq!(curve.fee(100, 4, 0), Ok(1000));
+    }
+
+    #[test]
+    fn exponential_fee_overflow_is_capped() {
+        let curve = ProposalFeeCurve::Exponential {
+            max_fee_usdc: u64::MAX,
+        };
+
+        // the multiplication overflows before the power does, and then both do
+        assert_eq!(curve.fee(u64::MAX / 2, 2, 0), Ok(u64::MAX));
+        assert_eq!(curve.fee(100, 64, 0), Ok(u64::MAX));
+        assert_eq!(curve.fee(100, u32::MAX, 0), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn time_decaying_fee_decays_to_its_floor() {
+        let curve = ProposalFeeCurve::TimeDecaying {
+            peak_fee_usdc: 1000,
+            decay_per_slot_usdc: 10,
+        };
+
+        // proposals active don't matter, only the time since the last one
+        assert_eq!(curve.fee(100, 0, 0), Ok(1000));
+        assert_eq!(curve.fee(100, u32::MAX, 0), Ok(1000));
+        assert_eq!(curve.fee(100, 0, 45), Ok(550));
+        assert_eq!(curve.fee(100, 0, 90), Ok(100));
+        assert_eq!(curve.fee(100, 0, 91), Ok(100));
+        assert_eq!(curve.fee(100, 0, u64::MAX), Ok(100));
+    }
+
+    #[test]
+    fn fee_curves_cant_charge_less_than_the_base_fee() {
+        assert_eq!(
+            ProposalFeeCurve::Exponential { max_fee_usdc: 100 }.validate(100),
+            Ok(())
+        );
+        assert_eq!(
+            ProposalFeeCurve::Exponential { max_fee_usdc: 99 }.validate(100),
+            err!(ErrorCode::InvalidProposalFeeCurve)
+        );
+        assert_eq!(
+            ProposalFeeCurve::TimeDecaying {
+                peak_fee_usdc: 99,
+                decay_per_slot_usdc: 1,
+            }
+            .validate(100),
+            err!(ErrorCode::InvalidProposalFeeCurve)
+        );
+    }
+
+    #[test]
+    fn tickers_round_trip() {
+        let mut dao = test_dao();
+        dao.meta_ticker = encode_ticker("MTN").unwrap();
+        dao.usdc_ticker = encode_ticker("USDCUSDC").unwrap();
+
+        assert_eq!(dao.meta_ticker(), "MTN");
+        assert_eq!(dao.usdc_ticker(), "USDCUSDC");
+    }
+
+    #[test]
+    fn tickers_must_be_short_and_alphanumeric() {
+        for ticker in ["", "USDCUSDCX", "US DC", "pMETA-1", "MÉTA"] {
+            assert_eq!(
+                encode_ticker(ticker).map(|_| ()),
+                err!(ErrorCode::InvalidTicker)
+            );
+        }
+    }
+
+    // the default config with `update` applied, validated
+    fn validate_with(update: impl FnOnce(&mut DaoConfig)) -> Result<()> {
+        let mut config = DaoConfig::default_for_usdc_decimals(6).unwrap();
+        update(&mut config);
+        config.validate()
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        for decimals in 0..=MAX_DECIMALS {
+            assert_eq!(
+                DaoConfig::default_for_usdc_decimals(decimals)
+                    .unwrap()
+                    .validate(),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn finalizer_bounty_cant_be_more_than_the_proposal_fee() {
+        assert_eq!(
+            validate_with(|config| config.finalizer_bounty_usdc = config.proposal_fee_usdc),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.finalizer_bounty_usdc = config.proposal_fee_usdc + 1),
+            err!(ErrorCode::InvalidFinalizerBountyUsdc)
+        );
+    }
+
+    #[test]
+    fn proposal_fee_refund_cant_be_more_than_100_percent() {
+        assert_eq!(
+            validate_with(|config| config.proposal_fee_refund_bps = BPS_SCALE),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.proposal_fee_refund_bps = BPS_SCALE + 1),
+            err!(ErrorCode::InvalidProposalFeeRefundBps)
+        );
+    }
+
+    #[test]
+    fn pass_threshold_is_capped() {
+        assert_eq!(
+            validate_with(|config| config.pass_threshold_bps = 0),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.pass_threshold_bps = MAX_PASS_THRESHOLD_BPS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.pass_threshold_bps = MAX_PASS_THRESHOLD_BPS + 1),
+            err!(ErrorCode::InvalidPassThresholdBps)
+        );
+    }
+
+    #[test]
+    fn proposal_duration_is_bounded() {
+        assert_eq!(
+            validate_with(|config| config.proposal_duration_slots = MIN_PROPOSAL_DURATION_SLOTS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.proposal_duration_slots = MAX_PROPOSAL_DURATION_SLOTS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.proposal_duration_slots = MIN_PROPOSAL_DURATION_SLOTS - 1),
+            err!(ErrorCode::InvalidProposalDurationSlots)
+        );
+        assert_eq!(
+            validate_with(|config| config.proposal_duration_slots = MAX_PROPOSAL_DURATION_SLOTS + 1),
+            err!(ErrorCode::InvalidProposalDurationSlots)
+        );
+    }
+
+    #[test]
+    fn at_least_one_proposal_can_be_active() {
+        assert_eq!(
+            validate_with(|config| config.max_active_proposals = 1),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.max_active_proposals = u32::MAX),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.max_active_proposals = 0),
+            err!(ErrorCode::InvalidMaxActiveProposals)
+        );
+    }
+
+    #[test]
+    fn finalize_window_is_bounded() {
+        assert_eq!(
+            validate_with(|config| config.finalize_window_slots = MIN_FINALIZE_WINDOW_SLOTS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.finalize_window_slots = MAX_FINALIZE_WINDOW_SLOTS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.finalize_window_slots = MIN_FINALIZE_WINDOW_SLOTS - 1),
+            err!(ErrorCode::InvalidFinalizeWindowSlots)
+        );
+        assert_eq!(
+            validate_with(|config| config.finalize_window_slots = MAX_FINALIZE_WINDOW_SLOTS + 1),
+            err!(ErrorCode::InvalidFinalizeWindowSlots)
+        );
+    }
+
+    #[test]
+    fn execution_window_is_bounded() {
+        assert_eq!(
+            validate_with(|config| config.execution_window_slots = MIN_EXECUTION_WINDOW_SLOTS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.execution_window_slots = MAX_EXECUTION_WINDOW_SLOTS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.execution_window_slots = MIN_EXECUTION_WINDOW_SLOTS - 1),
+            err!(ErrorCode::InvalidExecutionWindowSlots)
+        );
+        assert_eq!(
+            validate_with(|config| config.execution_window_slots = MAX_EXECUTION_WINDOW_SLOTS + 1),
+            err!(ErrorCode::InvalidExecutionWindowSlots)
+        );
+    }
+
+    #[test]
+    fn execution_delay_is_capped() {
+        assert_eq!(
+            validate_with(|config| config.execution_delay_slots = 0),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.execution_delay_slots = MAX_EXECUTION_DELAY_SLOTS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.execution_delay_slots = MAX_EXECUTION_DELAY_SLOTS + 1),
+            err!(ErrorCode::InvalidExecutionDelaySlots)
+        );
+    }
+
+    #[test]
+    fn amm_initial_quote_liquidity_cant_be_zero() {
+        assert_eq!(
+            validate_with(|config| config.amm_initial_quote_liquidity_amount = 1),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.amm_initial_quote_liquidity_amount = 0),
+            err!(ErrorCode::InvalidAmmInitialQuoteLiquidityAmount)
+        );
+    }
+
+    #[test]
+    fn amm_swap_fee_is_bounded() {
+        assert_eq!(
+            validate_with(|config| config.amm_swap_fee_bps = AMM_SWAP_FEE_BPS_MIN),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.amm_swap_fee_bps = AMM_SWAP_FEE_BPS_MAX),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.amm_swap_fee_bps = AMM_SWAP_FEE_BPS_MIN - 1),
+            err!(ErrorCode::InvalidAmmSwapFeeBps)
+        );
+        assert_eq!(
+            validate_with(|config| config.amm_swap_fee_bps = AMM_SWAP_FEE_BPS_MAX + 1),
+            err!(ErrorCode::InvalidAmmSwapFeeBps)
+        );
+    }
+
+    #[test]
+    fn amm_ltwap_decimals_are_capped() {
+        assert_eq!(
+            validate_with(|config| config.amm_ltwap_decimals = MAX_DECIMALS),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with(|config| config.amm_ltwap_decimals = MAX_DECIMALS + 1),
+            err!(ErrorCode::InvalidAmmLtwapDecimals)
+        );
+    }
+
+    #[test]
+    fn invalid_fee_curves_are_rejected() {
+        assert_eq!(
+            validate_with(
+                |config| config.proposal_fee_curve = ProposalFeeCurve::Exponential {
+                    max_fee_usdc: config.proposal_fee_usdc - 1
+                }
+            ),
+            err!(ErrorCode::InvalidProposalFeeCurve)
+        );
+    }
+}
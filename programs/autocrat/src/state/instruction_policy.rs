@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+// restricts what the instructions of a DAO's proposals can do, and can only be
+// changed through a passed proposal
+#[account]
+#[derive(InitSpace)]
+pub struct InstructionPolicy {
+    pub bump: u8,
+    pub dao: Pubkey,
+
+    // when disabled, proposals can target any program
+    pub is_enabled: bool,
+
+    #[max_len(MAX_INSTRUCTION_POLICY_RULES)]
+    pub rules: Vec<InstructionPolicyRule>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, InitSpace)]
+pub struct InstructionPolicyRule {
+    pub program_id: Pubkey,
+
+    // when empty any instruction of the program is allowed, otherwise the
+    // instruction data has to start with one of these discriminators
+    #[max_len(MAX_DISCRIMINATORS_PER_RULE, MAX_DISCRIMINATOR_LEN)]
+    pub discriminators: Vec<Vec<u8>>,
+}
+
+impl InstructionPolicy {
+    pub fn validate_rules(rules: &[InstructionPolicyRule]) -> Result<()> {
+        require!(
+            rules.len() <= MAX_INSTRUCTION_POLICY_RULES,
+            ErrorCode::InvalidInstructionPolicy
+        );
+
+        for rule in rules.iter() {
+            require!(
+                rule.discriminators.len() <= MAX_DISCRIMINATORS_PER_RULE,
+                ErrorCode::InvalidInstructionPolicy
+            );
+
+            for discriminator in rule.discriminators.iter() {
+                require!(
+                    !discriminator.is_empty() && discriminator.len() <= MAX_DISCRIMINATOR_LEN,
+                    ErrorCode::InvalidInstructionPolicy
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn check_instruction(&self, ix: &ProposalInstruction) -> Result<()> {
+        // autocrat is always allowed, so that a DAO can't lock itself out of
+        // changing its parameters or this policy
+        if !self.is_enabled || ix.program_id == crate::ID {
+            return Ok(());
+        }
+
+        let is_allowed = self.rules.iter().any(|rule| {
+            rule.program_id == ix.program_id
+                && (rule.discriminators.is_empty()
+                    || rule
+                        .discriminators
+                        .iter()
+                        .any(|discriminator| ix.data.starts_with(discriminator)))
+        });
+
+        require!(is_allowed, ErrorCode::InstructionNotAllowed);
+
+        Ok(())
+    }
+}
@@ -1,42 +1,89 @@
-use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
-
+pub use conditional_vault::*;
 pub use dao::*;
 pub use dao_treasury::*;
+pub use instruction_policy::*;
 pub use proposal::*;
-pub use proposal_vault::*;
 
+pub mod conditional_vault;
 pub mod dao;
 pub mod dao_treasury;
+pub mod instruction_policy;
 pub mod proposal;
-pub mod proposal_vault;
 
 pub const SLOTS_PER_10_SECS: u64 = 25;
 pub const PROPOSAL_DURATION_SLOTS: u64 = 1 * 60 * 6 * SLOTS_PER_10_SECS;
+// how long after its markets close a proposal can be finalized before it's failed regardless of its prices
 pub const FINALIZE_WINDOW_SLOTS: u64 = 15 * 6 * SLOTS_PER_10_SECS;
+// how long a passed proposal can be retried for before it's marked as having failed execution
+pub const EXECUTION_WINDOW_SLOTS: u64 = 24 * 60 * 6 * SLOTS_PER_10_SECS;
+// how long a passed proposal is queued for, during which the guardian can veto it
+pub const EXECUTION_DELAY_SLOTS: u64 = 24 * 60 * 6 * SLOTS_PER_10_SECS;
+
+// bounds enforced on every `DaoConfig`, so that a bad update can't brick future proposals
+pub const MIN_PROPOSAL_DURATION_SLOTS: u64 = 10 * 6 * SLOTS_PER_10_SECS; // 10 minutes
+pub const MAX_PROPOSAL_DURATION_SLOTS: u64 = 30 * 24 * 60 * 6 * SLOTS_PER_10_SECS; // 30 days
+pub const MIN_FINALIZE_WINDOW_SLOTS: u64 = 6 * SLOTS_PER_10_SECS; // 1 minute
+pub const MAX_FINALIZE_WINDOW_SLOTS: u64 = 30 * 24 * 60 * 6 * SLOTS_PER_10_SECS; // 30 days
+pub const MIN_EXECUTION_WINDOW_SLOTS: u64 = 6 * SLOTS_PER_10_SECS; // 1 minute
+pub const MAX_EXECUTION_WINDOW_SLOTS: u64 = 30 * 24 * 60 * 6 * SLOTS_PER_10_SECS; // 30 days
+pub const MAX_EXECUTION_DELAY_SLOTS: u64 = 30 * 24 * 60 * 6 * SLOTS_PER_10_SECS; // 30 days
 
 // by default, the pass price needs to be 5% higher than the fail price
 pub const DEFAULT_PASS_THRESHOLD_BPS: u64 = 500;
+pub const MAX_PASS_THRESHOLD_BPS: u64 = BPS_SCALE;
+
+// by default, proposers get their whole fee back if the proposal passes
+pub const DEFAULT_PROPOSAL_FEE_REFUND_BPS: u64 = BPS_SCALE;
 
-// start at 10 SOL ($1000 at current prices), decay by ~5 SOL per day
-pub const DEFAULT_BASE_BURN_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
-pub const DEFAULT_BURN_DECAY_PER_SLOT_LAMPORTS: u64 = 23_150;
+pub const DEFAULT_MAX_ACTIVE_PROPOSALS: u32 = 8;
 
-pub const PROPOSAL_FEE_USDC: u64 = 1000 * 1_000_000; // $1000 * 10^6
+// by default the proposal fee doubles with every active proposal, up to 1024x
+pub const DEFAULT_PROPOSAL_FEE_MAX_MULTIPLIER: u64 = 1024;
 
-pub const AMM_INITIAL_QUOTE_LIQUIDITY: u64 = 1000 * 1_000_000; // $1000 * 10^6
+// denominated in whole quote tokens, scaled by the quote mint's decimals on initialization
+pub const PROPOSAL_FEE_USDC: u64 = 1000; // $1000
+pub const AMM_INITIAL_QUOTE_LIQUIDITY: u64 = 1000; // $1000
+pub const FINALIZER_BOUNTY_USDC: u64 = 10; // $10
 
 pub const AMM_SWAP_FEE_BPS: u64 = 300; // 3%
 pub const AMM_SWAP_FEE_BPS_MIN: u64 = 100; // 1%
 pub const AMM_SWAP_FEE_BPS_MAX: u64 = 1000; // 10%
 
+pub const AMM_LTWAP_DECIMALS: u8 = 9;
+
+// mint decimals and ltwap decimals must stay within what `get_decimal_scale_u64` supports
+pub const MAX_DECIMALS: u8 = 15;
+
+// conditional token symbols prefix the underlying ticker with up to two
+// characters (e.g. `o7META`), and metaplex symbols are at most 10 bytes
+pub const MAX_TICKER_LEN: usize = 8;
+
 pub const BPS_SCALE: u64 = 100 * 100;
 
+pub const STATUS_QUO_OUTCOME_INDEX: u8 = 0;
+pub const MIN_PROPOSAL_OUTCOMES: u8 = 2;
+pub const MAX_PROPOSAL_OUTCOMES: usize = 8;
+
+pub const MAX_PROPOSAL_PREREQUISITES: usize = 4;
+
+// sized so that the policy account can be created on DAO initialization
+pub const MAX_INSTRUCTION_POLICY_RULES: usize = 32;
+pub const MAX_DISCRIMINATORS_PER_RULE: usize = 8;
+pub const MAX_DISCRIMINATOR_LEN: usize = 8; // long enough for anchor discriminators
+
 pub const AMM_SEED_PREFIX: &[u8] = b"amm__";
 pub const AMM_POSITION_SEED_PREFIX: &[u8] = b"amm_position";
 pub const AMM_AUTH_SEED_PREFIX: &[u8] = b"amm_auth";
 
+// We will create a civilization of the Mind in Cyberspace. May it be
+// more humane and fair than the world your governments have made before.
+//  - John Perry Barlow, A Declaration of the Independence of Cyberspace
+pub const DAO_SEED_PREFIX: &[u8] = b"WWCACOTMICMIBMHAFTTWYGHMB";
 pub const DAO_TREASURY_SEED_PREFIX: &[u8] = b"dao_treasury";
+pub const INSTRUCTION_POLICY_SEED_PREFIX: &[u8] = b"instruction_policy";
 
 pub const PROPOSAL_SEED_PREFIX: &[u8] = b"proposal__";
 pub const PROPOSAL_INSTRUCTIONS_SEED_PREFIX: &[u8] = b"proposal_instructions";
-pub const PROPOSAL_VAULT_SEED_PREFIX: &[u8] = b"proposal_vault";
+pub const PROPOSAL_FEE_ESCROW_SEED_PREFIX: &[u8] = b"proposal_fee_escrow";
+pub const CONDITIONAL_VAULT_SEED_PREFIX: &[u8] = b"conditional_vault";
+pub const CONDITIONAL_MINT_SEED_PREFIX: &[u8] = b"conditional_mint";
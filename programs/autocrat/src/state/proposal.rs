@@ -1,73 +1,366 @@
+use std::ops::Range;
+
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
 
+use crate::error::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+
 #[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, InitSpace)]
 pub enum ProposalState {
     Initialize,
+    // submitted, waiting for a free slot before its markets start trading
+    Queued,
     Pending,
     Passed,
     Failed,
+    Cancelled,
+    Executed,
+    ExecutionFailed,
+    Vetoed,
+}
+
+impl ProposalState {
+    // whether the markets passed the proposal, regardless of whether it was
+    // executed or vetoed afterwards
+    pub fn is_passed(&self) -> bool {
+        matches!(
+            self,
+            ProposalState::Passed
+                | ProposalState::Executed
+                | ProposalState::ExecutionFailed
+                | ProposalState::Vetoed
+        )
+    }
+
+    pub fn is_finalized(&self) -> bool {
+        self.is_passed() || *self == ProposalState::Failed
+    }
+
+    // whether the proposal's instructions will never be executed
+    pub fn is_abandoned(&self) -> bool {
+        matches!(
+            self,
+            ProposalState::Failed
+                | ProposalState::Cancelled
+                | ProposalState::ExecutionFailed
+                | ProposalState::Vetoed
+        )
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct Proposal {
+    pub dao: Pubkey,
     pub number: u64,
     pub proposer: Pubkey,
     #[max_len(100)]
     pub description_url: String,
     pub slot_enqueued: u64,
-    pub slots_duration: u64,
     pub state: ProposalState,
-    pub instructions: Pubkey,
-
-    pub proposal_vault: Pubkey,
+    // only meaningful while `Queued`
+    pub queue_position: u64,
 
-    pub is_pass_market_created: bool,
-    pub is_fail_market_created: bool,
+    // conditional vaults of the proposal that haven't been closed, including
+    // the two that back its markets. The proposal can't be closed before them,
+    // since they're settled by it
+    pub conditional_vault_count: u8,
 
     pub meta_mint: Pubkey,
     pub usdc_mint: Pubkey,
 
-    pub pass_market_amm: Pubkey,
-    pub fail_market_amm: Pubkey,
+    // mutually exclusive options, each with its own market. The first one is
+    // the status quo, which every other outcome has to beat by the pass threshold
+    #[max_len(MAX_PROPOSAL_OUTCOMES)]
+    pub outcomes: Vec<ProposalOutcome>,
+    // set on finalization, the status quo if the proposal failed
+    pub winning_outcome_index: u8,
 
-    pub conditional_on_pass_meta_mint: Pubkey,
-    pub conditional_on_pass_usdc_mint: Pubkey,
+    // set for proposals that pick a value in a range rather than between options
+    pub scalar: Option<ScalarRange>,
 
-    pub conditional_on_fail_meta_mint: Pubkey,
-    pub conditional_on_fail_usdc_mint: Pubkey,
+    // numbers of earlier proposals of the DAO that have to be executed before
+    // this one can be. If any of them isn't, this proposal isn't executed either
+    #[max_len(MAX_PROPOSAL_PREREQUISITES)]
+    pub prerequisites: Vec<u64>,
+    // submitted proposals that list this one as a prerequisite and haven't been
+    // closed, this proposal can't be closed before them
+    pub dependent_count: u32,
 
     pub proposer_inititial_conditional_meta_minted: u64,
     pub proposer_inititial_conditional_usdc_minted: u64,
+
+    // dao parameters snapshotted when the proposal is submitted, so that a later
+    // `update_dao` can't change the rules for markets that are already trading
+    pub slots_duration: u64,
+    pub finalize_window_slots: u64,
+    pub execution_window_slots: u64,
+    pub execution_delay_slots: u64,
+    pub pass_threshold_bps: u64,
+    pub finalizer_bounty_usdc: u64,
+    pub proposal_fee_refund_bps: u64,
+
+    // a passed proposal becomes executable `execution_delay_slots` after it's
+    // finalized, and stays executable for `execution_window_slots`
+    pub slot_finalized: u64,
+
+    // snapshotted on creation instead, since the markets are created before submission
+    pub amm_swap_fee_bps: u64,
+    pub amm_ltwap_decimals: u8,
+}
+
+impl Proposal {
+    pub fn outcome(&self, outcome_index: u8) -> Result<&ProposalOutcome> {
+        self.outcomes
+            .get(outcome_index as usize)
+            .ok_or(error!(ErrorCode::InvalidOutcomeIndex))
+    }
+
+    pub fn outcome_mut(&mut self, outcome_index: u8) -> Result<&mut ProposalOutcome> {
+        self.outcomes
+            .get_mut(outcome_index as usize)
+            .ok_or(error!(ErrorCode::InvalidOutcomeIndex))
+    }
+
+    pub fn has_amm(&self, amm: &Pubkey) -> bool {
+        self.outcomes.iter().any(|outcome| &outcome.amm == amm)
+    }
+
+    // e.g. `pMETA` and `fUSDC` for the pass and fail sides of a binary proposal,
+    // and `o2META` for the third outcome of a proposal with more than two
+    pub fn conditional_token_symbol(&self, outcome_index: u8, underlying_symbol: &str) -> String {
+        if outcome_index == STATUS_QUO_OUTCOME_INDEX {
+            format!("f{underlying_symbol}")
+        } else if self.outcomes.len() == 2 {
+            format!("p{underlying_symbol}")
+        } else {
+            format!("o{outcome_index}{underlying_symbol}")
+        }
+    }
+
+    // e.g. `pMETA-42`
+    pub fn conditional_token_name(&self, outcome_index: u8, underlying_symbol: &str) -> String {
+        format!(
+            "{}-{}",
+            self.conditional_token_symbol(outcome_index, underlying_symbol),
+            self.number
+        )
+    }
+
+    // the outcome that passes given each outcome's LTWAP, or `None` if the
+    // status quo wins. The best of the other outcomes wins if it beats the
+    // status quo by the pass threshold, ties going to the lowest index.
+    pub fn winning_outcome_index(&self, ltwaps: &[u64]) -> Option<u8> {
+        let threshold = (ltwaps[STATUS_QUO_OUTCOME_INDEX as usize] as u128)
+            .checked_mul(BPS_SCALE.checked_add(self.pass_threshold_bps).unwrap() as u128)
+            .unwrap()
+            .checked_div(BPS_SCALE as u128)
+            .unwrap();
+
+        let mut best_outcome_index = STATUS_QUO_OUTCOME_INDEX as usize;
+        let mut best_ltwap = 0;
+
+        for (outcome_index, &ltwap) in ltwaps.iter().enumerate() {
+            if outcome_index != STATUS_QUO_OUTCOME_INDEX as usize && ltwap > best_ltwap {
+                best_outcome_index = outcome_index;
+                best_ltwap = ltwap;
+            }
+        }
+
+        ((best_ltwap as u128) > threshold).then_some(best_outcome_index as u8)
+    }
+
+    // splits the escrowed proposal fee into the finalizer's bounty, the
+    // proposer's refund and what's forfeited to the treasury. The bounty is
+    // paid first, and the refund share applies to what's left.
+    pub fn fee_split(&self, escrowed_fee: u64) -> (u64, u64, u64) {
+        let bounty = self.finalizer_bounty_usdc.min(escrowed_fee);
+        let refund = if self.state == ProposalState::Passed {
+            ((escrowed_fee - bounty) as u128 * self.proposal_fee_refund_bps as u128
+                / BPS_SCALE as u128) as u64
+        } else {
+            0
+        };
+        let forfeited = escrowed_fee - bounty - refund;
+
+        (bounty, refund, forfeited)
+    }
+
+    // the value that the winning outcome of a scalar proposal stands for
+    pub fn scalar_value(&self) -> Result<Option<u64>> {
+        self.scalar
+            .map(|scalar| scalar.bucket_value(self.winning_outcome_index, self.outcomes.len()))
+            .transpose()
+    }
+}
+
+// every outcome other than the status quo is a bucket, with the buckets evenly
+// spaced from `min_value` to `max_value`. All buckets share one instruction
+// template, which has the winning value written into it before it's executed.
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, InitSpace)]
+pub struct ScalarRange {
+    pub min_value: u64,
+    pub max_value: u64,
+    // the value is written as a little-endian u64 at `data_offset` in the data
+    // of the template's instruction at `instruction_index`
+    pub instruction_index: u32,
+    pub data_offset: u32,
+}
+
+impl ScalarRange {
+    pub const VALUE_LEN: usize = 8;
+
+    pub fn validate(&self, outcome_count: u8) -> Result<()> {
+        require!(
+            self.min_value < self.max_value && outcome_count > MIN_PROPOSAL_OUTCOMES,
+            ErrorCode::InvalidScalarRange
+        );
+
+        Ok(())
+    }
+
+    // rounds down, so every bucket's value is within the range
+    pub fn bucket_value(&self, outcome_index: u8, outcome_count: usize) -> Result<u64> {
+        let bucket = outcome_index.saturating_sub(STATUS_QUO_OUTCOME_INDEX + 1) as u128;
+        let last_bucket = outcome_count
+            .checked_sub(2)
+            .filter(|&last_bucket| last_bucket > 0)
+            .ok_or(ErrorCode::InvalidScalarRange)? as u128;
+        let range = self
+            .max_value
+            .checked_sub(self.min_value)
+            .ok_or(ErrorCode::InvalidScalarRange)? as u128;
+
+        require!(bucket <= last_bucket, ErrorCode::InvalidOutcomeIndex);
+
+        Ok(self.min_value + (range * bucket / last_bucket) as u64)
+    }
+
+    // the template instruction may be added later, so only checked if present
+    pub fn validate_template(&self, instructions: &[ProposalInstruction]) -> Result<()> {
+        match instructions.get(self.instruction_index as usize) {
+            Some(ix) => self.write_value(&mut ix.data.clone(), self.min_value),
+            None => Ok(()),
+        }
+    }
+
+    pub fn write_value(&self, data: &mut [u8], value: u64) -> Result<()> {
+        let start = self.data_offset as usize;
+
+        data.get_mut(start..start + Self::VALUE_LEN)
+            .ok_or(error!(ErrorCode::InvalidScalarTemplate))?
+            .copy_from_slice(&value.to_le_bytes());
+
+        Ok(())
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, InitSpace,
+)]
+pub struct ProposalOutcome {
+    pub is_market_created: bool,
+    pub amm: Pubkey,
+
+    pub conditional_meta_mint: Pubkey,
+    pub conditional_usdc_mint: Pubkey,
+
+    // run if this outcome wins, `Pubkey::default()` if it has no instructions
+    pub instructions: Pubkey,
 }
 
 #[account]
 pub struct ProposalInstructions {
     pub proposer: Pubkey,
     pub proposal: Pubkey,
-    pub proposal_instructions_frozen: bool,
+    pub outcome_index: u8,
+    // index of the next instruction to execute, instructions run strictly in order
+    pub execution_cursor: u64,
+    // address lookup tables that instruction accounts can be referenced through
+    pub lookup_tables: Vec<Pubkey>,
     pub instructions: Vec<ProposalInstruction>,
 }
 
 impl ProposalInstructions {
-    pub const SERIALIZED_LEN: usize = 4 + 32 + 32 + 1 + 4;
+    pub const SERIALIZED_LEN: usize = 4 + 32 + 32 + 1 + 8 + 4 + 4;
+
+    // the instructions run by an execution of `instruction_count` instructions
+    // from `instruction_index`, which has to be the execution cursor
+    pub fn execution_range(
+        &self,
+        instruction_index: u64,
+        instruction_count: u64,
+    ) -> Result<Range<usize>> {
+        require_eq!(
+            instruction_index,
+            self.execution_cursor,
+            ErrorCode::InstructionExecutedOutOfOrder
+        );
+
+        let end = instruction_index
+            .checked_add(instruction_count)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        require!(
+            end <= self.instructions.len() as u64,
+            ErrorCode::InstructionIndexOutOfRange
+        );
+
+        Ok(instruction_index as usize..end as usize)
+    }
+
+    // lookup tables have to be passed in the order they're recorded in
+    pub fn validate_lookup_tables(&self, lookup_tables: &[AccountInfo]) -> Result<()> {
+        require!(
+            lookup_tables.len() == self.lookup_tables.len()
+                && lookup_tables
+                    .iter()
+                    .zip(self.lookup_tables.iter())
+                    .all(|(lookup_table, key)| lookup_table.key == key),
+            ErrorCode::InvalidLookupTables
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub enum ProposalAccountKey {
+    Pubkey(Pubkey),
+    // an index into one of the proposal's lookup tables. Lookup tables can only
+    // be appended to, so this always resolves to the same account once it exists
+    LookupTable { table_index: u8, address_index: u8 },
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct ProposalAccount {
-    pub pubkey: Pubkey,
+    pub key: ProposalAccountKey,
     pub is_signer: bool,
     pub is_writable: bool,
 }
 
-impl From<&ProposalAccount> for AccountMeta {
-    fn from(acc: &ProposalAccount) -> Self {
-        Self {
-            pubkey: acc.pubkey,
-            is_signer: acc.is_signer,
-            is_writable: acc.is_writable,
-        }
+impl ProposalAccount {
+    pub fn to_account_meta(&self, lookup_tables: &[AccountInfo]) -> Result<AccountMeta> {
+        let pubkey = match self.key {
+            ProposalAccountKey::Pubkey(pubkey) => pubkey,
+            ProposalAccountKey::LookupTable {
+                table_index,
+                address_index,
+            } => {
+                let lookup_table = lookup_tables
+                    .get(table_index as usize)
+                    .ok_or(ErrorCode::InvalidLookupTableIndex)?;
+                get_lookup_table_address(lookup_table, address_index)?
+            }
+        };
+
+        Ok(AccountMeta {
+            pubkey,
+            is_signer: self.is_signer,
+            is_writable: self.is_writable,
+        })
     }
 }
 
@@ -78,12 +371,336 @@ pub struct ProposalInstruction {
     pub data: Vec<u8>,
 }
 
-impl From<&ProposalInstruction> for Instruction {
-    fn from(ix: &ProposalInstruction) -> Self {
-        Self {
-            program_id: ix.program_id,
-            data: ix.data.clone(),
-            accounts: ix.accounts.iter().map(Into::into).collect(),
+impl ProposalInstruction {
+    // `lookup_tables` must already have been checked against the proposal's
+    pub fn to_instruction(&self, lookup_tables: &[AccountInfo]) -> Result<Instruction> {
+        Ok(Instruction {
+            program_id: self.program_id,
+            data: self.data.clone(),
+            accounts: self
+                .accounts
+                .iter()
+                .map(|acc| acc.to_account_meta(lookup_tables))
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    fn proposal_instructions(instruction_count: usize) -> ProposalInstructions {
+        ProposalInstructions {
+            proposer: Pubkey::new_unique(),
+            proposal: Pubkey::new_unique(),
+            outcome_index: 1,
+            execution_cursor: 0,
+            lookup_tables: Vec::new(),
+            instructions: (0..instruction_count)
+                .map(|_| ProposalInstruction {
+                    program_id: Pubkey::new_unique(),
+                    accounts: Vec::new(),
+                    data: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn instructions_are_executed_in_batches_from_the_cursor() {
+        let mut proposal_instructions = proposal_instructions(5);
+
+        assert_eq!(proposal_instructions.execution_range(0, 2).unwrap(), 0..2);
+        proposal_instructions.execution_cursor = 2;
+
+        assert_eq!(proposal_instructions.execution_range(2, 3).unwrap(), 2..5);
+    }
+
+    #[test]
+    fn instructions_cant_be_skipped_or_rerun() {
+        let mut proposal_instructions = proposal_instructions(5);
+        proposal_instructions.execution_cursor = 2;
+
+        assert_eq!(
+            proposal_instructions.execution_range(3, 1),
+            err!(ErrorCode::InstructionExecutedOutOfOrder)
+        );
+        assert_eq!(
+            proposal_instructions.execution_range(0, 2),
+            err!(ErrorCode::InstructionExecutedOutOfOrder)
+        );
+    }
+
+    #[test]
+    fn execution_cant_run_past_the_last_instruction() {
+        let mut proposal_instructions = proposal_instructions(5);
+        proposal_instructions.execution_cursor = 2;
+
+        assert_eq!(
+            proposal_instructions.execution_range(2, 4),
+            err!(ErrorCode::InstructionIndexOutOfRange)
+        );
+        assert_eq!(
+            proposal_instructions.execution_range(2, u64::MAX),
+            err!(ErrorCode::ArithmeticOverflow)
+        );
+    }
+
+    fn lookup_table_account(table_index: u8, address_index: u8) -> ProposalAccount {
+        ProposalAccount {
+            key: ProposalAccountKey::LookupTable {
+                table_index,
+                address_index,
+            },
+            is_signer: false,
+            is_writable: true,
+        }
+    }
+
+    #[test]
+    fn lookup_table_accounts_resolve_to_their_addresses() {
+        let addresses = [(); 3].map(|_| Pubkey::new_unique());
+        let direct = Pubkey::new_unique();
+        let mut lookup_tables = [
+            TestAccount::lookup_table(&addresses[..1]),
+            TestAccount::lookup_table(&addresses[1..]),
+        ];
+
+        let mut proposal_instructions = proposal_instructions(1);
+        proposal_instructions.lookup_tables = lookup_tables.iter().map(|table| table.key).collect();
+        proposal_instructions.instructions[0].accounts = vec![
+            lookup_table_account(1, 1),
+            ProposalAccount {
+                key: ProposalAccountKey::Pubkey(direct),
+                is_signer: true,
+                is_writable: false,
+            },
+            lookup_table_account(0, 0),
+        ];
+
+        let lookup_tables = infos(&mut lookup_tables);
+        proposal_instructions
+            .validate_lookup_tables(&lookup_tables)
+            .unwrap();
+        let ix = proposal_instructions.instructions[0]
+            .to_instruction(&lookup_tables)
+            .unwrap();
+
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(addresses[2], false),
+                AccountMeta::new_readonly(direct, true),
+                AccountMeta::new(addresses[0], false),
+            ]
+        );
+    }
+
+    #[test]
+    fn lookup_tables_must_be_passed_as_recorded() {
+        let mut lookup_tables = [
+            TestAccount::lookup_table(&[Pubkey::new_unique()]),
+            TestAccount::lookup_table(&[Pubkey::new_unique()]),
+        ];
+        let mut proposal_instructions = proposal_instructions(0);
+        proposal_instructions.lookup_tables = lookup_tables.iter().map(|table| table.key).collect();
+
+        let mut lookup_tables = infos(&mut lookup_tables);
+
+        // missing one
+        assert_eq!(
+            proposal_instructions.validate_lookup_tables(&lookup_tables[..1]),
+            err!(ErrorCode::InvalidLookupTables)
+        );
+
+        // out of order
+        lookup_tables.swap(0, 1);
+        assert_eq!(
+            proposal_instructions.validate_lookup_tables(&lookup_tables),
+            err!(ErrorCode::InvalidLookupTables)
+        );
+    }
+
+    #[test]
+    fn lookup_table_accounts_must_exist() {
+        let mut lookup_tables = [TestAccount::lookup_table(&[Pubkey::new_unique()])];
+        let lookup_tables = infos(&mut lookup_tables);
+
+        assert_eq!(
+            lookup_table_account(1, 0).to_account_meta(&lookup_tables),
+            err!(ErrorCode::InvalidLookupTableIndex)
+        );
+        assert_eq!(
+            lookup_table_account(0, 1).to_account_meta(&lookup_tables),
+            err!(ErrorCode::InvalidLookupTableIndex)
+        );
+
+        // an account that isn't a lookup table
+        let mut not_lookup_tables = [TestAccount::new(Pubkey::new_unique(), Vec::new())];
+
+        assert_eq!(
+            lookup_table_account(0, 0).to_account_meta(&infos(&mut not_lookup_tables)),
+            err!(ErrorCode::InvalidLookupTables)
+        );
+    }
+
+    #[test]
+    fn the_best_outcome_passes_if_it_beats_the_status_quo_by_the_threshold() {
+        let proposal = test_proposal(Pubkey::new_unique(), 0, 4);
+
+        // 5% over the status quo is needed by default
+        assert_eq!(
+            proposal.winning_outcome_index(&[1000, 900, 1051, 1000]),
+            Some(2)
+        );
+        assert_eq!(
+            proposal.winning_outcome_index(&[1000, 900, 1050, 1000]),
+            None
+        );
+        assert_eq!(
+            proposal.winning_outcome_index(&[1000, 2000, 0, 3000]),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn ties_go_to_the_lowest_outcome_index() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 5);
+
+        assert_eq!(
+            proposal.winning_outcome_index(&[1000, 900, 2000, 2000, 2000]),
+            Some(2)
+        );
+
+        // with no threshold, a tie with the status quo keeps the status quo
+        proposal.pass_threshold_bps = 0;
+
+        assert_eq!(
+            proposal.winning_outcome_index(&[2000, 2000, 2000, 1000, 1000]),
+            None
+        );
+        assert_eq!(proposal.winning_outcome_index(&[0, 0, 0, 0, 0]), None);
+    }
+
+    fn scalar_range(min_value: u64, max_value: u64) -> ScalarRange {
+        ScalarRange {
+            min_value,
+            max_value,
+            instruction_index: 0,
+            data_offset: 0,
+        }
+    }
+
+    #[test]
+    fn buckets_span_the_scalar_range() {
+        let scalar = scalar_range(100, 400);
+
+        // outcome 0 is the status quo, the buckets are outcomes 1 to 4
+        assert_eq!(scalar.bucket_value(1, 5), Ok(100));
+        assert_eq!(scalar.bucket_value(2, 5), Ok(200));
+        assert_eq!(scalar.bucket_value(4, 5), Ok(400));
+
+        let scalar = scalar_range(0, u64::MAX);
+
+        assert_eq!(scalar.bucket_value(1, 3), Ok(0));
+        assert_eq!(scalar.bucket_value(2, 3), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn bucket_values_round_down() {
+        let scalar = scalar_range(0, 10);
+
+        assert_eq!(scalar.bucket_value(2, 5), Ok(3));
+        assert_eq!(scalar.bucket_value(3, 5), Ok(6));
+        assert_eq!(scalar.bucket_value(4, 5), Ok(10));
+    }
+
+    #[test]
+    fn invalid_buckets_are_rejected() {
+        // a single bucket has no range to span
+        assert_eq!(
+            scalar_range(0, 10).bucket_value(1, 2),
+            err!(ErrorCode::InvalidScalarRange)
+        );
+        assert_eq!(
+            scalar_range(10, 0).bucket_value(1, 3),
+            err!(ErrorCode::InvalidScalarRange)
+        );
+        assert_eq!(
+            scalar_range(0, 10).bucket_value(3, 3),
+            err!(ErrorCode::InvalidOutcomeIndex)
+        );
+    }
+
+    #[test]
+    fn the_finalizer_bounty_is_paid_from_the_fee() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+        proposal.finalizer_bounty_usdc = 100;
+        proposal.proposal_fee_refund_bps = 0;
+
+        for state in [ProposalState::Passed, ProposalState::Failed] {
+            proposal.state = state;
+
+            assert_eq!(proposal.fee_split(1000), (100, 0, 900));
+        }
+    }
+
+    #[test]
+    fn the_finalizer_bounty_is_capped_at_the_fee() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+        proposal.finalizer_bounty_usdc = 1000;
+        proposal.state = ProposalState::Passed;
+
+        assert_eq!(proposal.fee_split(600), (600, 0, 0));
+        assert_eq!(proposal.fee_split(0), (0, 0, 0));
+    }
+
+    #[test]
+    fn passed_proposals_refund_a_share_of_the_fee() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+        proposal.finalizer_bounty_usdc = 100;
+        proposal.proposal_fee_refund_bps = 5000;
+        proposal.state = ProposalState::Passed;
+
+        // the refund rounds down, in the treasury's favour
+        assert_eq!(proposal.fee_split(1001), (100, 450, 451));
+
+        proposal.proposal_fee_refund_bps = BPS_SCALE;
+
+        assert_eq!(proposal.fee_split(1001), (100, 901, 0));
+    }
+
+    #[test]
+    fn failed_proposals_forfeit_the_fee() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+        proposal.finalizer_bounty_usdc = 100;
+        proposal.proposal_fee_refund_bps = BPS_SCALE;
+        proposal.state = ProposalState::Failed;
+
+        assert_eq!(proposal.fee_split(1001), (100, 0, 901));
+    }
+
+    #[test]
+    fn the_whole_escrowed_fee_is_paid_out() {
+        let mut proposal = test_proposal(Pubkey::new_unique(), 0, 2);
+
+        for state in [ProposalState::Passed, ProposalState::Failed] {
+            for escrowed_fee in [0, 1, 999, u64::MAX] {
+                for refund_bps in [0, 1, 3333, BPS_SCALE] {
+                    proposal.state = state;
+                    proposal.finalizer_bounty_usdc = 7;
+                    proposal.proposal_fee_refund_bps = refund_bps;
+
+                    let (bounty, refund, forfeited) = proposal.fee_split(escrowed_fee);
+
+                    assert_eq!(
+                        bounty as u128 + refund as u128 + forfeited as u128,
+                        escrowed_fee as u128
+                    );
+                }
+            }
         }
     }
 }
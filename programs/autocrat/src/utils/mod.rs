@@ -1,11 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::address_lookup_table;
+use anchor_lang::solana_program::address_lookup_table::state::AddressLookupTable;
+use anchor_lang::system_program;
 use crate::error::ErrorCode;
 
+pub use prerequisites::*;
+pub use queue::*;
 pub use token::*;
 pub use seeds::*;
 
+pub mod prerequisites;
+pub mod queue;
 pub mod token;
 pub mod seeds;
+#[cfg(test)]
+pub mod test_utils;
 
 use crate::state::*;
 
@@ -34,13 +43,111 @@ pub fn get_decimal_scale_u64(decimals: u8) -> Result<u64> {
     }
 }
 
-pub fn get_instructions_size(instructions: &Vec<ProposalInstruction>) -> usize {
+pub fn get_instructions_size(instructions: &[ProposalInstruction]) -> usize {
     instructions.iter().fold(0, |accumulator, ix| {
         accumulator + 
         32 + // program id
         4 + // accounts vec prefix
-        ix.accounts.len() * (32 + 1 + 1) + // pubkey + 2 bools per account
+        ix.accounts.iter().fold(0, |accounts_size, acc| {
+            accounts_size +
+            1 + // key variant
+            match acc.key {
+                ProposalAccountKey::Pubkey(_) => 32,
+                ProposalAccountKey::LookupTable { .. } => 1 + 1, // table index + address index
+            } +
+            1 + 1 // 2 bools
+        }) +
         4 + // data vec prefix
         ix.data.len()
     })
 }
+
+// writes back a proposal that was loaded from a remaining account
+pub fn save_proposal(proposal_info: &AccountInfo, proposal: &Proposal) -> Result<()> {
+    let mut data = proposal_info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    proposal.try_serialize(&mut writer)
+}
+
+// creates an account at a PDA of this program like anchor's `init` does, which
+// also works when lamports have already been sent to the address
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    lamports: u64,
+    owner: &Pubkey,
+    seeds: &[&[u8]],
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    if lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports - current_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        owner,
+    )
+}
+
+pub fn get_lookup_table_address(lookup_table: &AccountInfo, address_index: u8) -> Result<Pubkey> {
+    require_keys_eq!(
+        *lookup_table.owner,
+        address_lookup_table::program::ID,
+        ErrorCode::InvalidLookupTables
+    );
+
+    let data = lookup_table.try_borrow_data()?;
+    let lookup_table = AddressLookupTable::deserialize(&data)
+        .map_err(|_| ErrorCode::InvalidLookupTables)?;
+
+    lookup_table
+        .addresses
+        .get(address_index as usize)
+        .copied()
+        .ok_or(error!(ErrorCode::InvalidLookupTableIndex))
+}
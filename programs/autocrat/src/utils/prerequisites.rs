@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+// only earlier proposals can be prerequisites, which rules out cycles
+pub fn validate_prerequisites(prerequisites: &[u64], proposal_count: u64) -> Result<()> {
+    require!(
+        prerequisites.len() <= MAX_PROPOSAL_PREREQUISITES
+            && prerequisites.windows(2).all(|pair| pair[0] < pair[1])
+            && prerequisites.iter().all(|&number| number < proposal_count),
+        ErrorCode::InvalidPrerequisites
+    );
+
+    Ok(())
+}
+
+// the proposals that a proposal depends on, passed as remaining accounts in the
+// order they're listed on it
+pub fn get_prerequisites<'a, 'info>(
+    proposal: &Proposal,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Vec<(&'a AccountInfo<'info>, Proposal)>> {
+    require_eq!(
+        remaining_accounts.len(),
+        proposal.prerequisites.len(),
+        ErrorCode::InvalidPrerequisiteAccounts
+    );
+
+    proposal
+        .prerequisites
+        .iter()
+        .zip(remaining_accounts.iter())
+        .map(|(&number, prerequisite_info)| {
+            // only this program can create accounts it owns with a proposal's
+            // discriminator, so the DAO and number identify the proposal
+            require_keys_eq!(
+                *prerequisite_info.owner,
+                crate::ID,
+                ErrorCode::InvalidPrerequisiteAccounts
+            );
+
+            let prerequisite =
+                Proposal::try_deserialize(&mut prerequisite_info.data.borrow().as_ref())?;
+
+            require!(
+                prerequisite.dao == proposal.dao && prerequisite.number == number,
+                ErrorCode::InvalidPrerequisiteAccounts
+            );
+
+            Ok((prerequisite_info, prerequisite))
+        })
+        .collect()
+}
+
+// `false` if one of the prerequisites will never be executed. Otherwise they
+// all have to have been executed already.
+pub fn check_prerequisites_executed(prerequisites: &[(&AccountInfo, Proposal)]) -> Result<bool> {
+    if prerequisites
+        .iter()
+        .any(|(_, prerequisite)| prerequisite.state.is_abandoned())
+    {
+        return Ok(false);
+    }
+
+    require!(
+        prerequisites
+            .iter()
+            .all(|(_, prerequisite)| prerequisite.state == ProposalState::Executed),
+        ErrorCode::PrerequisitesNotExecuted
+    );
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    #[test]
+    fn earlier_proposals_can_be_prerequisites() {
+        assert_eq!(validate_prerequisites(&[], 0), Ok(()));
+        assert_eq!(validate_prerequisites(&[0, 2, 5], 6), Ok(()));
+    }
+
+    #[test]
+    fn prerequisites_must_be_sorted_earlier_proposals() {
+        // unsorted, and listed twice
+        assert_eq!(
+            validate_prerequisites(&[2, 0], 6),
+            err!(ErrorCode::InvalidPrerequisites)
+        );
+        assert_eq!(
+            validate_prerequisites(&[2, 2], 6),
+            err!(ErrorCode::InvalidPrerequisites)
+        );
+
+        // the proposal being created gets number `proposal_count`
+        assert_eq!(
+            validate_prerequisites(&[6], 6),
+            err!(ErrorCode::InvalidPrerequisites)
+        );
+        assert_eq!(
+            validate_prerequisites(&[7], 6),
+            err!(ErrorCode::InvalidPrerequisites)
+        );
+
+        let too_many: Vec<u64> = (0..=MAX_PROPOSAL_PREREQUISITES as u64).collect();
+        assert_eq!(
+            validate_prerequisites(&too_many, 100),
+            err!(ErrorCode::InvalidPrerequisites)
+        );
+    }
+
+    #[test]
+    fn prerequisites_are_loaded_in_order() {
+        let dao = Pubkey::new_unique();
+        let mut proposal = test_proposal(dao, 5, 2);
+        proposal.prerequisites = vec![1, 3];
+
+        let mut prerequisites = [
+            TestAccount::proposal(&test_proposal(dao, 1, 2)),
+            TestAccount::proposal(&test_proposal(dao, 3, 2)),
+        ];
+        let prerequisite_infos = infos(&mut prerequisites);
+
+        let loaded = get_prerequisites(&proposal, &prerequisite_infos).unwrap();
+
+        assert_eq!(
+            loaded
+                .iter()
+                .map(|(_, prerequisite)| prerequisite.number)
+                .collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+
+        // out of order, or missing one
+        let swapped = [prerequisite_infos[1].clone(), prerequisite_infos[0].clone()];
+
+        assert_eq!(
+            get_prerequisites(&proposal, &swapped).map(|_| ()),
+            err!(ErrorCode::InvalidPrerequisiteAccounts)
+        );
+        assert_eq!(
+            get_prerequisites(&proposal, &prerequisite_infos[..1]).map(|_| ()),
+            err!(ErrorCode::InvalidPrerequisiteAccounts)
+        );
+    }
+
+    #[test]
+    fn prerequisites_must_belong_to_the_dao() {
+        let dao = Pubkey::new_unique();
+        let mut proposal = test_proposal(dao, 5, 2);
+        proposal.prerequisites = vec![1];
+
+        let mut other_dao = [TestAccount::proposal(&test_proposal(
+            Pubkey::new_unique(),
+            1,
+            2,
+        ))];
+
+        assert_eq!(
+            get_prerequisites(&proposal, &infos(&mut other_dao)).map(|_| ()),
+            err!(ErrorCode::InvalidPrerequisiteAccounts)
+        );
+
+        let mut not_owned = [TestAccount::proposal(&test_proposal(dao, 1, 2))];
+        not_owned[0].owner = Pubkey::new_unique();
+
+        assert_eq!(
+            get_prerequisites(&proposal, &infos(&mut not_owned)).map(|_| ()),
+            err!(ErrorCode::InvalidPrerequisiteAccounts)
+        );
+    }
+
+    #[test]
+    fn proposals_wait_for_their_prerequisites_to_be_executed() {
+        let mut account = TestAccount::new(crate::ID, Vec::new());
+        let info = account.info();
+        let prerequisite = |state| {
+            let mut prerequisite = test_proposal(Pubkey::new_unique(), 0, 2);
+            prerequisite.state = state;
+            (&info, prerequisite)
+        };
+
+        assert_eq!(check_prerequisites_executed(&[]), Ok(true));
+        assert_eq!(
+            check_prerequisites_executed(&[
+                prerequisite(ProposalState::Executed),
+                prerequisite(ProposalState::Executed)
+            ]),
+            Ok(true)
+        );
+
+        // a prerequisite that hasn't been executed yet
+        for state in [ProposalState::Pending, ProposalState::Passed] {
+            assert_eq!(
+                check_prerequisites_executed(&[
+                    prerequisite(ProposalState::Executed),
+                    prerequisite(state)
+                ]),
+                err!(ErrorCode::PrerequisitesNotExecuted)
+            );
+        }
+
+        // a prerequisite that never will be, which fails the proposal
+        for state in [
+            ProposalState::Failed,
+            ProposalState::Cancelled,
+            ProposalState::ExecutionFailed,
+            ProposalState::Vetoed,
+        ] {
+            assert_eq!(
+                check_prerequisites_executed(&[
+                    prerequisite(ProposalState::Pending),
+                    prerequisite(state)
+                ]),
+                Ok(false)
+            );
+        }
+    }
+}
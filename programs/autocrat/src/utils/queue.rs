@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+// the queued proposal to start when a slot frees up, with its AMMs, passed as
+// remaining accounts after the finalized proposal's AMMs. `None` if the queue
+// is empty or the DAO is still at capacity, in which case nothing may be passed.
+pub fn get_next_queued_proposal<'a, 'info>(
+    dao_key: &Pubkey,
+    dao: &Dao,
+    queued_accounts: &'a [AccountInfo<'info>],
+) -> Result<Option<(&'a AccountInfo<'info>, Proposal, &'a [AccountInfo<'info>])>> {
+    if dao.queue_head == dao.queue_tail || dao.proposals_active >= dao.config.max_active_proposals {
+        require!(queued_accounts.is_empty(), ErrorCode::InvalidQueuedProposal);
+
+        return Ok(None);
+    }
+
+    // proposals are promoted strictly in the order they were submitted
+    let (queued_proposal_info, queued_amms) = queued_accounts
+        .split_first()
+        .ok_or(ErrorCode::InvalidQueuedProposal)?;
+
+    require_keys_eq!(
+        *queued_proposal_info.owner,
+        crate::ID,
+        ErrorCode::InvalidQueuedProposal
+    );
+
+    let queued_proposal =
+        Proposal::try_deserialize(&mut queued_proposal_info.data.borrow().as_ref())?;
+
+    require!(
+        queued_proposal.dao == *dao_key
+            && queued_proposal.state == ProposalState::Queued
+            && queued_proposal.queue_position == dao.queue_head,
+        ErrorCode::InvalidQueuedProposal
+    );
+
+    // nothing can follow the queued proposal's AMMs
+    require_eq!(
+        queued_amms.len(),
+        queued_proposal.outcomes.len(),
+        ErrorCode::InvalidOutcomeAccounts
+    );
+
+    for (outcome, amm) in queued_proposal.outcomes.iter().zip(queued_amms.iter()) {
+        require_keys_eq!(amm.key(), outcome.amm, ErrorCode::AmmProposalMismatch);
+    }
+
+    Ok(Some((queued_proposal_info, queued_proposal, queued_amms)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    // a DAO at `proposals_active` of its 2 active proposals, with 2 queued
+    fn queued_dao(proposals_active: u32) -> Dao {
+        let mut dao = test_dao();
+        dao.config.max_active_proposals = 2;
+        dao.proposals_active = proposals_active;
+        dao.queue_head = 3;
+        dao.queue_tail = 5;
+
+        dao
+    }
+
+    fn queued_proposal(dao_key: Pubkey, queue_position: u64) -> Proposal {
+        let mut proposal = test_proposal(dao_key, 10 + queue_position, 2);
+        proposal.state = ProposalState::Queued;
+        proposal.queue_position = queue_position;
+
+        proposal
+    }
+
+    // the queued proposal followed by its AMMs
+    fn queued_accounts(proposal: &Proposal) -> Vec<TestAccount> {
+        let mut accounts = vec![TestAccount::proposal(proposal)];
+        accounts.extend(
+            proposal
+                .outcomes
+                .iter()
+                .map(|outcome| TestAccount::new(amm::ID, Vec::new()).with_key(outcome.amm)),
+        );
+
+        accounts
+    }
+
+    #[test]
+    fn the_queue_head_is_promoted_when_a_slot_frees_up() {
+        let dao_key = Pubkey::new_unique();
+        let dao = queued_dao(1);
+        let proposal = queued_proposal(dao_key, 3);
+        let mut accounts = queued_accounts(&proposal);
+        let accounts = infos(&mut accounts);
+
+        let (queued_proposal_info, queued_proposal, queued_amms) =
+            get_next_queued_proposal(&dao_key, &dao, &accounts)
+                .unwrap()
+                .unwrap();
+
+        assert_eq!(queued_proposal_info.key, accounts[0].key);
+        assert_eq!(queued_proposal.number, proposal.number);
+        assert_eq!(queued_amms.len(), 2);
+    }
+
+    #[test]
+    fn nothing_is_promoted_from_an_empty_queue() {
+        let dao_key = Pubkey::new_unique();
+        let mut dao = queued_dao(0);
+        dao.queue_head = dao.queue_tail;
+
+        assert!(get_next_queued_proposal(&dao_key, &dao, &[])
+            .unwrap()
+            .is_none());
+
+        let mut accounts = queued_accounts(&queued_proposal(dao_key, 5));
+
+        assert_eq!(
+            get_next_queued_proposal(&dao_key, &dao, &infos(&mut accounts)).map(|_| ()),
+            err!(ErrorCode::InvalidQueuedProposal)
+        );
+    }
+
+    #[test]
+    fn nothing_is_promoted_while_the_dao_is_at_capacity() {
+        let dao_key = Pubkey::new_unique();
+        let dao = queued_dao(2);
+
+        assert!(get_next_queued_proposal(&dao_key, &dao, &[])
+            .unwrap()
+            .is_none());
+
+        let mut accounts = queued_accounts(&queued_proposal(dao_key, 3));
+
+        assert_eq!(
+            get_next_queued_proposal(&dao_key, &dao, &infos(&mut accounts)).map(|_| ()),
+            err!(ErrorCode::InvalidQueuedProposal)
+        );
+    }
+
+    #[test]
+    fn only_the_queue_head_can_be_promoted() {
+        let dao_key = Pubkey::new_unique();
+        let dao = queued_dao(1);
+
+        // missing, out of order, or not from this DAO
+        assert_eq!(
+            get_next_queued_proposal(&dao_key, &dao, &[]).map(|_| ()),
+            err!(ErrorCode::InvalidQueuedProposal)
+        );
+
+        for proposal in [
+            queued_proposal(dao_key, 4),
+            queued_proposal(Pubkey::new_unique(), 3),
+        ] {
+            let mut accounts = queued_accounts(&proposal);
+
+            assert_eq!(
+                get_next_queued_proposal(&dao_key, &dao, &infos(&mut accounts)).map(|_| ()),
+                err!(ErrorCode::InvalidQueuedProposal)
+            );
+        }
+    }
+
+    #[test]
+    fn the_queued_proposals_amms_must_be_passed_exactly() {
+        let dao_key = Pubkey::new_unique();
+        let dao = queued_dao(1);
+        let proposal = queued_proposal(dao_key, 3);
+
+        // a trailing account after the AMMs
+        let mut accounts = queued_accounts(&proposal);
+        accounts.push(TestAccount::new(Pubkey::new_unique(), Vec::new()));
+
+        assert_eq!(
+            get_next_queued_proposal(&dao_key, &dao, &infos(&mut accounts)).map(|_| ()),
+            err!(ErrorCode::InvalidOutcomeAccounts)
+        );
+
+        // an AMM that isn't the proposal's
+        let mut accounts = queued_accounts(&proposal);
+        accounts[2].key = Pubkey::new_unique();
+
+        assert_eq!(
+            get_next_queued_proposal(&dao_key, &dao, &infos(&mut accounts)).map(|_| ()),
+            err!(ErrorCode::AmmProposalMismatch)
+        );
+    }
+}
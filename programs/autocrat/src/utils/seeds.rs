@@ -1,6 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
 #[macro_export]
-macro_rules! generate_proposal_vault_seeds {
-    ($proposal:expr, $bump:expr) => {{
-        &[PROPOSAL_VAULT_SEED_PREFIX, $proposal.as_ref(), &[$bump]]
+macro_rules! generate_proposal_seeds {
+    ($dao:expr, $number:expr, $bump:expr) => {{
+        &[
+            PROPOSAL_SEED_PREFIX,
+            $dao.as_ref(),
+            $number.as_ref(),
+            &[$bump],
+        ]
     }};
 }
+
+#[macro_export]
+macro_rules! generate_conditional_vault_seeds {
+    ($proposal:expr, $underlying_mint:expr, $bump:expr) => {{
+        &[
+            CONDITIONAL_VAULT_SEED_PREFIX,
+            $proposal.as_ref(),
+            $underlying_mint.as_ref(),
+            &[$bump],
+        ]
+    }};
+}
+
+// the conditional mint of an outcome, for one of the proposal's underlying mints.
+// Together with the proposal's address, this lets clients derive every
+// conditional mint of a proposal without reading it
+pub fn get_conditional_mint_address(
+    proposal: &Pubkey,
+    outcome_index: u8,
+    underlying_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CONDITIONAL_MINT_SEED_PREFIX,
+            proposal.as_ref(),
+            &[outcome_index],
+            underlying_mint.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
@@ -0,0 +1,506 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::address_lookup_table;
+use anchor_lang::solana_program::address_lookup_table::state::AddressLookupTable;
+use anchor_lang::solana_program::address_lookup_table::state::LookupTableMeta;
+use anchor_lang::solana_program::bpf_loader;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::program_stubs;
+use anchor_lang::solana_program::program_stubs::SyscallStubs;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::solana_program::sysvar::rent;
+use anchor_lang::Bumps;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensionsMut;
+
+use crate::state::*;
+use crate::utils::get_conditional_mint_address;
+
+// owns the data that an `AccountInfo` borrows, so that handlers' account
+// checks can be run against accounts built in tests
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    pub executable: bool,
+}
+
+impl TestAccount {
+    pub fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key: Pubkey::new_unique(),
+            owner,
+            lamports: 1_000_000,
+            data,
+            is_signer: false,
+            is_writable: false,
+            executable: false,
+        }
+    }
+
+    // an address that nothing has been created at yet
+    pub fn empty() -> Self {
+        Self {
+            lamports: 0,
+            ..Self::new(system_program::ID, Vec::new())
+        }
+    }
+
+    pub fn mint(supply: u64) -> Self {
+        Self::mint_with_authority(supply, None)
+    }
+
+    pub fn mint_with_authority(supply: u64, mint_authority: Option<Pubkey>) -> Self {
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: mint_authority.into(),
+            supply,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+
+        Self::new(spl_token::ID, data)
+    }
+
+    // a token-2022 mint, with a transfer fee extension if `has_transfer_fee`
+    pub fn mint_2022(supply: u64, has_transfer_fee: bool) -> Self {
+        let extensions: &[ExtensionType] = if has_transfer_fee {
+            &[ExtensionType::TransferFeeConfig]
+        } else {
+            &[]
+        };
+        let len =
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(extensions)
+                .unwrap();
+        let mut data = vec![0; len];
+
+        let mut mint =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+                .unwrap();
+        if has_transfer_fee {
+            mint.init_extension::<TransferFeeConfig>(true).unwrap();
+        }
+        mint.base = spl_token_2022::state::Mint {
+            mint_authority: COption::None,
+            supply,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+
+        Self::new(spl_token_2022::ID, data)
+    }
+
+    // `owner`'s associated token account of an SPL token `mint`
+    pub fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Self {
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+
+        Self::new(spl_token::ID, data).with_key(get_associated_token_address_with_program_id(
+            owner,
+            mint,
+            &spl_token::ID,
+        ))
+    }
+
+    pub fn proposal(proposal: &Proposal) -> Self {
+        Self::account(proposal)
+    }
+
+    // an account of this program
+    pub fn account<T: AccountSerialize>(account: &T) -> Self {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+
+        Self::new(crate::ID, data)
+    }
+
+    pub fn program(program_id: Pubkey) -> Self {
+        Self {
+            executable: true,
+            ..Self::new(bpf_loader::ID, Vec::new()).with_key(program_id)
+        }
+    }
+
+    // the rent sysvar, laid out as bincode does
+    pub fn rent() -> Self {
+        let rent = Rent::default();
+        let data = [
+            &rent.lamports_per_byte_year.to_le_bytes()[..],
+            &rent.exemption_threshold.to_le_bytes(),
+            &[rent.burn_percent],
+        ]
+        .concat();
+
+        Self::new(rent::ID, data).with_key(rent::ID)
+    }
+
+    pub fn lookup_table(addresses: &[Pubkey]) -> Self {
+        let data = AddressLookupTable {
+            meta: LookupTableMeta::new(Pubkey::new_unique()),
+            addresses: Cow::Borrowed(addresses),
+        }
+        .serialize_for_tests()
+        .unwrap();
+
+        Self::new(address_lookup_table::program::ID, data)
+    }
+
+    pub fn with_key(mut self, key: Pubkey) -> Self {
+        self.key = key;
+        self
+    }
+
+    // pads the data to the space the account is allocated with on chain
+    pub fn with_space(mut self, space: usize) -> Self {
+        self.data.resize(space, 0);
+        self
+    }
+
+    pub fn signer(mut self) -> Self {
+        self.is_signer = true;
+        self
+    }
+
+    pub fn writable(mut self) -> Self {
+        self.is_writable = true;
+        self
+    }
+
+    pub fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            self.is_writable,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            self.executable,
+            0,
+        )
+    }
+}
+
+pub fn infos(accounts: &mut [TestAccount]) -> Vec<AccountInfo<'_>> {
+    accounts.iter_mut().map(TestAccount::info).collect()
+}
+
+// leaked, for handlers that need the info to live as long as the data it
+// borrows. Clones of the info share its data and lamports.
+pub fn leak_info(account: TestAccount) -> AccountInfo<'static> {
+    Box::leak(Box::new(account)).info()
+}
+
+// reads an account of this program, or a token account or mint
+pub fn read<T: AccountDeserialize>(info: &AccountInfo) -> T {
+    T::try_deserialize(&mut info.data.borrow().as_ref()).unwrap()
+}
+
+// validates `accounts`, followed by the remaining accounts, as `T`, runs
+// `handler` on them and writes back the accounts it changed, like an
+// instruction of this program with `ix_data` as its arguments
+pub fn run_instruction<T>(
+    accounts: &[AccountInfo<'static>],
+    ix_data: &[u8],
+    handler: impl FnOnce(Context<'_, '_, 'static, 'static, T>) -> Result<()>,
+) -> Result<()>
+where
+    T: Bumps + Accounts<'static, T::Bumps> + AccountsExit<'static>,
+    T::Bumps: Default,
+{
+    let mut accounts: &'static [AccountInfo<'static>] = Vec::leak(accounts.to_vec());
+    let mut bumps = T::Bumps::default();
+    let mut validated = T::try_accounts(
+        &crate::ID,
+        &mut accounts,
+        ix_data,
+        &mut bumps,
+        &mut BTreeSet::new(),
+    )?;
+
+    handler(Context::new(&crate::ID, &mut validated, accounts, bumps))?;
+
+    validated.exit(&crate::ID)
+}
+
+thread_local! {
+    static INVOKED_INSTRUCTIONS: RefCell<Vec<Instruction>> = const { RefCell::new(Vec::new()) };
+    static RUNS_TOKEN_PROGRAM: Cell<bool> = const { Cell::new(false) };
+}
+
+struct RecordingSyscallStubs;
+
+impl SyscallStubs for RecordingSyscallStubs {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        INVOKED_INSTRUCTIONS.with(|invoked| invoked.borrow_mut().push(instruction.clone()));
+
+        if !RUNS_TOKEN_PROGRAM.get() || instruction.program_id != spl_token::ID {
+            return Ok(());
+        }
+
+        // the accounts in the instruction's order, signed as the instruction says
+        // since the runtime checks the signer seeds
+        let account_infos = instruction
+            .accounts
+            .iter()
+            .map(|meta| {
+                let mut info = account_infos
+                    .iter()
+                    .find(|info| info.key == &meta.pubkey)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?
+                    .clone();
+                info.is_signer = meta.is_signer;
+                info.is_writable = meta.is_writable;
+                Ok(info)
+            })
+            .collect::<std::result::Result<Vec<_>, ProgramError>>()?;
+
+        spl_token::processor::Processor::process(
+            &instruction.program_id,
+            &account_infos,
+            &instruction.data,
+        )
+    }
+}
+
+fn set_syscall_stubs() {
+    static SET_SYSCALL_STUBS: Once = Once::new();
+    SET_SYSCALL_STUBS.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(RecordingSyscallStubs));
+    });
+}
+
+// the instructions that `f` invokes, which are recorded instead of being run
+pub fn invoked_instructions(f: impl FnOnce() -> Result<()>) -> Result<Vec<Instruction>> {
+    set_syscall_stubs();
+
+    INVOKED_INSTRUCTIONS.with(|invoked| invoked.borrow_mut().clear());
+    f()?;
+
+    Ok(INVOKED_INSTRUCTIONS.with(|invoked| invoked.take()))
+}
+
+// runs `f` with the SPL token program's instructions run against the accounts
+// they're invoked with, as they would be on chain. Other programs' instructions
+// are only recorded.
+pub fn with_token_program<R>(f: impl FnOnce() -> Result<R>) -> Result<R> {
+    set_syscall_stubs();
+
+    RUNS_TOKEN_PROGRAM.set(true);
+    let result = f();
+    RUNS_TOKEN_PROGRAM.set(false);
+
+    result
+}
+
+// a DAO with the default config and no proposals
+pub fn test_dao() -> Dao {
+    Dao {
+        bump: 255,
+        treasury_pda_bump: 255,
+        treasury_pda: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        meta_mint: Pubkey::new_unique(),
+        usdc_mint: Pubkey::new_unique(),
+        meta_mint_decimals: 9,
+        usdc_mint_decimals: 6,
+        meta_ticker: encode_ticker("META").unwrap(),
+        usdc_ticker: encode_ticker("USDC").unwrap(),
+        proposal_count: 0,
+        proposals_active: 0,
+        last_proposal_slot: 0,
+        queue_head: 0,
+        queue_tail: 0,
+        guardian: Pubkey::default(),
+        config: DaoConfig::default_for_usdc_decimals(6).unwrap(),
+    }
+}
+
+// a submitted proposal with `outcome_count` outcomes and default parameters
+pub fn test_proposal(dao: Pubkey, number: u64, outcome_count: usize) -> Proposal {
+    Proposal {
+        dao,
+        number,
+        proposer: Pubkey::new_unique(),
+        description_url: String::new(),
+        slot_enqueued: 0,
+        state: ProposalState::Pending,
+        queue_position: 0,
+        conditional_vault_count: 2,
+        meta_mint: Pubkey::new_unique(),
+        usdc_mint: Pubkey::new_unique(),
+        outcomes: (0..outcome_count)
+            .map(|_| ProposalOutcome {
+                is_market_created: true,
+                amm: Pubkey::new_unique(),
+                conditional_meta_mint: Pubkey::new_unique(),
+                conditional_usdc_mint: Pubkey::new_unique(),
+                instructions: Pubkey::default(),
+            })
+            .collect(),
+        winning_outcome_index: STATUS_QUO_OUTCOME_INDEX,
+        scalar: None,
+        prerequisites: Vec::new(),
+        dependent_count: 0,
+        proposer_inititial_conditional_meta_minted: 0,
+        proposer_inititial_conditional_usdc_minted: 0,
+        slots_duration: PROPOSAL_DURATION_SLOTS,
+        finalize_window_slots: FINALIZE_WINDOW_SLOTS,
+        execution_window_slots: EXECUTION_WINDOW_SLOTS,
+        execution_delay_slots: EXECUTION_DELAY_SLOTS,
+        pass_threshold_bps: DEFAULT_PASS_THRESHOLD_BPS,
+        finalizer_bounty_usdc: 0,
+        proposal_fee_refund_bps: DEFAULT_PROPOSAL_FEE_REFUND_BPS,
+        slot_finalized: 0,
+        amm_swap_fee_bps: AMM_SWAP_FEE_BPS,
+        amm_ltwap_decimals: AMM_LTWAP_DECIMALS,
+    }
+}
+
+// a vault's accounts, writable so that they can be passed to any instruction
+pub struct TestVault {
+    pub vault: AccountInfo<'static>,
+    pub underlying_mint: AccountInfo<'static>,
+    pub underlying_token_account: AccountInfo<'static>,
+    // in outcome order
+    pub conditional_mints: Vec<AccountInfo<'static>>,
+}
+
+pub struct TestProposalAccounts {
+    pub proposal: AccountInfo<'static>,
+    pub meta_vault: TestVault,
+    pub usdc_vault: TestVault,
+    pub token_program: AccountInfo<'static>,
+}
+
+// `proposal` at its address, with a META and a USDC vault that have no
+// deposits. Outcomes whose markets have been created have an SPL token
+// conditional mint in each vault, the others an empty account at its address.
+pub fn test_proposal_accounts(mut proposal: Proposal) -> TestProposalAccounts {
+    let (proposal_key, _) = Pubkey::find_program_address(
+        &[
+            PROPOSAL_SEED_PREFIX,
+            proposal.dao.as_ref(),
+            proposal.number.to_le_bytes().as_ref(),
+        ],
+        &crate::ID,
+    );
+    let is_market_created: Vec<bool> = proposal
+        .outcomes
+        .iter()
+        .map(|outcome| outcome.is_market_created)
+        .collect();
+
+    let test_vault = |underlying_mint: Pubkey| {
+        let (vault_key, bump) = Pubkey::find_program_address(
+            &[
+                CONDITIONAL_VAULT_SEED_PREFIX,
+                proposal_key.as_ref(),
+                underlying_mint.as_ref(),
+            ],
+            &crate::ID,
+        );
+        let conditional_mint_keys: Vec<Pubkey> = (0..proposal.outcomes.len())
+            .map(|outcome_index| {
+                get_conditional_mint_address(&proposal_key, outcome_index as u8, &underlying_mint).0
+            })
+            .collect();
+        let underlying_token_account = TestAccount::token_account(&underlying_mint, &vault_key, 0);
+
+        let vault = ConditionalVault {
+            bump,
+            settlement_authority: proposal_key,
+            underlying_mint,
+            underlying_token_account: underlying_token_account.key,
+            status: VaultStatus::Active,
+            conditional_mints: conditional_mint_keys
+                .iter()
+                .zip(&is_market_created)
+                .map(|(key, is_created)| if *is_created { *key } else { Pubkey::default() })
+                .collect(),
+        };
+
+        TestVault {
+            vault: leak_info(
+                TestAccount::account(&vault)
+                    .with_key(vault_key)
+                    .with_space(8 + ConditionalVault::INIT_SPACE)
+                    .writable(),
+            ),
+            underlying_mint: leak_info(TestAccount::mint(u64::MAX).with_key(underlying_mint)),
+            underlying_token_account: leak_info(underlying_token_account.writable()),
+            conditional_mints: conditional_mint_keys
+                .iter()
+                .zip(&is_market_created)
+                .map(|(key, is_created)| {
+                    let conditional_mint = if *is_created {
+                        TestAccount::mint_with_authority(0, Some(vault_key))
+                    } else {
+                        TestAccount::empty()
+                    };
+                    leak_info(conditional_mint.with_key(*key).writable())
+                })
+                .collect(),
+        }
+    };
+
+    let meta_vault = test_vault(proposal.meta_mint);
+    let usdc_vault = test_vault(proposal.usdc_mint);
+
+    for (outcome, (conditional_meta_mint, conditional_usdc_mint)) in
+        proposal.outcomes.iter_mut().zip(
+            meta_vault
+                .conditional_mints
+                .iter()
+                .zip(&usdc_vault.conditional_mints),
+        )
+    {
+        if outcome.is_market_created {
+            outcome.conditional_meta_mint = *conditional_meta_mint.key;
+            outcome.conditional_usdc_mint = *conditional_usdc_mint.key;
+        }
+    }
+
+    TestProposalAccounts {
+        proposal: leak_info(
+            TestAccount::proposal(&proposal)
+                .with_key(proposal_key)
+                .with_space(8 + Proposal::INIT_SPACE)
+                .writable(),
+        ),
+        meta_vault,
+        usdc_vault,
+        token_program: leak_info(TestAccount::program(spl_token::ID)),
+    }
+}
@@ -1,5 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::BaseStateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_interface;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
 
 pub fn token_mint_signed<
     'info,
@@ -16,15 +24,15 @@ pub fn token_mint_signed<
     seeds: &[&[u8]],
 ) -> Result<()> {
     if amount > 0 {
-        token::mint_to(
+        token_interface::mint_to(
             CpiContext::new_with_signer(
                 token_program.to_account_info(),
-                token::MintTo {
+                token_interface::MintTo {
                     mint: mint.to_account_info(),
                     to: to.to_account_info(),
                     authority: authority.to_account_info(),
                 },
-                &[&seeds[..]],
+                &[seeds],
             ),
             amount,
         )?
@@ -47,10 +55,10 @@ pub fn token_burn<
     authority: &A,
 ) -> Result<()> {
     if amount > 0 {
-        token::burn(
+        token_interface::burn(
             CpiContext::new(
                 token_program.to_account_info(),
-                token::Burn {
+                token_interface::Burn {
                     mint: mint.to_account_info(),
                     from: from.to_account_info(),
                     authority: authority.to_account_info(),
@@ -63,6 +71,39 @@ pub fn token_burn<
     Ok(())
 }
 
+pub fn token_burn_signed<
+    'info,
+    P: ToAccountInfo<'info>,
+    M: ToAccountInfo<'info>,
+    F: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
+    amount: u64,
+    token_program: &P,
+    mint: &M,
+    from: &F,
+    authority: &A,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    if amount > 0 {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::Burn {
+                    mint: mint.to_account_info(),
+                    from: from.to_account_info(),
+                    authority: authority.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?
+    }
+
+    Ok(())
+}
+
+// with a transfer fee, `to` receives less than `amount`, see `token_received`
 pub fn token_transfer<
     'info,
     P: ToAccountInfo<'info>,
@@ -73,20 +114,23 @@ pub fn token_transfer<
     amount: u64,
     token_program: &P,
     from: &F,
+    mint: &InterfaceAccount<'info, Mint>,
     to: &T,
     authority: &A,
 ) -> Result<()> {
     if amount > 0 {
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new(
                 token_program.to_account_info(),
-                token::Transfer {
+                token_interface::TransferChecked {
                     from: from.to_account_info(),
+                    mint: mint.to_account_info(),
                     to: to.to_account_info(),
                     authority: authority.to_account_info(),
                 },
             ),
             amount,
+            mint.decimals,
         )?
     }
 
@@ -103,24 +147,170 @@ pub fn token_transfer_signed<
     amount: u64,
     token_program: &P,
     from: &F,
+    mint: &InterfaceAccount<'info, Mint>,
     to: &T,
     authority: &A,
     seeds: &[&[u8]],
 ) -> Result<()> {
     if amount > 0 {
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 token_program.to_account_info(),
-                token::Transfer {
+                token_interface::TransferChecked {
                     from: from.to_account_info(),
+                    mint: mint.to_account_info(),
                     to: to.to_account_info(),
                     authority: authority.to_account_info(),
                 },
-                &[&seeds[..]],
+                &[seeds],
             ),
             amount,
+            mint.decimals,
         )?
     }
 
     Ok(())
 }
+
+// what `account` has received since its balance was `balance_before`. Anything
+// credited against tokens that came in has to use this rather than the amount
+// sent, which includes the transfer fee of a token-2022 mint that charges one
+pub fn token_received<'info>(
+    account: &mut InterfaceAccount<'info, TokenAccount>,
+    balance_before: u64,
+) -> Result<u64> {
+    account.reload()?;
+
+    Ok(account.amount.checked_sub(balance_before).unwrap())
+}
+
+// token-2022 won't close an account that still holds withheld transfer fees,
+// so they're moved to the mint first, where the mint's withdraw authority can
+// collect them. Does nothing for mints without a transfer fee.
+pub fn token_harvest_withheld_fees<'info, P: ToAccountInfo<'info>, C: ToAccountInfo<'info>>(
+    token_program: &P,
+    mint: &InterfaceAccount<'info, Mint>,
+    account: &C,
+) -> Result<()> {
+    let mint_info = mint.to_account_info();
+
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let has_transfer_fee = {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        mint_state.get_extension::<TransferFeeConfig>().is_ok()
+    };
+
+    if has_transfer_fee {
+        let account_info = account.to_account_info();
+        invoke(
+            &harvest_withheld_tokens_to_mint(
+                token_program.to_account_info().key,
+                mint_info.key,
+                &[account_info.key],
+            )?,
+            &[mint_info, account_info],
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn token_close_account<
+    'info,
+    P: ToAccountInfo<'info>,
+    C: ToAccountInfo<'info>,
+    D: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
+    token_program: &P,
+    account: &C,
+    destination: &D,
+    authority: &A,
+) -> Result<()> {
+    token_interface::close_account(CpiContext::new(
+        token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: account.to_account_info(),
+            destination: destination.to_account_info(),
+            authority: authority.to_account_info(),
+        },
+    ))
+}
+
+pub fn token_close_account_signed<
+    'info,
+    P: ToAccountInfo<'info>,
+    C: ToAccountInfo<'info>,
+    D: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+>(
+    token_program: &P,
+    account: &C,
+    destination: &D,
+    authority: &A,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: account.to_account_info(),
+            destination: destination.to_account_info(),
+            authority: authority.to_account_info(),
+        },
+        &[seeds],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::instruction::Instruction;
+
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    // the instructions invoked to harvest the withheld fees of an escrow account
+    fn harvest_instructions(mint: &mut TestAccount, escrow: &mut TestAccount) -> Vec<Instruction> {
+        let mut token_program =
+            TestAccount::new(Pubkey::new_unique(), Vec::new()).with_key(mint.owner);
+        token_program.executable = true;
+
+        let mint_info = mint.info();
+        let mint = InterfaceAccount::<Mint>::try_from(&mint_info).unwrap();
+
+        invoked_instructions(|| {
+            token_harvest_withheld_fees(&token_program.info(), &mint, &escrow.info())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn withheld_transfer_fees_are_harvested_to_the_mint() {
+        let mut mint = TestAccount::mint_2022(1_000_000, true);
+        let mut escrow = TestAccount::new(spl_token_2022::ID, Vec::new());
+        escrow.is_writable = true;
+
+        assert_eq!(
+            harvest_instructions(&mut mint, &mut escrow),
+            vec![
+                harvest_withheld_tokens_to_mint(&spl_token_2022::ID, &mint.key, &[&escrow.key])
+                    .unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn mints_without_a_transfer_fee_have_nothing_to_harvest() {
+        for mut mint in [
+            TestAccount::mint(1_000_000),
+            TestAccount::mint_2022(1_000_000, false),
+        ] {
+            let mut escrow = TestAccount::new(mint.owner, Vec::new());
+
+            assert!(harvest_instructions(&mut mint, &mut escrow).is_empty());
+        }
+    }
+}
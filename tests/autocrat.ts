@@ -12,13 +12,14 @@ import {
     createMint,
     createAssociatedTokenAccount,
     getAccount,
+    getMint,
     mintTo,
 } from "spl-token-bankrun";
 
 import { assert } from "chai";
 
 import { AutocratClient } from "../app/src/AutocratClient";
-import { getATA, getAmmPositionAddr, getDaoAddr, getDaoTreasuryAddr, getProposalAddr, getProposalInstructionsAddr, sleep } from "../app/src/utils";
+import { getATA, getAmmPositionAddr, getDaoAddr, getDaoTreasuryAddr, getProposalAddr, getProposalInstructionsAddr, getConditionalVaultAddr, sleep } from "../app/src/utils";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { AmmClient } from "../app/src/AmmClient";
 import { InstructionHandler } from "../app/src/InstructionHandler";
@@ -34,6 +35,7 @@ describe("autocrat", async function () {
         context,
         banksClient,
         dao,
+        daoAddr,
         daoTreasury,
         META,
         USDC,
@@ -83,16 +85,19 @@ describe("autocrat", async function () {
     describe("#initialize_dao", async function () {
         it("initializes the DAO", async function () {
 
-            let ixh = await autocratClient.initializeDao(META, USDC);
+            let ixh = await autocratClient.initializeDao(META, USDC, "META", "USDC");
             await ixh.bankrun(banksClient);
 
-            [dao] = getDaoAddr(autocratClient.program.programId);
-            [daoTreasury] = getDaoTreasuryAddr(autocratClient.program.programId);
+            [daoAddr] = getDaoAddr(autocratClient.program.programId, payer.publicKey, META);
+            [daoTreasury] = getDaoTreasuryAddr(autocratClient.program.programId, daoAddr);
 
-            const daoAcc = await autocratClient.program.account.dao.fetch(dao);
+            const daoAcc = await autocratClient.program.account.dao.fetch(daoAddr);
 
+            assert(daoAcc.creator.equals(payer.publicKey));
             assert(daoAcc.metaMint.equals(META));
             assert(daoAcc.usdcMint.equals(USDC));
+            assert.equal(Buffer.from(daoAcc.metaTicker).toString().replace(/\0+$/, ""), "META");
+            assert.equal(Buffer.from(daoAcc.usdcTicker).toString().replace(/\0+$/, ""), "USDC");
 
             assert.equal(daoAcc.proposalCount, 10);
             assert.equal(daoAcc.passThresholdBps, 500);
@@ -145,7 +150,7 @@ describe("autocrat", async function () {
     describe("#update_dao", async function () {
         it("updates the DAO", async function () {
 
-            let ixh = await autocratClient.updateDao({
+            let ixh = await autocratClient.updateDao(daoAddr, {
                 passThresholdBps: new BN(123),
                 proposalDurationSlots: new BN(69_420),
                 finalizeWindowSlots: new BN(69_420),
@@ -156,18 +161,17 @@ describe("autocrat", async function () {
             });
             await ixh.bankrun(banksClient);
 
-            let [daoAddr] = getDaoAddr(autocratClient.program.programId);
             dao = await autocratClient.program.account.dao.fetch(daoAddr);
 
             proposalNumber = dao.proposalCount
-            proposalAddr = getProposalAddr(autocratClient.program.programId, proposalNumber)[0]
+            proposalAddr = getProposalAddr(autocratClient.program.programId, daoAddr, proposalNumber)[0]
 
             assert.equal(dao.passThresholdBps, 123);
-            assert.equal(dao.proposalDurationSlots, 69_420);
-            assert.equal(dao.finalizeWindowSlots, 69_420);
-            assert.equal(dao.ammInitialQuoteLiquidityAmount, 100_000_005);
-            assert.equal(dao.ammSwapFeeBps, 600);
-            assert.equal(dao.ammLtwapDecimals, 9);
+            assert.equal(dao.config.proposalDurationSlots, 69_420);
+            assert.equal(dao.config.finalizeWindowSlots, 69_420);
+            assert.equal(dao.config.ammInitialQuoteLiquidityAmount, 100_000_005);
+            assert.equal(dao.config.ammSwapFeeBps, 600);
+            assert.equal(dao.config.ammLtwapDecimals, 9);
         });
     });
 
@@ -176,10 +180,10 @@ describe("autocrat", async function () {
 
             const proposalDescription = "https://based-proposals.com/10"
 
-            let daoAddr = getDaoAddr(autocratClient.program.programId)[0];
             const daoAccStart = await autocratClient.program.account.dao.fetch(daoAddr);
 
             let ixh = await autocratClient.createProposal(
+                daoAddr,
                 proposalNumber,
                 proposalDescription,
                 new BN(10 * 10 ** 9),
@@ -218,7 +222,7 @@ describe("autocrat", async function () {
                 accounts: [],
             };
 
-            let ixh = await autocratClient.createProposalInstructions(proposalNumber, [memoInstruction]);
+            let ixh = await autocratClient.createProposalInstructions(daoAddr, proposalNumber, [memoInstruction]);
             await ixh.bankrun(banksClient);
 
             proposalInstructionsAddr = getProposalInstructionsAddr(autocratClient.program.programId, proposalAddr)[0]
@@ -239,7 +243,7 @@ describe("autocrat", async function () {
                 accounts: [],
             };
 
-            let ixh = await autocratClient.addProposalInstructions(proposalNumber, [memoInstruction, memoInstruction]);
+            let ixh = await autocratClient.addProposalInstructions(daoAddr, proposalNumber, [memoInstruction, memoInstruction]);
             await ixh.bankrun(banksClient);
 
             const instructionsAcc = await autocratClient.program.account.proposalInstructions.fetch(proposalInstructionsAddr);
@@ -252,6 +256,7 @@ describe("autocrat", async function () {
         it("creates a proposal [pass] market", async function () {
 
             let ixh = await autocratClient.createProposalMarketSide(
+                daoAddr,
                 proposalNumber,
                 true,
                 new BN(10 * 10 ** 9),
@@ -286,6 +291,7 @@ describe("autocrat", async function () {
         it("creates a proposal [fail] market", async function () {
 
             let ixh = await autocratClient.createProposalMarketSide(
+                daoAddr,
                 proposalNumber,
                 false,
                 new BN(10 * 10 ** 9),
@@ -318,6 +324,7 @@ describe("autocrat", async function () {
             let startUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.usdcMint, payer.publicKey)[0])).amount
 
             let ixh = await autocratClient.submitProposal(
+                daoAddr,
                 proposalNumber,
             );
             await ixh
@@ -338,39 +345,35 @@ describe("autocrat", async function () {
 
             const proposalAcc = await autocratClient.program.account.proposal.fetch(proposalAddr);
 
-            const metaToMint = 100 * 10 ** 9
-            const usdcToMint = 100_000 * 10 ** 6
-
-            let startMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.metaMint, payer.publicKey)[0])).amount
-            let startUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.usdcMint, payer.publicKey)[0])).amount
-
-            let startCondPassMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassMetaMint, payer.publicKey)[0])).amount
-            let startCondPassUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassUsdcMint, payer.publicKey)[0])).amount
-            let startCondFailMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailMetaMint, payer.publicKey)[0])).amount
-            let startCondFailUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailUsdcMint, payer.publicKey)[0])).amount
-
-            let ixh = await autocratClient.mintConditionalTokens(
-                proposalAddr,
-                new BN(metaToMint),
-                new BN(usdcToMint),
-            );
-            await ixh.bankrun(banksClient);
-
-            let endMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.metaMint, payer.publicKey)[0])).amount
-            let endUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.usdcMint, payer.publicKey)[0])).amount
-
-            let endCondPassMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassMetaMint, payer.publicKey)[0])).amount
-            let endCondPassUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassUsdcMint, payer.publicKey)[0])).amount
-            let endCondFailMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailMetaMint, payer.publicKey)[0])).amount
-            let endCondFailUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailUsdcMint, payer.publicKey)[0])).amount
-
-            assert.equal(endCondPassMetaBalance - startCondPassMetaBalance, BigInt(metaToMint))
-            assert.equal(endCondPassUsdcBalance - startCondPassUsdcBalance, BigInt(usdcToMint))
-            assert.equal(endCondFailMetaBalance - startCondFailMetaBalance, BigInt(metaToMint))
-            assert.equal(endCondFailUsdcBalance - startCondFailUsdcBalance, BigInt(usdcToMint))
-
-            assert.equal(startMetaBalance - endMetaBalance, BigInt(metaToMint))
-            assert.equal(startUsdcBalance - endUsdcBalance, BigInt(usdcToMint))
+            for (const [underlyingMint, amount, conditionalMintKey] of [
+                [proposalAcc.metaMint, BigInt(100 * 10 ** 9), "conditionalMetaMint"],
+                [proposalAcc.usdcMint, BigInt(100_000 * 10 ** 6), "conditionalUsdcMint"],
+            ]) {
+                const vaultAddr = getConditionalVaultAddr(autocratClient.program.programId, proposalAddr, underlyingMint)[0]
+                const balance = async (mint: PublicKey, owner: PublicKey) => (await getAccount(banksClient, getATA(mint, owner)[0])).amount
+                const conditionalBalances = () => Promise.all(proposalAcc.outcomes.map(outcome => balance(outcome[conditionalMintKey], payer.publicKey)))
+
+                let startBalance = await balance(underlyingMint, payer.publicKey)
+                let startVaultBalance = await balance(underlyingMint, vaultAddr)
+                let startConditionalBalances = await conditionalBalances()
+
+                let ixh = await autocratClient.mintConditionalTokens(
+                    proposalAddr,
+                    underlyingMint,
+                    new BN(amount.toString()),
+                );
+                await ixh.bankrun(banksClient);
+
+                let endConditionalBalances = await conditionalBalances()
+
+                // a conditional token of every outcome for each underlying token deposited
+                endConditionalBalances.forEach((endConditionalBalance, outcomeIndex) => {
+                    assert.equal(endConditionalBalance - startConditionalBalances[outcomeIndex], amount)
+                })
+
+                assert.equal(startBalance - await balance(underlyingMint, payer.publicKey), amount)
+                assert.equal(await balance(underlyingMint, vaultAddr) - startVaultBalance, amount)
+            }
         });
     });
 
@@ -379,39 +382,34 @@ describe("autocrat", async function () {
 
             const proposalAcc = await autocratClient.program.account.proposal.fetch(proposalAddr);
 
-            const metaToMerge = 1 * 10 ** 9
-            const usdcToMerge = 1_000 * 10 ** 6
-
-            let startMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.metaMint, payer.publicKey)[0])).amount
-            let startUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.usdcMint, payer.publicKey)[0])).amount
-
-            let startCondPassMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassMetaMint, payer.publicKey)[0])).amount
-            let startCondPassUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassUsdcMint, payer.publicKey)[0])).amount
-            let startCondFailMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailMetaMint, payer.publicKey)[0])).amount
-            let startCondFailUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailUsdcMint, payer.publicKey)[0])).amount
-
-            let ixh = await autocratClient.mergeConditionalTokens(
-                proposalAddr,
-                new BN(metaToMerge),
-                new BN(usdcToMerge),
-            );
-            await ixh.bankrun(banksClient);
-
-            let endMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.metaMint, payer.publicKey)[0])).amount
-            let endUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.usdcMint, payer.publicKey)[0])).amount
-
-            let endCondPassMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassMetaMint, payer.publicKey)[0])).amount
-            let endCondPassUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassUsdcMint, payer.publicKey)[0])).amount
-            let endCondFailMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailMetaMint, payer.publicKey)[0])).amount
-            let endCondFailUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailUsdcMint, payer.publicKey)[0])).amount
-
-            assert.equal(startCondPassMetaBalance - endCondPassMetaBalance, BigInt(metaToMerge))
-            assert.equal(startCondPassUsdcBalance - endCondPassUsdcBalance, BigInt(usdcToMerge))
-            assert.equal(startCondFailMetaBalance - endCondFailMetaBalance, BigInt(metaToMerge))
-            assert.equal(startCondFailUsdcBalance - endCondFailUsdcBalance, BigInt(usdcToMerge))
-
-            assert.equal(endMetaBalance - startMetaBalance, BigInt(metaToMerge))
-            assert.equal(endUsdcBalance - startUsdcBalance, BigInt(usdcToMerge))
+            for (const [underlyingMint, amount, conditionalMintKey] of [
+                [proposalAcc.metaMint, BigInt(1 * 10 ** 9), "conditionalMetaMint"],
+                [proposalAcc.usdcMint, BigInt(1_000 * 10 ** 6), "conditionalUsdcMint"],
+            ]) {
+                const vaultAddr = getConditionalVaultAddr(autocratClient.program.programId, proposalAddr, underlyingMint)[0]
+                const balance = async (mint: PublicKey, owner: PublicKey) => (await getAccount(banksClient, getATA(mint, owner)[0])).amount
+                const conditionalBalances = () => Promise.all(proposalAcc.outcomes.map(outcome => balance(outcome[conditionalMintKey], payer.publicKey)))
+
+                let startBalance = await balance(underlyingMint, payer.publicKey)
+                let startVaultBalance = await balance(underlyingMint, vaultAddr)
+                let startConditionalBalances = await conditionalBalances()
+
+                let ixh = await autocratClient.mergeConditionalTokens(
+                    vaultAddr,
+                    new BN(amount.toString()),
+                );
+                await ixh.bankrun(banksClient);
+
+                let endConditionalBalances = await conditionalBalances()
+
+                // a conditional token of every outcome is burned for each underlying token
+                endConditionalBalances.forEach((endConditionalBalance, outcomeIndex) => {
+                    assert.equal(startConditionalBalances[outcomeIndex] - endConditionalBalance, amount)
+                })
+
+                assert.equal(await balance(underlyingMint, payer.publicKey) - startBalance, amount)
+                assert.equal(startVaultBalance - await balance(underlyingMint, vaultAddr), amount)
+            }
         });
     });
 
@@ -571,6 +569,7 @@ describe("autocrat", async function () {
             }]
 
             let ixh = await autocratClient.finalizeProposal(
+                daoAddr,
                 proposalNumber,
                 accounts
             );
@@ -673,39 +672,94 @@ describe("autocrat", async function () {
         });
     });
 
+    describe("#settle_conditional_vault", async function () {
+        it("settles both of the proposal's vaults with its winning outcome", async function () {
+
+            const proposalAcc = await autocratClient.program.account.proposal.fetch(proposalAddr);
+
+            for (const underlyingMint of [proposalAcc.metaMint, proposalAcc.usdcMint]) {
+                const vaultAddr = getConditionalVaultAddr(autocratClient.program.programId, proposalAddr, underlyingMint)[0]
+
+                let ixh = await autocratClient.settleConditionalVault(vaultAddr);
+                await ixh.bankrun(banksClient);
+
+                const vault = await autocratClient.program.account.conditionalVault.fetch(vaultAddr);
+
+                assert.equal(vault.status.finalized.winningOutcomeIndex, proposalAcc.winningOutcomeIndex)
+            }
+        });
+    });
+
     describe("#redeem_conditional_tokens", async function () {
-        it("redeem conditional tokens from proposal", async function () {
+        it("redeem part of the winning conditional tokens from proposal", async function () {
 
             const proposalAcc = await autocratClient.program.account.proposal.fetch(proposalAddr);
 
-            let startMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.metaMint, payer.publicKey)[0])).amount
-            let startUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.usdcMint, payer.publicKey)[0])).amount
+            const balance = async (mint: PublicKey, owner: PublicKey) => (await getAccount(banksClient, getATA(mint, owner)[0])).amount
+            const supply = async (mint: PublicKey) => (await getMint(banksClient, mint)).supply
 
-            let startCondPassMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassMetaMint, payer.publicKey)[0])).amount
-            let startCondPassUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassUsdcMint, payer.publicKey)[0])).amount
+            for (const underlyingMint of [proposalAcc.metaMint, proposalAcc.usdcMint]) {
+                const vaultAddr = getConditionalVaultAddr(autocratClient.program.programId, proposalAddr, underlyingMint)[0]
+                const vault = await autocratClient.program.account.conditionalVault.fetch(vaultAddr);
 
-            let startCondFailMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailMetaMint, payer.publicKey)[0])).amount
-            let startCondFailUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailUsdcMint, payer.publicKey)[0])).amount
+                const winningMint = vault.conditionalMints[proposalAcc.winningOutcomeIndex]
+                const losingMints = vault.conditionalMints.filter((_, index) => index != proposalAcc.winningOutcomeIndex)
 
-            let ixh = await autocratClient.redeemConditionalTokens(
-                proposalAddr,
-            );
-            await ixh.bankrun(banksClient);
+                // the vault must always hold at least the winning outcome's supply
+                const assertVaultSolvent = async () => {
+                    assert.isTrue(await balance(underlyingMint, vaultAddr) >= await supply(winningMint))
+                }
 
-            let endMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.metaMint, payer.publicKey)[0])).amount
-            let endUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.usdcMint, payer.publicKey)[0])).amount
+                await assertVaultSolvent()
 
-            let endCondPassMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassMetaMint, payer.publicKey)[0])).amount
-            let endCondPassUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnPassUsdcMint, payer.publicKey)[0])).amount
+                let startLosingBalances = await Promise.all(losingMints.map(mint => balance(mint, payer.publicKey)))
+                let startConditionalBalance = await balance(winningMint, payer.publicKey)
 
-            let endCondFailMetaBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailMetaMint, payer.publicKey)[0])).amount
-            let endCondFailUsdcBalance = (await getAccount(banksClient, getATA(proposalAcc.conditionalOnFailUsdcMint, payer.publicKey)[0])).amount
+                // redeem in two steps, to check that partial redemptions pay out exactly what's burned
+                for (const amount of [
+                    startConditionalBalance / BigInt(3),
+                    startConditionalBalance - startConditionalBalance / BigInt(3),
+                ]) {
+                    let startBalance = await balance(underlyingMint, payer.publicKey)
+                    let startConditional = await balance(winningMint, payer.publicKey)
+
+                    let ixh = await autocratClient.redeemConditionalTokens(
+                        vaultAddr,
+                        new BN(amount.toString()),
+                    );
+                    await ixh.bankrun(banksClient);
+
+                    assert.equal(startConditional - await balance(winningMint, payer.publicKey), amount)
+                    assert.equal(await balance(underlyingMint, payer.publicKey) - startBalance, amount)
+
+                    await assertVaultSolvent()
+                }
+
+                assert.equal(await balance(winningMint, payer.publicKey), BigInt(0))
+
+                // losing conditional tokens are left untouched
+                let endLosingBalances = await Promise.all(losingMints.map(mint => balance(mint, payer.publicKey)))
 
-            assert.equal(startCondPassMetaBalance - endCondPassMetaBalance, BigInt(endMetaBalance - startMetaBalance))
-            assert.equal(startCondPassUsdcBalance - endCondPassUsdcBalance, BigInt(endUsdcBalance - startUsdcBalance))
-            assert.equal(startCondFailMetaBalance - endCondFailMetaBalance, BigInt(endMetaBalance - startMetaBalance))
-            assert.equal(startCondFailUsdcBalance - endCondFailUsdcBalance, BigInt(endUsdcBalance - startUsdcBalance))
+                assert.deepEqual(endLosingBalances, startLosingBalances)
+            }
+        });
+
+        it("can't redeem more conditional tokens than held", async function () {
+
+            const proposalAcc = await autocratClient.program.account.proposal.fetch(proposalAddr);
+            const vaultAddr = getConditionalVaultAddr(autocratClient.program.programId, proposalAddr, proposalAcc.metaMint)[0]
 
+            let ixh = await autocratClient.redeemConditionalTokens(
+                vaultAddr,
+                new BN(1),
+            );
+
+            try {
+                await ixh.bankrun(banksClient);
+                assert.fail("redeemed conditional tokens that weren't held")
+            } catch (e) {
+                assert.include(e.message, "InsufficientConditionalTokens")
+            }
         });
     });
 });
\ No newline at end of file